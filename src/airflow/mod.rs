@@ -0,0 +1,195 @@
+use std::{borrow::Cow, collections::BTreeMap};
+
+use testcontainers::{
+    core::{CmdWaitFor, ContainerPort, ExecCommand, WaitFor},
+    CopyDataSource, CopyToContainer, Image,
+};
+
+const NAME: &str = "apache/airflow";
+const TAG: &str = "2.10.3";
+const DAGS_DIR: &str = "/opt/airflow/dags";
+const ADMIN_PASSWORD_FILE: &str = "/opt/airflow/standalone_admin_password.txt";
+
+/// Port of the web server and REST API that the [`Airflow`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Airflow`]: https://airflow.apache.org/
+pub const AIRFLOW_PORT: ContainerPort = ContainerPort::Tcp(8080);
+
+/// Module to work with [`Apache Airflow`] inside of tests.
+///
+/// Starts an all-in-one instance of Airflow (`airflow standalone`) based on the official [`Airflow docker image`],
+/// running the scheduler, the triggerer and the web server on port 8080 ([`AIRFLOW_PORT`]) with a SQLite database.
+///
+/// DAG files are registered with [`Airflow::with_dag`], the example DAGs are not loaded.
+/// The REST API accepts basic authentication of the `admin` user, whose password is generated on startup
+/// and can be read with [`Airflow::admin_password_command`].
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{
+///     airflow::{Airflow, AIRFLOW_PORT},
+///     testcontainers::runners::SyncRunner,
+/// };
+///
+/// let airflow_instance = Airflow::default().start().unwrap();
+/// let mut result = airflow_instance
+///     .exec(Airflow::admin_password_command())
+///     .unwrap();
+/// let admin_password = String::from_utf8(result.stdout_to_vec().unwrap()).unwrap();
+/// let api_url = format!(
+///     "http://{}:{}/api/v1",
+///     airflow_instance.get_host().unwrap(),
+///     airflow_instance.get_host_port_ipv4(AIRFLOW_PORT).unwrap()
+/// );
+/// ```
+///
+/// [`Apache Airflow`]: https://airflow.apache.org/
+/// [`Airflow docker image`]: https://hub.docker.com/r/apache/airflow
+#[derive(Debug, Clone)]
+pub struct Airflow {
+    env_vars: BTreeMap<String, String>,
+    dags: Vec<CopyToContainer>,
+}
+
+impl Default for Airflow {
+    fn default() -> Self {
+        let mut env_vars = BTreeMap::new();
+        env_vars.insert(
+            "AIRFLOW__CORE__LOAD_EXAMPLES".to_owned(),
+            "False".to_owned(),
+        );
+        env_vars.insert(
+            "AIRFLOW__API__AUTH_BACKENDS".to_owned(),
+            "airflow.api.auth.backend.basic_auth,airflow.api.auth.backend.session".to_owned(),
+        );
+        Self {
+            env_vars,
+            dags: Vec::new(),
+        }
+    }
+}
+
+impl Airflow {
+    /// Registers a DAG file (python source), copied to the dags folder `/opt/airflow/dags`.
+    /// Can be called multiple times to add (not override) DAG files.
+    ///
+    /// # Example
+    /// ```
+    /// use testcontainers_modules::airflow::Airflow;
+    ///
+    /// let airflow = Airflow::default().with_dag(
+    ///     "from airflow import DAG\n\
+    ///      from airflow.operators.empty import EmptyOperator\n\
+    ///      with DAG('example', schedule=None):\n    EmptyOperator(task_id='noop')\n"
+    ///         .to_string()
+    ///         .into_bytes(),
+    /// );
+    /// ```
+    pub fn with_dag(mut self, dag: impl Into<CopyDataSource>) -> Self {
+        let target = format!("{DAGS_DIR}/testcontainers_dag_{}.py", self.dags.len());
+        self.dags.push(CopyToContainer::new(dag.into(), target));
+        self
+    }
+
+    /// Sets an Airflow configuration option with the `AIRFLOW__{SECTION}__{KEY}` environment variable,
+    /// e.g. `with_config("scheduler", "dag_dir_list_interval", "5")`.
+    pub fn with_config(mut self, section: &str, key: &str, value: &str) -> Self {
+        self.env_vars.insert(
+            format!(
+                "AIRFLOW__{}__{}",
+                section.to_uppercase(),
+                key.to_uppercase()
+            ),
+            value.to_owned(),
+        );
+        self
+    }
+
+    /// Returns a command printing the password of the `admin` user, which is generated on startup.
+    pub fn admin_password_command() -> ExecCommand {
+        ExecCommand::new(["cat", ADMIN_PASSWORD_FILE])
+            .with_cmd_ready_condition(CmdWaitFor::exit_code(0))
+    }
+}
+
+impl Image for Airflow {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn tag(&self) -> &str {
+        TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![WaitFor::message_on_stdout("Airflow is ready")]
+    }
+
+    fn env_vars(
+        &self,
+    ) -> impl IntoIterator<Item = (impl Into<Cow<'_, str>>, impl Into<Cow<'_, str>>)> {
+        &self.env_vars
+    }
+
+    fn copy_to_sources(&self) -> impl IntoIterator<Item = &CopyToContainer> {
+        &self.dags
+    }
+
+    fn cmd(&self) -> impl IntoIterator<Item = impl Into<Cow<'_, str>>> {
+        ["standalone"]
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[AIRFLOW_PORT]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use retry::{delay::Fixed, retry};
+
+    use super::*;
+    use crate::testcontainers::runners::SyncRunner;
+
+    #[test]
+    fn airflow_list_dags() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = Airflow::default()
+            .with_config("scheduler", "dag_dir_list_interval", "5")
+            .with_dag(
+                "from airflow import DAG\n\
+                 from airflow.operators.empty import EmptyOperator\n\
+                 with DAG('testcontainers', schedule=None):\n    EmptyOperator(task_id='noop')\n"
+                    .to_string()
+                    .into_bytes(),
+            )
+            .start()?;
+        let mut result = node.exec(Airflow::admin_password_command())?;
+        let password = String::from_utf8(result.stdout_to_vec()?)?;
+        let url = format!(
+            "http://{}:{}/api/v1/dags",
+            node.get_host()?,
+            node.get_host_port_ipv4(AIRFLOW_PORT)?
+        );
+
+        let client = reqwest::blocking::Client::new();
+        let dags = retry(Fixed::from_millis(1000).take(60), || {
+            let dags: serde_json::Value = client
+                .get(&url)
+                .basic_auth("admin", Some(password.trim()))
+                .send()
+                .and_then(|response| response.error_for_status())
+                .and_then(|response| response.json())
+                .map_err(|err| err.to_string())?;
+            if dags["total_entries"] == 1 {
+                Ok(dags)
+            } else {
+                Err(format!("DAG not parsed yet: {dags}"))
+            }
+        })
+        .map_err(|err| err.to_string())?;
+        assert_eq!(dags["dags"][0]["dag_id"], "testcontainers");
+        Ok(())
+    }
+}
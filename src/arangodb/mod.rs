@@ -0,0 +1,172 @@
+use std::{borrow::Cow, collections::BTreeMap};
+
+use testcontainers::{
+    core::{wait::HttpWaitStrategy, ContainerPort, WaitFor},
+    CopyDataSource, CopyToContainer, Image,
+};
+
+const NAME: &str = "arangodb";
+const TAG: &str = "3.12.3";
+
+/// Port that the [`ArangoDB`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`ArangoDB`]: https://arangodb.com/
+pub const ARANGODB_PORT: ContainerPort = ContainerPort::Tcp(8529);
+
+/// Module to work with [`ArangoDB`] inside of tests.
+///
+/// Starts a single server instance of ArangoDB based on the official [`ArangoDB docker image`],
+/// serving the HTTP API on port 8529 ([`ARANGODB_PORT`]).
+///
+/// Authentication is disabled by default, use [`ArangoDb::with_root_password`] to enable it.
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{
+///     arangodb::{ArangoDb, ARANGODB_PORT},
+///     testcontainers::runners::SyncRunner,
+/// };
+///
+/// let arangodb_instance = ArangoDb::default().start().unwrap();
+/// let url = format!(
+///     "http://{}:{}",
+///     arangodb_instance.get_host().unwrap(),
+///     arangodb_instance.get_host_port_ipv4(ARANGODB_PORT).unwrap()
+/// );
+/// ```
+///
+/// [`ArangoDB`]: https://arangodb.com/
+/// [`ArangoDB docker image`]: https://hub.docker.com/_/arangodb
+#[derive(Debug, Clone)]
+pub struct ArangoDb {
+    env_vars: BTreeMap<String, String>,
+    init_scripts: Vec<CopyToContainer>,
+}
+
+impl Default for ArangoDb {
+    fn default() -> Self {
+        let mut env_vars = BTreeMap::new();
+        env_vars.insert("ARANGO_NO_AUTH".to_owned(), "1".to_owned());
+        Self {
+            env_vars,
+            init_scripts: Vec::new(),
+        }
+    }
+}
+
+impl ArangoDb {
+    /// Enables authentication and sets the password of the `root` user (`ARANGO_ROOT_PASSWORD`).
+    pub fn with_root_password(mut self, password: &str) -> Self {
+        self.env_vars.remove("ARANGO_NO_AUTH");
+        self.env_vars
+            .insert("ARANGO_ROOT_PASSWORD".to_owned(), password.to_owned());
+        self
+    }
+
+    /// Disables authentication (`ARANGO_NO_AUTH`), which is the default.
+    pub fn with_no_auth(mut self) -> Self {
+        self.env_vars.remove("ARANGO_ROOT_PASSWORD");
+        self.env_vars
+            .insert("ARANGO_NO_AUTH".to_owned(), "1".to_owned());
+        self
+    }
+
+    /// Registers a JavaScript file, which is executed by `arangosh` when the database is initialized.
+    /// Can be called multiple times to add (not override) scripts, which are executed in registration order.
+    ///
+    /// # Example
+    /// ```
+    /// use testcontainers_modules::arangodb::ArangoDb;
+    ///
+    /// let arangodb = ArangoDb::default().with_init_js(
+    ///     "db._create('movies');"
+    ///         .to_string()
+    ///         .into_bytes(),
+    /// );
+    /// ```
+    pub fn with_init_js(mut self, init_js: impl Into<CopyDataSource>) -> Self {
+        let target = format!(
+            "/docker-entrypoint-initdb.d/init_{:03}.js",
+            self.init_scripts.len()
+        );
+        self.init_scripts
+            .push(CopyToContainer::new(init_js.into(), target));
+        self
+    }
+}
+
+impl Image for ArangoDb {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn tag(&self) -> &str {
+        TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        // the database is initialized by a temporary server, which reports to be ready as well,
+        // thus the availability of the actual server is checked in addition
+        vec![
+            WaitFor::message_on_stdout("is ready for business"),
+            WaitFor::http(
+                HttpWaitStrategy::new("/_admin/server/availability")
+                    .with_port(ARANGODB_PORT)
+                    .with_expected_status_code(200_u16),
+            ),
+        ]
+    }
+
+    fn env_vars(
+        &self,
+    ) -> impl IntoIterator<Item = (impl Into<Cow<'_, str>>, impl Into<Cow<'_, str>>)> {
+        &self.env_vars
+    }
+
+    fn copy_to_sources(&self) -> impl IntoIterator<Item = &CopyToContainer> {
+        &self.init_scripts
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[ARANGODB_PORT]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testcontainers::runners::SyncRunner;
+
+    #[test]
+    fn arangodb_with_root_password_and_init_js() -> Result<(), Box<dyn std::error::Error + 'static>>
+    {
+        let _ = pretty_env_logger::try_init();
+        let node = ArangoDb::default()
+            .with_root_password("secret")
+            .with_init_js(
+                "db._create('movies');\ndb.movies.save({title: 'The Matrix'});\n"
+                    .to_string()
+                    .into_bytes(),
+            )
+            .start()?;
+        let url = format!(
+            "http://{}:{}/_api/collection/movies/count",
+            node.get_host()?,
+            node.get_host_port_ipv4(ARANGODB_PORT)?
+        );
+
+        let client = reqwest::blocking::Client::new();
+        let response = client.get(&url).send()?;
+        assert_eq!(response.status(), 401);
+
+        let count: serde_json::Value = client
+            .get(&url)
+            .basic_auth("root", Some("secret"))
+            .send()?
+            .error_for_status()?
+            .json()?;
+        assert_eq!(count["count"], 1);
+        Ok(())
+    }
+}
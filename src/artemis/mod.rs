@@ -0,0 +1,156 @@
+use std::{borrow::Cow, collections::BTreeMap};
+
+use testcontainers::{
+    core::{ContainerPort, WaitFor},
+    Image,
+};
+
+const NAME: &str = "apache/activemq-artemis";
+const TAG: &str = "2.38.0-alpine";
+
+/// Port of the acceptor for all protocols (Core, AMQP 1.0, STOMP, MQTT, OpenWire) that the [`ActiveMQ Artemis`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`ActiveMQ Artemis`]: https://activemq.apache.org/components/artemis/
+pub const ARTEMIS_PORT: ContainerPort = ContainerPort::Tcp(61616);
+
+/// Port of the web console that the [`ActiveMQ Artemis`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`ActiveMQ Artemis`]: https://activemq.apache.org/components/artemis/
+pub const ARTEMIS_CONSOLE_PORT: ContainerPort = ContainerPort::Tcp(8161);
+
+/// Module to work with [`Apache ActiveMQ Artemis`] inside of tests.
+///
+/// Starts a broker based on the official [`ActiveMQ Artemis docker image`].
+/// Clients of all supported protocols (Core, AMQP 1.0, STOMP, MQTT, OpenWire) connect on port 61616
+/// ([`ARTEMIS_PORT`]), the web console is served on port 8161 ([`ARTEMIS_CONSOLE_PORT`]).
+///
+/// Clients authenticate as `artemis` with the password `artemis`, see [`ActiveMqArtemis::with_user`]
+/// and [`ActiveMqArtemis::with_password`].
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{
+///     artemis::{ActiveMqArtemis, ARTEMIS_PORT},
+///     testcontainers::runners::SyncRunner,
+/// };
+///
+/// let artemis_instance = ActiveMqArtemis::default().start().unwrap();
+/// let amqp_url = format!(
+///     "amqp://artemis:artemis@{}:{}",
+///     artemis_instance.get_host().unwrap(),
+///     artemis_instance.get_host_port_ipv4(ARTEMIS_PORT).unwrap()
+/// );
+/// ```
+///
+/// [`Apache ActiveMQ Artemis`]: https://activemq.apache.org/components/artemis/
+/// [`ActiveMQ Artemis docker image`]: https://hub.docker.com/r/apache/activemq-artemis
+#[derive(Debug, Clone)]
+pub struct ActiveMqArtemis {
+    env_vars: BTreeMap<String, String>,
+}
+
+impl Default for ActiveMqArtemis {
+    fn default() -> Self {
+        let mut env_vars = BTreeMap::new();
+        env_vars.insert("ARTEMIS_USER".to_owned(), "artemis".to_owned());
+        env_vars.insert("ARTEMIS_PASSWORD".to_owned(), "artemis".to_owned());
+        Self { env_vars }
+    }
+}
+
+impl ActiveMqArtemis {
+    /// Sets the name of the user created on startup (`ARTEMIS_USER`), defaults to `artemis`.
+    pub fn with_user(mut self, user: &str) -> Self {
+        self.env_vars
+            .insert("ARTEMIS_USER".to_owned(), user.to_owned());
+        self
+    }
+
+    /// Sets the password of the user created on startup (`ARTEMIS_PASSWORD`), defaults to `artemis`.
+    pub fn with_password(mut self, password: &str) -> Self {
+        self.env_vars
+            .insert("ARTEMIS_PASSWORD".to_owned(), password.to_owned());
+        self
+    }
+
+    /// Allows clients to connect without credentials (`ANONYMOUS_LOGIN`).
+    pub fn with_anonymous_login(mut self) -> Self {
+        self.env_vars
+            .insert("ANONYMOUS_LOGIN".to_owned(), "true".to_owned());
+        self
+    }
+}
+
+impl Image for ActiveMqArtemis {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn tag(&self) -> &str {
+        TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![WaitFor::message_on_stdout("Artemis Console available")]
+    }
+
+    fn env_vars(
+        &self,
+    ) -> impl IntoIterator<Item = (impl Into<Cow<'_, str>>, impl Into<Cow<'_, str>>)> {
+        &self.env_vars
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[ARTEMIS_PORT, ARTEMIS_CONSOLE_PORT]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{Read, Write},
+        net::TcpStream,
+    };
+
+    use super::*;
+    use crate::testcontainers::runners::SyncRunner;
+
+    fn stomp_connect(
+        stream: &mut TcpStream,
+        login: &str,
+        passcode: &str,
+    ) -> Result<String, Box<dyn std::error::Error + 'static>> {
+        write!(
+            stream,
+            "CONNECT\naccept-version:1.2\nhost:localhost\nlogin:{login}\npasscode:{passcode}\n\n\0"
+        )?;
+        let mut frame = Vec::new();
+        let mut byte = [0];
+        while stream.read(&mut byte)? == 1 && byte[0] != 0 {
+            frame.push(byte[0]);
+        }
+        Ok(String::from_utf8(frame)?)
+    }
+
+    #[test]
+    fn artemis_stomp_connect() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = ActiveMqArtemis::default()
+            .with_user("user")
+            .with_password("secret")
+            .start()?;
+        let address = (
+            node.get_host()?.to_string(),
+            node.get_host_port_ipv4(ARTEMIS_PORT)?,
+        );
+
+        let frame = stomp_connect(&mut TcpStream::connect(&address)?, "user", "secret")?;
+        assert!(frame.starts_with("CONNECTED"), "{frame}");
+
+        let frame = stomp_connect(&mut TcpStream::connect(&address)?, "user", "wrong")?;
+        assert!(frame.starts_with("ERROR"), "{frame}");
+        Ok(())
+    }
+}
@@ -0,0 +1,319 @@
+use std::{borrow::Cow, fmt::Display};
+
+use testcontainers::{
+    core::{ContainerPort, WaitFor},
+    ContainerAsync, Image, TestcontainersError,
+};
+
+const NAME: &str = "mcr.microsoft.com/azure-storage/azurite";
+const TAG: &str = "3.33.0";
+
+/// Name of the well-known development storage account.
+pub const ACCOUNT_NAME: &str = "devstoreaccount1";
+/// Key of the well-known development storage account.
+pub const ACCOUNT_KEY: &str =
+    "Eby8vdM02xNOcqFlqUwJPLlmEtlCDXJ1OUzFT50uSRZ6IFsuFq2UVErCz4I6tq/K1SZFPTOtr/KBHBeksoGMGw==";
+
+/// Port of the Blob service that the [`Azurite`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Azurite`]: https://github.com/Azure/Azurite
+pub const BLOB_PORT: ContainerPort = ContainerPort::Tcp(10000);
+/// Port of the Queue service that the [`Azurite`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Azurite`]: https://github.com/Azure/Azurite
+pub const QUEUE_PORT: ContainerPort = ContainerPort::Tcp(10001);
+/// Port of the Table service that the [`Azurite`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Azurite`]: https://github.com/Azure/Azurite
+pub const TABLE_PORT: ContainerPort = ContainerPort::Tcp(10002);
+
+/// Storage service emulated by [`Azurite`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AzuriteService {
+    /// Blob service, listening on [`BLOB_PORT`]
+    Blob,
+    /// Queue service, listening on [`QUEUE_PORT`]
+    Queue,
+    /// Table service, listening on [`TABLE_PORT`]
+    Table,
+}
+
+impl AzuriteService {
+    fn id(self) -> &'static str {
+        match self {
+            AzuriteService::Blob => "blob",
+            AzuriteService::Queue => "queue",
+            AzuriteService::Table => "table",
+        }
+    }
+
+    fn display_name(self) -> &'static str {
+        match self {
+            AzuriteService::Blob => "Blob",
+            AzuriteService::Queue => "Queue",
+            AzuriteService::Table => "Table",
+        }
+    }
+
+    /// Port of the service inside of the container.
+    pub fn port(self) -> ContainerPort {
+        match self {
+            AzuriteService::Blob => BLOB_PORT,
+            AzuriteService::Queue => QUEUE_PORT,
+            AzuriteService::Table => TABLE_PORT,
+        }
+    }
+
+    fn endpoint_key(self) -> &'static str {
+        match self {
+            AzuriteService::Blob => "BlobEndpoint",
+            AzuriteService::Queue => "QueueEndpoint",
+            AzuriteService::Table => "TableEndpoint",
+        }
+    }
+}
+
+/// Module to work with [`Azurite`] inside of tests.
+///
+/// Starts an instance of the Azure Storage emulator based on the official [`Azurite docker image`].
+///
+/// By default the Blob, Queue and Table services are started, listening on the ports
+/// 10000 ([`BLOB_PORT`]), 10001 ([`QUEUE_PORT`]) and 10002 ([`TABLE_PORT`]).
+/// Use [`Azurite::with_services`] to start only some of them.
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{
+///     azurite::{Azurite, BLOB_PORT},
+///     testcontainers::runners::SyncRunner,
+/// };
+///
+/// let azurite_instance = Azurite::default().start().unwrap();
+/// let blob_endpoint = format!(
+///     "http://{}:{}/devstoreaccount1",
+///     azurite_instance.get_host().unwrap(),
+///     azurite_instance.get_host_port_ipv4(BLOB_PORT).unwrap()
+/// );
+/// ```
+///
+/// [`Azurite`]: https://github.com/Azure/Azurite
+/// [`Azurite docker image`]: https://hub.docker.com/r/microsoft/azure-storage-azurite
+#[derive(Debug, Clone)]
+pub struct Azurite {
+    services: Vec<AzuriteService>,
+    exposed_ports: Vec<ContainerPort>,
+}
+
+impl Default for Azurite {
+    fn default() -> Self {
+        Self {
+            services: vec![
+                AzuriteService::Blob,
+                AzuriteService::Queue,
+                AzuriteService::Table,
+            ],
+            exposed_ports: vec![BLOB_PORT, QUEUE_PORT, TABLE_PORT],
+        }
+    }
+}
+
+impl Azurite {
+    /// Starts only the given services instead of all of them.
+    /// An empty list of services is ignored, i.e. all services are started.
+    pub fn with_services(mut self, services: &[AzuriteService]) -> Self {
+        if services.is_empty() {
+            return self;
+        }
+        self.services = [
+            AzuriteService::Blob,
+            AzuriteService::Queue,
+            AzuriteService::Table,
+        ]
+        .into_iter()
+        .filter(|service| services.contains(service))
+        .collect();
+        self.exposed_ports = self.services.iter().map(|service| service.port()).collect();
+        self
+    }
+
+    /// Returns the connection string of the well-known development storage account,
+    /// containing the endpoints of the services started in the given container.
+    ///
+    /// # Example
+    /// ```
+    /// use testcontainers_modules::{azurite::Azurite, testcontainers::runners::AsyncRunner};
+    ///
+    /// # async fn example() -> Result<(), testcontainers_modules::testcontainers::TestcontainersError> {
+    /// let azurite_instance = Azurite::default().start().await?;
+    /// let connection_string = Azurite::connection_string(&azurite_instance).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn connection_string(
+        container: &ContainerAsync<Self>,
+    ) -> Result<String, TestcontainersError> {
+        let host = container.get_host().await?;
+        let mut endpoints = Vec::new();
+        for service in &container.image().services {
+            let port = container.get_host_port_ipv4(service.port()).await?;
+            endpoints.push((*service, port));
+        }
+        Ok(format_connection_string(host, &endpoints))
+    }
+}
+
+fn format_connection_string(host: impl Display, endpoints: &[(AzuriteService, u16)]) -> String {
+    let mut connection_string = format!(
+        "DefaultEndpointsProtocol=http;AccountName={ACCOUNT_NAME};AccountKey={ACCOUNT_KEY};"
+    );
+    for (service, port) in endpoints {
+        connection_string.push_str(&format!(
+            "{}=http://{host}:{port}/{ACCOUNT_NAME};",
+            service.endpoint_key()
+        ));
+    }
+    connection_string
+}
+
+impl Image for Azurite {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn tag(&self) -> &str {
+        TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        self.services
+            .iter()
+            .map(|service| {
+                WaitFor::message_on_stdout(format!(
+                    "Azurite {} service is successfully listening",
+                    service.display_name()
+                ))
+            })
+            .collect()
+    }
+
+    fn cmd(&self) -> impl IntoIterator<Item = impl Into<Cow<'_, str>>> {
+        let args = |service: AzuriteService| {
+            format!(
+                "--{id}Host 0.0.0.0 --{id}Port {port}",
+                id = service.id(),
+                port = service.port().as_u16()
+            )
+        };
+
+        let command = match self.services.as_slice() {
+            [service] => format!("azurite-{} {}", service.id(), args(*service)),
+            services if services.len() == 3 => format!(
+                "azurite -l /data {}",
+                services
+                    .iter()
+                    .map(|service| args(*service))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            ),
+            services => {
+                let background = services
+                    .iter()
+                    .map(|service| {
+                        format!(
+                            "azurite-{} -l /data/{} {} &",
+                            service.id(),
+                            service.id(),
+                            args(*service)
+                        )
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                format!("{background} wait")
+            }
+        };
+        vec!["sh".to_owned(), "-c".to_owned(), command]
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &self.exposed_ports
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testcontainers::runners::SyncRunner;
+
+    #[test]
+    fn azurite_connection_string() {
+        let connection_string = format_connection_string(
+            "localhost",
+            &[
+                (AzuriteService::Blob, 40000),
+                (AzuriteService::Table, 40002),
+            ],
+        );
+        assert_eq!(
+            connection_string,
+            format!(
+                "DefaultEndpointsProtocol=http;AccountName={ACCOUNT_NAME};AccountKey={ACCOUNT_KEY};\
+                 BlobEndpoint=http://localhost:40000/{ACCOUNT_NAME};\
+                 TableEndpoint=http://localhost:40002/{ACCOUNT_NAME};"
+            )
+        );
+    }
+
+    #[test]
+    fn azurite_blob_service() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = Azurite::default()
+            .with_services(&[AzuriteService::Blob])
+            .start()?;
+        let port = node.get_host_port_ipv4(BLOB_PORT)?;
+
+        let response =
+            reqwest::blocking::get(format!("http://{}:{port}/{ACCOUNT_NAME}", node.get_host()?))?;
+        let server = response
+            .headers()
+            .get("server")
+            .and_then(|server| server.to_str().ok())
+            .unwrap_or_default();
+        assert!(server.starts_with("Azurite-Blob"));
+        assert!(node.get_host_port_ipv4(QUEUE_PORT).is_err());
+        assert!(node.get_host_port_ipv4(TABLE_PORT).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn azurite_ignores_empty_services() {
+        let azurite = Azurite::default().with_services(&[]);
+        assert_eq!(azurite.expose_ports(), [BLOB_PORT, QUEUE_PORT, TABLE_PORT]);
+    }
+
+    #[tokio::test]
+    async fn azurite_queue_and_table_services() -> Result<(), Box<dyn std::error::Error + 'static>>
+    {
+        let _ = pretty_env_logger::try_init();
+        let image =
+            Azurite::default().with_services(&[AzuriteService::Queue, AzuriteService::Table]);
+        let node = crate::testcontainers::runners::AsyncRunner::start(image).await?;
+        let host = node.get_host().await?;
+        let queue_port = node.get_host_port_ipv4(QUEUE_PORT).await?;
+        let table_port = node.get_host_port_ipv4(TABLE_PORT).await?;
+        assert!(node.get_host_port_ipv4(BLOB_PORT).await.is_err());
+
+        let connection_string = Azurite::connection_string(&node).await?;
+        assert_eq!(
+            connection_string,
+            format!(
+                "DefaultEndpointsProtocol=http;AccountName={ACCOUNT_NAME};AccountKey={ACCOUNT_KEY};\
+                 QueueEndpoint=http://{host}:{queue_port}/{ACCOUNT_NAME};\
+                 TableEndpoint=http://{host}:{table_port}/{ACCOUNT_NAME};"
+            )
+        );
+        Ok(())
+    }
+}
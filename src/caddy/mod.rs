@@ -0,0 +1,163 @@
+use std::{borrow::Cow, collections::BTreeMap};
+
+use testcontainers::{
+    core::{wait::HttpWaitStrategy, ContainerPort, WaitFor},
+    CopyDataSource, CopyToContainer, Image,
+};
+
+const NAME: &str = "caddy";
+const TAG: &str = "2.8-alpine";
+const CADDYFILE_PATH: &str = "/etc/caddy/Caddyfile";
+
+/// Port for HTTP that the [`Caddy`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Caddy`]: https://caddyserver.com/
+pub const CADDY_HTTP_PORT: ContainerPort = ContainerPort::Tcp(80);
+
+/// Port for HTTPS that the [`Caddy`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Caddy`]: https://caddyserver.com/
+pub const CADDY_HTTPS_PORT: ContainerPort = ContainerPort::Tcp(443);
+
+/// Port of the admin API that the [`Caddy`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Caddy`]: https://caddyserver.com/
+pub const CADDY_ADMIN_PORT: ContainerPort = ContainerPort::Tcp(2019);
+
+/// Module to work with [`Caddy`] inside of tests.
+///
+/// Starts an instance of Caddy based on the official [`Caddy docker image`].
+///
+/// The default Caddyfile of the image serves a static welcome page on port 80 ([`CADDY_HTTP_PORT`]),
+/// use [`Caddy::with_caddyfile`] to configure e.g. reverse proxies or TLS on port 443 ([`CADDY_HTTPS_PORT`]).
+/// The admin API listens on all interfaces on port 2019 ([`CADDY_ADMIN_PORT`]), so it can be reached from the host.
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{
+///     caddy::{Caddy, CADDY_HTTP_PORT},
+///     testcontainers::runners::SyncRunner,
+/// };
+///
+/// let caddy_instance = Caddy::default().start().unwrap();
+/// let url = format!(
+///     "http://{}:{}",
+///     caddy_instance.get_host().unwrap(),
+///     caddy_instance.get_host_port_ipv4(CADDY_HTTP_PORT).unwrap()
+/// );
+/// ```
+///
+/// [`Caddy`]: https://caddyserver.com/
+/// [`Caddy docker image`]: https://hub.docker.com/_/caddy
+#[derive(Debug, Clone)]
+pub struct Caddy {
+    env_vars: BTreeMap<String, String>,
+    caddyfile: Option<CopyToContainer>,
+}
+
+impl Default for Caddy {
+    fn default() -> Self {
+        let mut env_vars = BTreeMap::new();
+        env_vars.insert(
+            "CADDY_ADMIN".to_owned(),
+            format!("0.0.0.0:{}", CADDY_ADMIN_PORT.as_u16()),
+        );
+        Self {
+            env_vars,
+            caddyfile: None,
+        }
+    }
+}
+
+impl Caddy {
+    /// Registers a custom Caddyfile, replacing `/etc/caddy/Caddyfile`.
+    ///
+    /// The Caddyfile must not override the `admin` global option with a loopback address,
+    /// as the container is considered ready once the admin API responds on port 2019.
+    ///
+    /// # Example
+    /// ```
+    /// use testcontainers_modules::caddy::Caddy;
+    ///
+    /// let caddy = Caddy::default().with_caddyfile(
+    ///     ":80 {\n  reverse_proxy app:8080\n}\n"
+    ///         .to_string()
+    ///         .into_bytes(),
+    /// );
+    /// ```
+    pub fn with_caddyfile(mut self, caddyfile: impl Into<CopyDataSource>) -> Self {
+        self.caddyfile = Some(CopyToContainer::new(caddyfile.into(), CADDYFILE_PATH));
+        self
+    }
+}
+
+impl Image for Caddy {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn tag(&self) -> &str {
+        TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![WaitFor::http(
+            HttpWaitStrategy::new("/config/")
+                .with_port(CADDY_ADMIN_PORT)
+                .with_expected_status_code(200_u16),
+        )]
+    }
+
+    fn env_vars(
+        &self,
+    ) -> impl IntoIterator<Item = (impl Into<Cow<'_, str>>, impl Into<Cow<'_, str>>)> {
+        &self.env_vars
+    }
+
+    fn copy_to_sources(&self) -> impl IntoIterator<Item = &CopyToContainer> {
+        &self.caddyfile
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[CADDY_HTTP_PORT, CADDY_HTTPS_PORT, CADDY_ADMIN_PORT]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testcontainers::runners::SyncRunner;
+
+    #[test]
+    fn caddy_with_caddyfile() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = Caddy::default()
+            .with_caddyfile(
+                ":80 {\n  respond \"testcontainers\"\n}\n"
+                    .to_string()
+                    .into_bytes(),
+            )
+            .start()?;
+        let host = node.get_host()?;
+
+        let body = reqwest::blocking::get(format!(
+            "http://{host}:{}/",
+            node.get_host_port_ipv4(CADDY_HTTP_PORT)?
+        ))?
+        .error_for_status()?
+        .text()?;
+        assert_eq!(body, "testcontainers");
+
+        let config: serde_json::Value = reqwest::blocking::get(format!(
+            "http://{host}:{}/config/",
+            node.get_host_port_ipv4(CADDY_ADMIN_PORT)?
+        ))?
+        .error_for_status()?
+        .json()?;
+        assert!(config["apps"]["http"].is_object());
+        Ok(())
+    }
+}
@@ -0,0 +1,181 @@
+use std::{borrow::Cow, collections::BTreeMap};
+
+use testcontainers::{
+    core::{CmdWaitFor, ContainerPort, ContainerState, ExecCommand, WaitFor},
+    CopyDataSource, CopyToContainer, Image, TestcontainersError,
+};
+
+const NAME: &str = "cassandra";
+const TAG: &str = "5.0.2";
+
+/// Port of the CQL native transport that the [`Cassandra`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Cassandra`]: https://cassandra.apache.org/
+pub const CASSANDRA_PORT: ContainerPort = ContainerPort::Tcp(9042);
+
+/// Module to work with [`Apache Cassandra`] inside of tests.
+///
+/// Starts a single node instance of Cassandra based on the official [`Cassandra docker image`].
+/// The heap size is limited to 512MB by default, to allow running several instances on a test host.
+///
+/// CQL clients can connect on port 9042 ([`CASSANDRA_PORT`]).
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{
+///     cassandra::{Cassandra, CASSANDRA_PORT},
+///     testcontainers::runners::SyncRunner,
+/// };
+///
+/// let cassandra_instance = Cassandra::default().start().unwrap();
+/// let contact_point = format!(
+///     "{}:{}",
+///     cassandra_instance.get_host().unwrap(),
+///     cassandra_instance.get_host_port_ipv4(CASSANDRA_PORT).unwrap()
+/// );
+/// ```
+///
+/// [`Apache Cassandra`]: https://cassandra.apache.org/
+/// [`Cassandra docker image`]: https://hub.docker.com/_/cassandra
+#[derive(Debug, Clone)]
+pub struct Cassandra {
+    env_vars: BTreeMap<String, String>,
+    init_scripts: Vec<CopyToContainer>,
+}
+
+impl Default for Cassandra {
+    fn default() -> Self {
+        let mut env_vars = BTreeMap::new();
+        env_vars.insert("MAX_HEAP_SIZE".to_owned(), "512M".to_owned());
+        env_vars.insert("HEAP_NEWSIZE".to_owned(), "128M".to_owned());
+        Self {
+            env_vars,
+            init_scripts: Vec::new(),
+        }
+    }
+}
+
+impl Cassandra {
+    /// Sets a `CASSANDRA_*` environment variable, used by the image to configure `cassandra.yaml`,
+    /// e.g. `with_config_env("NUM_TOKENS", "4")` sets `CASSANDRA_NUM_TOKENS`.
+    pub fn with_config_env(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.env_vars
+            .insert(format!("CASSANDRA_{}", name.into()), value.into());
+        self
+    }
+
+    /// Sets the name of the cluster (`CASSANDRA_CLUSTER_NAME`).
+    pub fn with_cluster_name(self, cluster_name: &str) -> Self {
+        self.with_config_env("CLUSTER_NAME", cluster_name)
+    }
+
+    /// Sets the datacenter of the node (`CASSANDRA_DC`),
+    /// which requires the `GossipingPropertyFileSnitch` that is configured as well.
+    pub fn with_datacenter(self, datacenter: &str) -> Self {
+        self.with_config_env("DC", datacenter)
+            .with_config_env("ENDPOINT_SNITCH", "GossipingPropertyFileSnitch")
+    }
+
+    /// Registers a CQL script, which is executed by `cqlsh` once the node accepts CQL clients.
+    /// Can be called multiple times to add (not override) scripts, which are executed in registration order.
+    ///
+    /// # Example
+    /// ```
+    /// use testcontainers_modules::cassandra::Cassandra;
+    ///
+    /// let cassandra = Cassandra::default().with_init_cql(
+    ///     "CREATE KEYSPACE test WITH replication = {'class': 'SimpleStrategy', 'replication_factor': 1};"
+    ///         .to_string()
+    ///         .into_bytes(),
+    /// );
+    /// ```
+    pub fn with_init_cql(mut self, init_cql: impl Into<CopyDataSource>) -> Self {
+        let target = format!("/tmp/testcontainers_init_{}.cql", self.init_scripts.len());
+        self.init_scripts
+            .push(CopyToContainer::new(init_cql.into(), target));
+        self
+    }
+}
+
+impl Image for Cassandra {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn tag(&self) -> &str {
+        TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![WaitFor::message_on_stdout(
+            "Starting listening for CQL clients",
+        )]
+    }
+
+    fn env_vars(
+        &self,
+    ) -> impl IntoIterator<Item = (impl Into<Cow<'_, str>>, impl Into<Cow<'_, str>>)> {
+        &self.env_vars
+    }
+
+    fn copy_to_sources(&self) -> impl IntoIterator<Item = &CopyToContainer> {
+        &self.init_scripts
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[CASSANDRA_PORT]
+    }
+
+    fn exec_after_start(
+        &self,
+        _cs: ContainerState,
+    ) -> Result<Vec<ExecCommand>, TestcontainersError> {
+        Ok((0..self.init_scripts.len())
+            .map(|i| {
+                ExecCommand::new([
+                    "cqlsh".to_string(),
+                    "-f".to_string(),
+                    format!("/tmp/testcontainers_init_{i}.cql"),
+                ])
+                .with_cmd_ready_condition(CmdWaitFor::exit_code(0))
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testcontainers::runners::SyncRunner;
+
+    #[test]
+    fn cassandra_with_init_cql() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = Cassandra::default()
+            .with_cluster_name("test-cluster")
+            .with_datacenter("dc1")
+            .with_init_cql(
+                "CREATE KEYSPACE test WITH replication = {'class': 'SimpleStrategy', 'replication_factor': 1};\n\
+                 CREATE TABLE test.foo (id int PRIMARY KEY, bar text);\n\
+                 INSERT INTO test.foo (id, bar) VALUES (1, 'blub');\n"
+                    .to_string()
+                    .into_bytes(),
+            )
+            .start()?;
+
+        let mut result = node.exec(
+            ExecCommand::new([
+                "cqlsh",
+                "-e",
+                "SELECT bar FROM test.foo; SELECT cluster_name, data_center FROM system.local;",
+            ])
+            .with_cmd_ready_condition(CmdWaitFor::exit_code(0)),
+        )?;
+        let stdout = String::from_utf8(result.stdout_to_vec()?)?;
+        assert!(stdout.contains("blub"));
+        assert!(stdout.contains("test-cluster"));
+        assert!(stdout.contains("dc1"));
+        Ok(())
+    }
+}
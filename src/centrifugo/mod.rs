@@ -0,0 +1,182 @@
+use std::{borrow::Cow, collections::BTreeMap};
+
+use testcontainers::{
+    core::{wait::HttpWaitStrategy, ContainerPort, WaitFor},
+    CopyDataSource, CopyToContainer, Image,
+};
+
+const NAME: &str = "centrifugo/centrifugo";
+const TAG: &str = "v5.4.9";
+const CONFIG_FILE_PATH: &str = "/centrifugo/config.json";
+
+/// Port of the HTTP server (client transports, server API and admin UI) that the [`Centrifugo`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Centrifugo`]: https://centrifugal.dev/
+pub const CENTRIFUGO_PORT: ContainerPort = ContainerPort::Tcp(8000);
+
+/// Module to work with [`Centrifugo`] inside of tests.
+///
+/// Starts an instance of Centrifugo based on the official [`Centrifugo docker image`].
+/// Clients connect over WebSocket (`/connection/websocket`) and the server API (`/api`) is served
+/// on port 8000 ([`CENTRIFUGO_PORT`]).
+///
+/// The server API requires the key set with [`Centrifugo::with_api_key`], `api_key` by default.
+/// Further options (namespaces, token secrets, ...) are set with [`Centrifugo::with_config_json`].
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{
+///     centrifugo::{Centrifugo, CENTRIFUGO_PORT},
+///     testcontainers::runners::SyncRunner,
+/// };
+///
+/// let centrifugo_instance = Centrifugo::default().start().unwrap();
+/// let api_url = format!(
+///     "http://{}:{}/api",
+///     centrifugo_instance.get_host().unwrap(),
+///     centrifugo_instance.get_host_port_ipv4(CENTRIFUGO_PORT).unwrap()
+/// );
+/// ```
+///
+/// [`Centrifugo`]: https://centrifugal.dev/
+/// [`Centrifugo docker image`]: https://hub.docker.com/r/centrifugo/centrifugo
+#[derive(Debug, Clone)]
+pub struct Centrifugo {
+    env_vars: BTreeMap<String, String>,
+    config_file: Option<CopyToContainer>,
+}
+
+impl Default for Centrifugo {
+    fn default() -> Self {
+        let mut env_vars = BTreeMap::new();
+        env_vars.insert("CENTRIFUGO_API_KEY".to_owned(), "api_key".to_owned());
+        Self {
+            env_vars,
+            config_file: None,
+        }
+    }
+}
+
+impl Centrifugo {
+    /// Registers the configuration file, copied to `/centrifugo/config.json`.
+    /// Options set with environment variables (e.g. [`Centrifugo::with_api_key`]) take precedence.
+    ///
+    /// # Example
+    /// ```
+    /// use testcontainers_modules::centrifugo::Centrifugo;
+    ///
+    /// let centrifugo = Centrifugo::default().with_config_json(
+    ///     r#"{"token_hmac_secret_key": "secret", "allow_subscribe_for_client": true}"#
+    ///         .to_string()
+    ///         .into_bytes(),
+    /// );
+    /// ```
+    pub fn with_config_json(mut self, config: impl Into<CopyDataSource>) -> Self {
+        self.config_file = Some(CopyToContainer::new(config.into(), CONFIG_FILE_PATH));
+        self
+    }
+
+    /// Sets the key required by the server API (`CENTRIFUGO_API_KEY`), defaults to `api_key`.
+    pub fn with_api_key(mut self, api_key: &str) -> Self {
+        self.env_vars
+            .insert("CENTRIFUGO_API_KEY".to_owned(), api_key.to_owned());
+        self
+    }
+
+    /// Enables the admin UI and API (`CENTRIFUGO_ADMIN`) with the given password and the secret
+    /// used to sign the admin session tokens.
+    pub fn with_admin_password(mut self, password: &str, secret: &str) -> Self {
+        self.env_vars
+            .insert("CENTRIFUGO_ADMIN".to_owned(), "true".to_owned());
+        self.env_vars
+            .insert("CENTRIFUGO_ADMIN_PASSWORD".to_owned(), password.to_owned());
+        self.env_vars
+            .insert("CENTRIFUGO_ADMIN_SECRET".to_owned(), secret.to_owned());
+        self
+    }
+}
+
+impl Image for Centrifugo {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn tag(&self) -> &str {
+        TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![WaitFor::http(
+            HttpWaitStrategy::new("/health")
+                .with_port(CENTRIFUGO_PORT)
+                .with_expected_status_code(200_u16),
+        )]
+    }
+
+    fn env_vars(
+        &self,
+    ) -> impl IntoIterator<Item = (impl Into<Cow<'_, str>>, impl Into<Cow<'_, str>>)> {
+        &self.env_vars
+    }
+
+    fn copy_to_sources(&self) -> impl IntoIterator<Item = &CopyToContainer> {
+        &self.config_file
+    }
+
+    fn cmd(&self) -> impl IntoIterator<Item = impl Into<Cow<'_, str>>> {
+        let mut cmd = vec!["centrifugo".to_owned()];
+        if self.config_file.is_some() {
+            cmd.push(format!("--config={CONFIG_FILE_PATH}"));
+        }
+        cmd
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[CENTRIFUGO_PORT]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testcontainers::runners::SyncRunner;
+
+    #[test]
+    fn centrifugo_server_api() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = Centrifugo::default()
+            .with_api_key("secret")
+            .with_admin_password("password", "admin_secret")
+            .with_config_json(r#"{"namespaces": [{"name": "chat"}]}"#.to_string().into_bytes())
+            .start()?;
+        let url = format!(
+            "http://{}:{}",
+            node.get_host()?,
+            node.get_host_port_ipv4(CENTRIFUGO_PORT)?
+        );
+
+        let client = reqwest::blocking::Client::new();
+        let response = client
+            .post(format!("{url}/api/publish"))
+            .json(&serde_json::json!({"channel": "chat:index", "data": {"text": "hello"}}))
+            .send()?;
+        assert_eq!(response.status(), 401);
+
+        let result: serde_json::Value = client
+            .post(format!("{url}/api/publish"))
+            .header("X-API-Key", "secret")
+            .json(&serde_json::json!({"channel": "chat:index", "data": {"text": "hello"}}))
+            .send()?
+            .error_for_status()?
+            .json()?;
+        assert!(result["error"].is_null(), "{result}");
+
+        let response = client
+            .post(format!("{url}/admin/auth"))
+            .form(&[("password", "password")])
+            .send()?;
+        assert!(response.status().is_success());
+        Ok(())
+    }
+}
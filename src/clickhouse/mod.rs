@@ -2,7 +2,7 @@ use std::{borrow::Cow, collections::BTreeMap};
 
 use testcontainers::{
     core::{wait::HttpWaitStrategy, ContainerPort, WaitFor},
-    Image,
+    CopyDataSource, CopyToContainer, Image,
 };
 
 const DEFAULT_IMAGE_NAME: &str = "clickhouse/clickhouse-server";
@@ -14,10 +14,19 @@ const DEFAULT_IMAGE_TAG: &str = "23.3.8.21-alpine";
 /// [`ClickHouse`]: https://clickhouse.com/
 pub const CLICKHOUSE_PORT: ContainerPort = ContainerPort::Tcp(8123);
 
+/// Port of the native protocol that the [`ClickHouse`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`ClickHouse`]: https://clickhouse.com/
+pub const CLICKHOUSE_NATIVE_PORT: ContainerPort = ContainerPort::Tcp(9000);
+
 /// Module to work with [`ClickHouse`] inside of tests.
 ///
 /// This module is based on the official [`ClickHouse docker image`].
 ///
+/// The HTTP interface is exposed on port 8123 ([`CLICKHOUSE_PORT`]) and the native protocol
+/// on port 9000 ([`CLICKHOUSE_NATIVE_PORT`]).
+///
 /// # Example
 /// ```
 /// use testcontainers_modules::{clickhouse, testcontainers::runners::SyncRunner};
@@ -33,6 +42,53 @@ pub const CLICKHOUSE_PORT: ContainerPort = ContainerPort::Tcp(8123);
 #[derive(Debug, Default, Clone)]
 pub struct ClickHouse {
     env_vars: BTreeMap<String, String>,
+    copy_to_sources: Vec<CopyToContainer>,
+}
+
+impl ClickHouse {
+    /// Sets the name of the user created on startup (`CLICKHOUSE_USER`), defaults to `default`.
+    pub fn with_user(mut self, user: &str) -> Self {
+        self.env_vars
+            .insert("CLICKHOUSE_USER".to_owned(), user.to_owned());
+        self
+    }
+
+    /// Sets the password of the user (`CLICKHOUSE_PASSWORD`), by default no password is required.
+    pub fn with_password(mut self, password: &str) -> Self {
+        self.env_vars
+            .insert("CLICKHOUSE_PASSWORD".to_owned(), password.to_owned());
+        self
+    }
+
+    /// Sets the name of the database created on startup (`CLICKHOUSE_DB`).
+    pub fn with_database(mut self, database: &str) -> Self {
+        self.env_vars
+            .insert("CLICKHOUSE_DB".to_owned(), database.to_owned());
+        self
+    }
+
+    /// Registers sql to be executed automatically when the container starts.
+    /// Can be called multiple times to add (not override) scripts.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use testcontainers_modules::clickhouse::ClickHouse;
+    /// let clickhouse_image = ClickHouse::default().with_init_sql(
+    ///     "CREATE TABLE foo (bar UInt8) ENGINE = Memory;"
+    ///         .to_string()
+    ///         .into_bytes(),
+    /// );
+    /// ```
+    pub fn with_init_sql(mut self, init_sql: impl Into<CopyDataSource>) -> Self {
+        let target = format!(
+            "/docker-entrypoint-initdb.d/init_{i}.sql",
+            i = self.copy_to_sources.len()
+        );
+        self.copy_to_sources
+            .push(CopyToContainer::new(init_sql.into(), target));
+        self
+    }
 }
 
 impl Image for ClickHouse {
@@ -46,7 +102,9 @@ impl Image for ClickHouse {
 
     fn ready_conditions(&self) -> Vec<WaitFor> {
         vec![WaitFor::http(
-            HttpWaitStrategy::new("/").with_expected_status_code(200_u16),
+            HttpWaitStrategy::new("/ping")
+                .with_port(CLICKHOUSE_PORT)
+                .with_expected_status_code(200_u16),
         )]
     }
 
@@ -56,8 +114,12 @@ impl Image for ClickHouse {
         &self.env_vars
     }
 
+    fn copy_to_sources(&self) -> impl IntoIterator<Item = &CopyToContainer> {
+        &self.copy_to_sources
+    }
+
     fn expose_ports(&self) -> &[ContainerPort] {
-        &[CLICKHOUSE_PORT]
+        &[CLICKHOUSE_PORT, CLICKHOUSE_NATIVE_PORT]
     }
 }
 
@@ -110,4 +172,42 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn clickhouse_with_user_and_init_sql() -> Result<(), Box<dyn std::error::Error + 'static>>
+    {
+        let node = ClickhouseImage::default()
+            .with_user("custom_user")
+            .with_password("custom_password")
+            .with_database("custom_db")
+            .with_init_sql(
+                "CREATE TABLE custom_db.t (a UInt8) ENGINE = Memory; INSERT INTO custom_db.t VALUES (1),(2);"
+                    .to_string()
+                    .into_bytes(),
+            )
+            .start()
+            .await?;
+
+        let host = node.get_host().await?;
+        let port = node.get_host_port_ipv4(8123).await?;
+        let url = format!("http://{host}:{port}");
+
+        let response = Client::new()
+            .post(url.clone())
+            .body("SELECT count() FROM t")
+            .send()
+            .await?;
+        assert_ne!(response.status(), 200);
+
+        let response = Client::new()
+            .post(url)
+            .basic_auth("custom_user", Some("custom_password"))
+            .header("X-ClickHouse-Database", "custom_db")
+            .body("SELECT count() FROM t")
+            .send()
+            .await?;
+        assert_eq!(response.status(), 200);
+        assert_eq!(response.text().await?.trim(), "2");
+        Ok(())
+    }
 }
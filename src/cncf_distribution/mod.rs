@@ -1,15 +1,40 @@
-use testcontainers::{core::WaitFor, Image};
+use std::{borrow::Cow, collections::BTreeMap};
+
+use testcontainers::{
+    core::{ContainerPort, WaitFor},
+    CopyDataSource, CopyToContainer, Image,
+};
 
 const NAME: &str = "registry";
 const TAG: &str = "2";
+const HTPASSWD_PATH: &str = "/auth/htpasswd";
+const TLS_CERT_PATH: &str = "/certs/registry.crt";
+const TLS_KEY_PATH: &str = "/certs/registry.key";
+
+/// Port of the registry API that the [`CNCF Distribution`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`CNCF Distribution`]: https://distribution.github.io/distribution/
+pub const REGISTRY_PORT: ContainerPort = ContainerPort::Tcp(5000);
+
+/// Cost of the bcrypt hashes generated by [`CncfDistribution::with_basic_auth`],
+/// kept low as the hashes are only used within tests.
+const BCRYPT_COST: u32 = 4;
 
 /// Module to work with a custom Docker registry inside of tests.
 ///
-/// Starts an instance of [`CNCF Distribution`], an easy-to-use registry for container images.
+/// Starts an instance of [`CNCF Distribution`], an easy-to-use registry for container images,
+/// serving the registry API on port 5000 ([`REGISTRY_PORT`]).
+///
+/// By default, the registry allows anonymous access via plain HTTP.
+/// See [`CncfDistribution::with_basic_auth`] and [`CncfDistribution::with_tls`] to secure it.
 ///
 /// # Example
 /// ```
-/// use testcontainers_modules::{cncf_distribution, testcontainers::runners::SyncRunner};
+/// use testcontainers_modules::{
+///     cncf_distribution::{self, REGISTRY_PORT},
+///     testcontainers::runners::SyncRunner,
+/// };
 ///
 /// let registry = cncf_distribution::CncfDistribution::default()
 ///     .start()
@@ -19,7 +44,7 @@ const TAG: &str = "2";
 /// let image_tag = format!(
 ///     "{}:{}/{image_name}",
 ///     registry.get_host().unwrap(),
-///     registry.get_host_port_ipv4(5000).unwrap()
+///     registry.get_host_port_ipv4(REGISTRY_PORT).unwrap()
 /// );
 ///
 /// // now you can push an image tagged with `image_tag` and pull it afterward
@@ -28,10 +53,64 @@ const TAG: &str = "2";
 /// [`CNCF Distribution`]: https://distribution.github.io/distribution/
 #[derive(Debug, Default, Clone)]
 pub struct CncfDistribution {
-    /// (remove if there is another variable)
-    /// Field is included to prevent this struct to be a unit struct.
-    /// This allows extending functionality (and thus further variables) without breaking changes
-    _priv: (),
+    env_vars: BTreeMap<String, String>,
+    copy_to_sources: Vec<CopyToContainer>,
+}
+
+impl CncfDistribution {
+    /// Requires HTTP basic authentication with the given user and password,
+    /// generating the htpasswd file of the registry.
+    ///
+    /// # Example
+    /// ```
+    /// use testcontainers_modules::cncf_distribution::CncfDistribution;
+    ///
+    /// let registry = CncfDistribution::default().with_basic_auth("user", "password");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the password is longer than 72 bytes, the maximum supported by bcrypt.
+    pub fn with_basic_auth(self, user: &str, password: &str) -> Self {
+        let password_hash = bcrypt::hash(password, BCRYPT_COST).expect("failed to hash password");
+        self.with_htpasswd(format!("{user}:{password_hash}\n").into_bytes())
+    }
+
+    /// Requires HTTP basic authentication with the users of the given htpasswd file (bcrypt hashes only).
+    pub fn with_htpasswd(mut self, htpasswd: impl Into<CopyDataSource>) -> Self {
+        self.copy_to_sources
+            .push(CopyToContainer::new(htpasswd.into(), HTPASSWD_PATH));
+        self.env_vars
+            .insert("REGISTRY_AUTH".to_owned(), "htpasswd".to_owned());
+        self.env_vars.insert(
+            "REGISTRY_AUTH_HTPASSWD_REALM".to_owned(),
+            "Registry Realm".to_owned(),
+        );
+        self.env_vars.insert(
+            "REGISTRY_AUTH_HTPASSWD_PATH".to_owned(),
+            HTPASSWD_PATH.to_owned(),
+        );
+        self
+    }
+
+    /// Serves the registry API via HTTPS with the given PEM encoded certificate and private key.
+    pub fn with_tls(
+        mut self,
+        cert: impl Into<CopyDataSource>,
+        key: impl Into<CopyDataSource>,
+    ) -> Self {
+        self.copy_to_sources
+            .push(CopyToContainer::new(cert.into(), TLS_CERT_PATH));
+        self.copy_to_sources
+            .push(CopyToContainer::new(key.into(), TLS_KEY_PATH));
+        self.env_vars.insert(
+            "REGISTRY_HTTP_TLS_CERTIFICATE".to_owned(),
+            TLS_CERT_PATH.to_owned(),
+        );
+        self.env_vars
+            .insert("REGISTRY_HTTP_TLS_KEY".to_owned(), TLS_KEY_PATH.to_owned());
+        self
+    }
 }
 
 impl Image for CncfDistribution {
@@ -46,6 +125,20 @@ impl Image for CncfDistribution {
     fn ready_conditions(&self) -> Vec<WaitFor> {
         vec![WaitFor::message_on_stderr("listening on [::]:5000")]
     }
+
+    fn env_vars(
+        &self,
+    ) -> impl IntoIterator<Item = (impl Into<Cow<'_, str>>, impl Into<Cow<'_, str>>)> {
+        &self.env_vars
+    }
+
+    fn copy_to_sources(&self) -> impl IntoIterator<Item = &CopyToContainer> {
+        &self.copy_to_sources
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[REGISTRY_PORT]
+    }
 }
 
 #[cfg(test)]
@@ -53,6 +146,7 @@ mod tests {
     use bollard::image::{BuildImageOptions, CreateImageOptions};
     use futures::StreamExt;
 
+    use super::*;
     use crate::{cncf_distribution, testcontainers::runners::AsyncRunner};
 
     const DOCKERFILE: &[u8] = b"
@@ -69,7 +163,7 @@ mod tests {
         let docker = bollard::Docker::connect_with_local_defaults().unwrap();
         let image_tag = format!(
             "localhost:{}/test:latest",
-            distribution_node.get_host_port_ipv4(5000).await?
+            distribution_node.get_host_port_ipv4(REGISTRY_PORT).await?
         );
 
         let mut archive = tar::Builder::new(Vec::new());
@@ -125,4 +219,56 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn distribution_with_basic_auth() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = CncfDistribution::default()
+            .with_basic_auth("user", "password")
+            .start()
+            .await?;
+        let url = format!(
+            "http://{}:{}/v2/",
+            node.get_host().await?,
+            node.get_host_port_ipv4(REGISTRY_PORT).await?
+        );
+        let client = reqwest::Client::new();
+
+        let response = client.get(&url).send().await?;
+        assert_eq!(response.status(), 401);
+
+        let response = client
+            .get(&url)
+            .basic_auth("user", Some("password"))
+            .send()
+            .await?;
+        assert_eq!(response.status(), 200);
+        Ok(())
+    }
+    #[cfg(feature = "tls")]
+    #[tokio::test]
+    async fn distribution_with_tls() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let certificates = crate::tls::TlsCertificates::default();
+        let node = CncfDistribution::default()
+            .with_tls(
+                certificates.cert_pem().as_bytes().to_vec(),
+                certificates.key_pem().as_bytes().to_vec(),
+            )
+            .start()
+            .await?;
+        let url = format!(
+            "https://localhost:{}/v2/",
+            node.get_host_port_ipv4(REGISTRY_PORT).await?
+        );
+        let client = reqwest::Client::builder()
+            .add_root_certificate(reqwest::Certificate::from_pem(
+                certificates.ca_cert_pem().as_bytes(),
+            )?)
+            .build()?;
+
+        let response = client.get(&url).send().await?;
+        assert_eq!(response.status(), 200);
+        Ok(())
+    }
 }
@@ -1,4 +1,4 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, collections::BTreeMap};
 
 use testcontainers::{core::WaitFor, Image};
 
@@ -25,12 +25,49 @@ const DEFAULT_IMAGE_TAG: &str = "v23.2.3";
 #[derive(Debug, Default, Clone)]
 pub struct CockroachDb {
     cmd: CockroachDbCmd,
+    env_vars: BTreeMap<String, String>,
+    cache: Option<String>,
+    max_sql_memory: Option<String>,
 }
 
 impl CockroachDb {
     /// Create a new instance of a CockroachDb image.
     pub fn new(cmd: CockroachDbCmd) -> Self {
-        CockroachDb { cmd }
+        CockroachDb {
+            cmd,
+            ..Default::default()
+        }
+    }
+
+    /// Sets the name of a database to be created when the node starts.
+    ///
+    /// The database is created via the `COCKROACH_DATABASE` environment variable of the image,
+    /// which is only supported by the `start-single-node` command.
+    pub fn with_database(mut self, database: impl Into<String>) -> Self {
+        self.env_vars
+            .insert("COCKROACH_DATABASE".to_owned(), database.into());
+        self
+    }
+
+    /// Sets the size of the node's cache (`--cache`), e.g. `256MiB` or `.25`.
+    ///
+    /// See the [`Cockroach commands`] documentation for the accepted values.
+    ///
+    /// [`Cockroach commands`]: https://www.cockroachlabs.com/docs/stable/cockroach-start-single-node#flags
+    pub fn with_cache(mut self, cache: impl Into<String>) -> Self {
+        self.cache = Some(cache.into());
+        self
+    }
+
+    /// Sets the maximum in-memory storage capacity for temporary SQL data (`--max-sql-memory`),
+    /// e.g. `256MiB` or `.25`.
+    ///
+    /// See the [`Cockroach commands`] documentation for the accepted values.
+    ///
+    /// [`Cockroach commands`]: https://www.cockroachlabs.com/docs/stable/cockroach-start-single-node#flags
+    pub fn with_max_sql_memory(mut self, max_sql_memory: impl Into<String>) -> Self {
+        self.max_sql_memory = Some(max_sql_memory.into());
+        self
     }
 }
 
@@ -76,8 +113,21 @@ impl Image for CockroachDb {
         vec![WaitFor::message_on_stdout("CockroachDB node starting at")]
     }
 
+    fn env_vars(
+        &self,
+    ) -> impl IntoIterator<Item = (impl Into<Cow<'_, str>>, impl Into<Cow<'_, str>>)> {
+        &self.env_vars
+    }
+
     fn cmd(&self) -> impl IntoIterator<Item = impl Into<Cow<'_, str>>> {
-        self.cmd
+        let mut cmd: Vec<String> = self.cmd.into_iter().collect();
+        if let Some(cache) = &self.cache {
+            cmd.push(format!("--cache={cache}"));
+        }
+        if let Some(max_sql_memory) = &self.max_sql_memory {
+            cmd.push(format!("--max-sql-memory={max_sql_memory}"));
+        }
+        cmd
     }
 }
 
@@ -124,4 +174,27 @@ mod tests {
         assert_eq!(first_column, 2);
         Ok(())
     }
+
+    #[test]
+    fn cockroach_db_with_database_and_tuning() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let cockroach = CockroachDb::default()
+            .with_database("test_db")
+            .with_cache("128MiB")
+            .with_max_sql_memory("128MiB");
+        let node = cockroach.start()?;
+
+        let connection_string = &format!(
+            "postgresql://root@127.0.0.1:{}/test_db?sslmode=disable",
+            node.get_host_port_ipv4(26257.tcp())?
+        );
+        let mut conn = postgres::Client::connect(connection_string, postgres::NoTls).unwrap();
+
+        let rows = conn.query("SELECT current_database()", &[]).unwrap();
+        assert_eq!(rows.len(), 1);
+
+        let first_row = &rows[0];
+        let first_column: String = first_row.get(0);
+        assert_eq!(first_column, "test_db");
+        Ok(())
+    }
 }
@@ -1,21 +1,40 @@
 use std::{borrow::Cow, collections::BTreeMap};
 
-use testcontainers::{core::WaitFor, Image};
+use testcontainers::{
+    core::{ContainerPort, WaitFor},
+    CopyDataSource, CopyToContainer, Image,
+};
 
 const DEFAULT_IMAGE_NAME: &str = "hashicorp/consul";
 const DEFAULT_IMAGE_TAG: &str = "1.16.1";
 const CONSUL_LOCAL_CONFIG: &str = "CONSUL_LOCAL_CONFIG";
+const CONFIG_DIR: &str = "/consul/config";
+
+/// Port of the HTTP API that the [`Consul`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Consul`]: https://www.consul.io/
+pub const CONSUL_HTTP_PORT: ContainerPort = ContainerPort::Tcp(8500);
+
+/// Port of the DNS interface that the [`Consul`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Consul`]: https://www.consul.io/
+pub const CONSUL_DNS_PORT: ContainerPort = ContainerPort::Udp(8600);
 
 /// Module to work with [`Consul`] inside of tests.
 ///
-/// This module is based on the official [`Consul docker image`].
+/// This module is based on the official [`Consul docker image`] and starts an agent in development mode.
+/// The HTTP API is exposed on port 8500 ([`CONSUL_HTTP_PORT`]) and the DNS interface on port 8600/udp ([`CONSUL_DNS_PORT`]).
 ///
 /// # Example
 /// ```
 /// use testcontainers_modules::{consul, testcontainers::runners::SyncRunner};
 ///
 /// let consul = consul::Consul::default().start().unwrap();
-/// let http_port = consul.get_host_port_ipv4(8500).unwrap();
+/// let http_port = consul
+///     .get_host_port_ipv4(consul::CONSUL_HTTP_PORT)
+///     .unwrap();
 ///
 /// // do something with the started consul instance..
 /// ```
@@ -25,15 +44,43 @@ const CONSUL_LOCAL_CONFIG: &str = "CONSUL_LOCAL_CONFIG";
 #[derive(Debug, Default, Clone)]
 pub struct Consul {
     env_vars: BTreeMap<String, String>,
+    config_files: Vec<CopyToContainer>,
 }
 
 impl Consul {
-    // not having docs here is currently allowed to address the missing docs problem one place at a time. Helping us by documenting just one of these places helps other devs tremendously
-    #[allow(missing_docs)]
-    pub fn with_local_config(self, config: String) -> Self {
-        let mut env_vars = self.env_vars;
-        env_vars.insert(CONSUL_LOCAL_CONFIG.to_owned(), config);
-        Self { env_vars }
+    /// Sets the agent configuration as JSON string (`CONSUL_LOCAL_CONFIG`).
+    pub fn with_local_config(mut self, config: String) -> Self {
+        self.env_vars.insert(CONSUL_LOCAL_CONFIG.to_owned(), config);
+        self
+    }
+
+    /// Registers a JSON configuration file, which is copied into the configuration directory of the agent.
+    /// Can be called multiple times to add (not override) configuration files.
+    ///
+    /// # Example
+    /// ```
+    /// use testcontainers_modules::consul::Consul;
+    ///
+    /// let consul = Consul::default().with_config_json(r#"{"node_name": "test-node"}"#.to_string().into_bytes());
+    /// ```
+    pub fn with_config_json(mut self, config: impl Into<CopyDataSource>) -> Self {
+        let target = format!(
+            "{CONFIG_DIR}/testcontainers_{}.json",
+            self.config_files.len()
+        );
+        self.config_files
+            .push(CopyToContainer::new(config.into(), target));
+        self
+    }
+
+    /// Enables ACLs denying all requests by default, with the given token as
+    /// [initial management token](https://developer.hashicorp.com/consul/docs/security/acl/tokens/initial-management),
+    /// which has to be passed to the API (e.g. via `X-Consul-Token` header).
+    pub fn with_acl_bootstrap(self, management_token: &str) -> Self {
+        let config = format!(
+            r#"{{"acl": {{"enabled": true, "default_policy": "deny", "tokens": {{"initial_management": "{management_token}"}}}}}}"#
+        );
+        self.with_config_json(config.into_bytes())
     }
 }
 
@@ -55,13 +102,24 @@ impl Image for Consul {
     ) -> impl IntoIterator<Item = (impl Into<Cow<'_, str>>, impl Into<Cow<'_, str>>)> {
         &self.env_vars
     }
+
+    fn copy_to_sources(&self) -> impl IntoIterator<Item = &CopyToContainer> {
+        &self.config_files
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[CONSUL_HTTP_PORT, CONSUL_DNS_PORT]
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use serde_json::Value;
 
-    use crate::{consul::Consul, testcontainers::runners::AsyncRunner};
+    use crate::{
+        consul::{Consul, CONSUL_HTTP_PORT},
+        testcontainers::runners::AsyncRunner,
+    };
 
     #[tokio::test]
     async fn consul_container() -> Result<(), Box<dyn std::error::Error + 'static>> {
@@ -88,4 +146,28 @@ mod tests {
         assert_eq!("dc-rust", dc);
         Ok(())
     }
+
+    #[tokio::test]
+    async fn consul_with_acl_bootstrap_and_config_json(
+    ) -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let consul = Consul::default()
+            .with_config_json(r#"{"node_name": "test-node"}"#.to_string().into_bytes())
+            .with_acl_bootstrap("root-token");
+        let node = consul.start().await?;
+        let port = node.get_host_port_ipv4(CONSUL_HTTP_PORT).await?;
+        let url = format!("http://localhost:{port}/v1/agent/self");
+
+        let response = reqwest::Client::new().get(&url).send().await?;
+        assert_eq!(response.status(), 403);
+
+        let response = reqwest::Client::new()
+            .get(&url)
+            .header("X-Consul-Token", "root-token")
+            .send()
+            .await?
+            .json::<Value>()
+            .await?;
+        assert_eq!("test-node", response["Config"]["NodeName"]);
+        Ok(())
+    }
 }
@@ -0,0 +1,151 @@
+use std::{borrow::Cow, collections::BTreeMap};
+
+use testcontainers::{
+    core::{wait::HttpWaitStrategy, ContainerPort, WaitFor},
+    CopyToContainer, Image,
+};
+
+const NAME: &str = "couchdb";
+const TAG: &str = "3.4.2";
+const SINGLE_NODE_CONFIG_PATH: &str = "/opt/couchdb/etc/local.d/testcontainers.ini";
+
+/// Port that the [`CouchDB`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`CouchDB`]: https://couchdb.apache.org/
+pub const COUCHDB_PORT: ContainerPort = ContainerPort::Tcp(5984);
+
+/// Module to work with [`CouchDB`] inside of tests.
+///
+/// Starts a single node instance of CouchDB based on the official [`CouchDB docker image`],
+/// serving the HTTP API on port 5984 ([`COUCHDB_PORT`]).
+///
+/// CouchDB requires an admin user, which is `admin` with password `password` by default,
+/// see [`CouchDb::with_admin_user`] and [`CouchDb::with_admin_password`].
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{
+///     couchdb::{CouchDb, COUCHDB_PORT},
+///     testcontainers::runners::SyncRunner,
+/// };
+///
+/// let couchdb_instance = CouchDb::default()
+///     .with_system_databases()
+///     .start()
+///     .unwrap();
+/// let url = format!(
+///     "http://admin:password@{}:{}",
+///     couchdb_instance.get_host().unwrap(),
+///     couchdb_instance.get_host_port_ipv4(COUCHDB_PORT).unwrap()
+/// );
+/// ```
+///
+/// [`CouchDB`]: https://couchdb.apache.org/
+/// [`CouchDB docker image`]: https://hub.docker.com/_/couchdb
+#[derive(Debug, Clone)]
+pub struct CouchDb {
+    env_vars: BTreeMap<String, String>,
+    single_node_config: Option<CopyToContainer>,
+}
+
+impl Default for CouchDb {
+    fn default() -> Self {
+        let mut env_vars = BTreeMap::new();
+        env_vars.insert("COUCHDB_USER".to_owned(), "admin".to_owned());
+        env_vars.insert("COUCHDB_PASSWORD".to_owned(), "password".to_owned());
+        Self {
+            env_vars,
+            single_node_config: None,
+        }
+    }
+}
+
+impl CouchDb {
+    /// Sets the name of the admin user (`COUCHDB_USER`), defaults to `admin`.
+    pub fn with_admin_user(mut self, user: &str) -> Self {
+        self.env_vars
+            .insert("COUCHDB_USER".to_owned(), user.to_owned());
+        self
+    }
+
+    /// Sets the password of the admin user (`COUCHDB_PASSWORD`), defaults to `password`.
+    pub fn with_admin_password(mut self, password: &str) -> Self {
+        self.env_vars
+            .insert("COUCHDB_PASSWORD".to_owned(), password.to_owned());
+        self
+    }
+
+    /// Configures the node as single node setup, thus the system databases (`_users` and `_replicator`)
+    /// are created on startup and CouchDB doesn't log warnings about missing databases.
+    pub fn with_system_databases(mut self) -> Self {
+        self.single_node_config = Some(CopyToContainer::new(
+            "[couchdb]\nsingle_node = true\n".to_string().into_bytes(),
+            SINGLE_NODE_CONFIG_PATH,
+        ));
+        self
+    }
+}
+
+impl Image for CouchDb {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn tag(&self) -> &str {
+        TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![WaitFor::http(
+            HttpWaitStrategy::new("/_up")
+                .with_port(COUCHDB_PORT)
+                .with_expected_status_code(200_u16),
+        )]
+    }
+
+    fn env_vars(
+        &self,
+    ) -> impl IntoIterator<Item = (impl Into<Cow<'_, str>>, impl Into<Cow<'_, str>>)> {
+        &self.env_vars
+    }
+
+    fn copy_to_sources(&self) -> impl IntoIterator<Item = &CopyToContainer> {
+        &self.single_node_config
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[COUCHDB_PORT]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testcontainers::runners::SyncRunner;
+
+    #[test]
+    fn couchdb_with_system_databases() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = CouchDb::default()
+            .with_admin_user("root")
+            .with_admin_password("secret")
+            .with_system_databases()
+            .start()?;
+        let url = format!(
+            "http://{}:{}/_all_dbs",
+            node.get_host()?,
+            node.get_host_port_ipv4(COUCHDB_PORT)?
+        );
+
+        let databases: Vec<String> = reqwest::blocking::Client::new()
+            .get(url)
+            .basic_auth("root", Some("secret"))
+            .send()?
+            .error_for_status()?
+            .json()?;
+        assert!(databases.contains(&"_users".to_owned()));
+        assert!(databases.contains(&"_replicator".to_owned()));
+        Ok(())
+    }
+}
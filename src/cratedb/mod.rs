@@ -0,0 +1,135 @@
+use std::{borrow::Cow, collections::BTreeMap};
+
+use testcontainers::{
+    core::{ContainerPort, WaitFor},
+    Image,
+};
+
+const NAME: &str = "crate";
+const TAG: &str = "5.9.2";
+
+/// Port of the HTTP endpoint that the [`CrateDB`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`CrateDB`]: https://cratedb.com/
+pub const CRATEDB_HTTP_PORT: ContainerPort = ContainerPort::Tcp(4200);
+
+/// Port of the Postgres wire protocol endpoint that the [`CrateDB`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`CrateDB`]: https://cratedb.com/
+pub const CRATEDB_PG_PORT: ContainerPort = ContainerPort::Tcp(5432);
+
+/// Module to work with [`CrateDB`] inside of tests.
+///
+/// Starts a single node instance of CrateDB based on the official [`CrateDB docker image`].
+///
+/// The HTTP endpoint is exposed on port 4200 ([`CRATEDB_HTTP_PORT`]) and the Postgres wire
+/// protocol on port 5432 ([`CRATEDB_PG_PORT`]). The default user is `crate` without a password,
+/// the default schema is `doc`.
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{
+///     cratedb::{CrateDb, CRATEDB_PG_PORT},
+///     testcontainers::runners::SyncRunner,
+/// };
+///
+/// let cratedb_instance = CrateDb::default().start().unwrap();
+///
+/// let connection_string = format!(
+///     "postgres://crate@{}:{}/doc",
+///     cratedb_instance.get_host().unwrap(),
+///     cratedb_instance
+///         .get_host_port_ipv4(CRATEDB_PG_PORT)
+///         .unwrap()
+/// );
+/// ```
+///
+/// [`CrateDB`]: https://cratedb.com/
+/// [`CrateDB docker image`]: https://hub.docker.com/_/crate
+#[derive(Debug, Default, Clone)]
+pub struct CrateDb {
+    env_vars: BTreeMap<String, String>,
+    settings: BTreeMap<String, String>,
+}
+
+impl CrateDb {
+    /// Sets the heap size of the CrateDB node (`CRATE_HEAP_SIZE`), e.g. `512m` or `1g`.
+    pub fn with_heap_size(mut self, heap_size: &str) -> Self {
+        self.env_vars
+            .insert("CRATE_HEAP_SIZE".to_owned(), heap_size.to_owned());
+        self
+    }
+
+    /// Sets a [node setting](https://cratedb.com/docs/crate/reference/en/latest/config/node.html),
+    /// passed as `-Ckey=value` to the `crate` command.
+    pub fn with_setting(mut self, key: &str, value: &str) -> Self {
+        self.settings.insert(key.to_owned(), value.to_owned());
+        self
+    }
+}
+
+impl Image for CrateDb {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn tag(&self) -> &str {
+        TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![WaitFor::message_on_stdout("] started")]
+    }
+
+    fn env_vars(
+        &self,
+    ) -> impl IntoIterator<Item = (impl Into<Cow<'_, str>>, impl Into<Cow<'_, str>>)> {
+        &self.env_vars
+    }
+
+    fn cmd(&self) -> impl IntoIterator<Item = impl Into<Cow<'_, str>>> {
+        let mut cmd = vec![
+            "crate".to_owned(),
+            "-Cdiscovery.type=single-node".to_owned(),
+        ];
+        for (key, value) in &self.settings {
+            cmd.push(format!("-C{key}={value}"));
+        }
+        cmd
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[CRATEDB_HTTP_PORT, CRATEDB_PG_PORT]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testcontainers::runners::SyncRunner;
+
+    #[test]
+    fn cratedb_query_via_postgres_wire() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = CrateDb::default()
+            .with_heap_size("512m")
+            .with_setting("cluster.name", "test-cluster")
+            .start()?;
+
+        let connection_string = &format!(
+            "postgres://crate@{}:{}/doc",
+            node.get_host()?,
+            node.get_host_port_ipv4(CRATEDB_PG_PORT)?
+        );
+        let mut conn = postgres::Client::connect(connection_string, postgres::NoTls).unwrap();
+
+        let rows = conn.query("SELECT name FROM sys.cluster", &[]).unwrap();
+        assert_eq!(rows.len(), 1);
+
+        let cluster_name: String = rows[0].get(0);
+        assert_eq!(cluster_name, "test-cluster");
+        Ok(())
+    }
+}
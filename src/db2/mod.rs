@@ -0,0 +1,162 @@
+use std::{borrow::Cow, collections::BTreeMap, time::Duration};
+
+use testcontainers::{
+    core::{ContainerPort, WaitFor},
+    Image,
+};
+
+const NAME: &str = "icr.io/db2_community/db2";
+const TAG: &str = "11.5.9.0";
+
+/// Port of the DRDA endpoint that the [`Db2`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Db2`]: https://www.ibm.com/db2
+pub const DB2_PORT: ContainerPort = ContainerPort::Tcp(50000);
+
+/// Recommended startup timeout for [`Db2`], to be set via
+/// [`testcontainers::core::ImageExt::with_startup_timeout`], as the setup of the instance
+/// and the database takes several minutes, exceeding the default startup timeout of testcontainers.
+pub const DB2_STARTUP_TIMEOUT: Duration = Duration::from_secs(600);
+
+/// Module to work with [`IBM Db2`] Community Edition inside of tests.
+///
+/// Starts an instance of Db2 based on the official [`Db2 Community docker image`], accepting clients on
+/// port 50000 ([`DB2_PORT`]). The instance user `db2inst1` with the password `password` owns the database `test`,
+/// see [`Db2::with_user`], [`Db2::with_password`] and [`Db2::with_database`].
+///
+/// Due to licensing restrictions the license has to be accepted explicitly with [`Db2::with_accept_license`].
+/// The image has to run in a privileged container (see [`testcontainers::core::ImageExt::with_privileged`])
+/// and, as the setup takes several minutes, requires a longer startup timeout (see [`DB2_STARTUP_TIMEOUT`]).
+/// Only amd64 images are available.
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{
+///     db2::{Db2, DB2_PORT, DB2_STARTUP_TIMEOUT},
+///     testcontainers::{runners::SyncRunner, ImageExt},
+/// };
+///
+/// let db2_instance = Db2::default()
+///     .with_accept_license()
+///     .with_privileged(true)
+///     .with_startup_timeout(DB2_STARTUP_TIMEOUT)
+///     .start()
+///     .unwrap();
+/// let connection_string = format!(
+///     "DATABASE=test;HOSTNAME={};PORT={};PROTOCOL=TCPIP;UID=db2inst1;PWD=password;",
+///     db2_instance.get_host().unwrap(),
+///     db2_instance.get_host_port_ipv4(DB2_PORT).unwrap()
+/// );
+/// ```
+///
+/// [`IBM Db2`]: https://www.ibm.com/db2
+/// [`Db2 Community docker image`]: https://www.ibm.com/docs/en/db2/11.5?topic=system-linux
+#[derive(Debug, Clone)]
+pub struct Db2 {
+    env_vars: BTreeMap<String, String>,
+}
+
+impl Default for Db2 {
+    fn default() -> Self {
+        let mut env_vars = BTreeMap::new();
+        env_vars.insert("DB2INSTANCE".to_owned(), "db2inst1".to_owned());
+        env_vars.insert("DB2INST1_PASSWORD".to_owned(), "password".to_owned());
+        env_vars.insert("DBNAME".to_owned(), "test".to_owned());
+        // skips time consuming setup steps, which are not needed in tests
+        env_vars.insert("ARCHIVE_LOGS".to_owned(), "false".to_owned());
+        env_vars.insert("AUTOCONFIG".to_owned(), "false".to_owned());
+        Self { env_vars }
+    }
+}
+
+impl Db2 {
+    /// Due to licensing restrictions you are required to explicitly accept the license of Db2 Community Edition
+    /// (`LICENSE=accept`), otherwise the container does not start.
+    pub fn with_accept_license(mut self) -> Self {
+        self.env_vars
+            .insert("LICENSE".to_owned(), "accept".to_owned());
+        self
+    }
+
+    /// Sets the name of the database created on startup (`DBNAME`), defaults to `test`.
+    /// Db2 restricts database names to 8 characters.
+    pub fn with_database(mut self, database: &str) -> Self {
+        self.env_vars
+            .insert("DBNAME".to_owned(), database.to_owned());
+        self
+    }
+
+    /// Sets the name of the instance (`DB2INSTANCE`), which is also the user owning the database,
+    /// defaults to `db2inst1`.
+    pub fn with_user(mut self, user: &str) -> Self {
+        self.env_vars
+            .insert("DB2INSTANCE".to_owned(), user.to_owned());
+        self
+    }
+
+    /// Sets the password of the instance user (`DB2INST1_PASSWORD`), defaults to `password`.
+    pub fn with_password(mut self, password: &str) -> Self {
+        self.env_vars
+            .insert("DB2INST1_PASSWORD".to_owned(), password.to_owned());
+        self
+    }
+}
+
+impl Image for Db2 {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn tag(&self) -> &str {
+        TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![WaitFor::message_on_stdout("Setup has completed")]
+    }
+
+    fn env_vars(
+        &self,
+    ) -> impl IntoIterator<Item = (impl Into<Cow<'_, str>>, impl Into<Cow<'_, str>>)> {
+        &self.env_vars
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[DB2_PORT]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use testcontainers::core::{CmdWaitFor, ExecCommand};
+
+    use super::*;
+    use crate::testcontainers::{runners::SyncRunner, ImageExt};
+
+    #[test]
+    fn db2_select_one() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = Db2::default()
+            .with_accept_license()
+            .with_database("tcdb")
+            .with_password("secret")
+            .with_privileged(true)
+            .with_startup_timeout(DB2_STARTUP_TIMEOUT)
+            .start()?;
+
+        let mut result = node.exec(
+            ExecCommand::new([
+                "su",
+                "-",
+                "db2inst1",
+                "-c",
+                "db2 connect to tcdb user db2inst1 using secret && db2 'VALUES 1 + 1'",
+            ])
+            .with_cmd_ready_condition(CmdWaitFor::exit_code(0)),
+        )?;
+        let stdout = String::from_utf8(result.stdout_to_vec()?)?;
+        assert!(stdout.contains("1 record(s) selected."), "{stdout}");
+        Ok(())
+    }
+}
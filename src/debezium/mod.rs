@@ -0,0 +1,195 @@
+use std::collections::BTreeMap;
+
+use testcontainers::{
+    core::{wait::HttpWaitStrategy, ContainerPort, WaitFor},
+    CopyToContainer, Image,
+};
+
+const NAME: &str = "quay.io/debezium/server";
+const TAG: &str = "2.7.3.Final";
+const CONFIG_FILE_PATH: &str = "/debezium/conf/application.properties";
+
+/// Port of the HTTP server (health checks) that the [`Debezium Server`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Debezium Server`]: https://debezium.io/documentation/reference/stable/operations/debezium-server.html
+pub const DEBEZIUM_SERVER_PORT: ContainerPort = ContainerPort::Tcp(8080);
+
+/// Module to work with [`Debezium Server`] inside of tests.
+///
+/// Starts an instance of Debezium Server based on the official [`Debezium Server docker image`],
+/// which streams the changes of a source database to a sink (e.g. Kafka, Redis, HTTP) without a Kafka Connect cluster.
+///
+/// The server is configured entirely with [`DebeziumServer::with_property`], the properties are written to
+/// `/debezium/conf/application.properties`. By default only the offsets are stored in `/tmp/offsets.dat`,
+/// so at least the `debezium.source.*` and `debezium.sink.*` properties have to be set.
+/// The source database and the sink are usually other containers in the same network
+/// (see [`testcontainers::core::ImageExt::with_network`]), e.g. a
+/// `postgres::Postgres` started with `postgres::Postgres::with_logical_replication`.
+///
+/// The container is ready once the health check on port 8080 ([`DEBEZIUM_SERVER_PORT`]) reports
+/// the source connector as running.
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{debezium::DebeziumServer, testcontainers::ImageExt};
+///
+/// let debezium = DebeziumServer::default()
+///     .with_property(
+///         "debezium.source.connector.class",
+///         "io.debezium.connector.postgresql.PostgresConnector",
+///     )
+///     .with_property("debezium.source.database.hostname", "postgres")
+///     .with_property("debezium.source.database.port", "5432")
+///     .with_property("debezium.source.database.user", "postgres")
+///     .with_property("debezium.source.database.password", "postgres")
+///     .with_property("debezium.source.database.dbname", "postgres")
+///     .with_property("debezium.source.topic.prefix", "test")
+///     .with_property("debezium.source.plugin.name", "pgoutput")
+///     .with_property("debezium.sink.type", "redis")
+///     .with_property("debezium.sink.redis.address", "redis:6379")
+///     .with_network("cdc-example");
+/// ```
+///
+/// [`Debezium Server`]: https://debezium.io/documentation/reference/stable/operations/debezium-server.html
+/// [`Debezium Server docker image`]: https://quay.io/repository/debezium/server
+#[derive(Debug, Clone)]
+pub struct DebeziumServer {
+    properties: BTreeMap<String, String>,
+    config_file: CopyToContainer,
+}
+
+impl Default for DebeziumServer {
+    fn default() -> Self {
+        let mut properties = BTreeMap::new();
+        properties.insert(
+            "debezium.source.offset.storage.file.filename".to_owned(),
+            "/tmp/offsets.dat".to_owned(),
+        );
+        properties.insert(
+            "debezium.source.offset.flush.interval.ms".to_owned(),
+            "0".to_owned(),
+        );
+        Self::from_properties(properties)
+    }
+}
+
+impl DebeziumServer {
+    fn from_properties(properties: BTreeMap<String, String>) -> Self {
+        let content: String = properties
+            .iter()
+            .map(|(key, value)| format!("{key}={value}\n"))
+            .collect();
+        Self {
+            config_file: CopyToContainer::new(content.into_bytes(), CONFIG_FILE_PATH),
+            properties,
+        }
+    }
+
+    /// Sets a property of `application.properties`, overriding a previously set value of the same key,
+    /// e.g. `with_property("debezium.sink.type", "kafka")`.
+    pub fn with_property(self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        let mut properties = self.properties;
+        properties.insert(key.into(), value.into());
+        Self::from_properties(properties)
+    }
+}
+
+impl Image for DebeziumServer {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn tag(&self) -> &str {
+        TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![WaitFor::http(
+            HttpWaitStrategy::new("/q/health")
+                .with_port(DEBEZIUM_SERVER_PORT)
+                .with_expected_status_code(200_u16),
+        )]
+    }
+
+    fn copy_to_sources(&self) -> impl IntoIterator<Item = &CopyToContainer> {
+        [&self.config_file]
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[DEBEZIUM_SERVER_PORT]
+    }
+}
+
+#[cfg(all(test, feature = "postgres", feature = "redis"))]
+mod tests {
+    use retry::{delay::Fixed, retry};
+
+    use super::*;
+    use crate::{
+        postgres::Postgres,
+        redis::Redis,
+        testcontainers::{runners::SyncRunner, ImageExt},
+    };
+
+    #[test]
+    fn debezium_postgres_to_redis() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let network = "testcontainers-debezium";
+        let postgres_alias = "testcontainers-debezium-postgres";
+        let redis_alias = "testcontainers-debezium-redis";
+        let _postgres_node = Postgres::default()
+            .with_logical_replication()
+            .with_init_sql(
+                "CREATE TABLE foo (id int PRIMARY KEY, bar text);\n\
+                 INSERT INTO foo (id, bar) VALUES (1, 'blub');\n"
+                    .to_string()
+                    .into_bytes(),
+            )
+            .with_network(network)
+            .with_container_name(postgres_alias)
+            .start()?;
+        let redis_node = Redis::default()
+            .with_network(network)
+            .with_container_name(redis_alias)
+            .start()?;
+        let _debezium_node = DebeziumServer::default()
+            .with_property(
+                "debezium.source.connector.class",
+                "io.debezium.connector.postgresql.PostgresConnector",
+            )
+            .with_property("debezium.source.database.hostname", postgres_alias)
+            .with_property("debezium.source.database.port", "5432")
+            .with_property("debezium.source.database.user", "postgres")
+            .with_property("debezium.source.database.password", "postgres")
+            .with_property("debezium.source.database.dbname", "postgres")
+            .with_property("debezium.source.topic.prefix", "test")
+            .with_property("debezium.source.plugin.name", "pgoutput")
+            .with_property("debezium.source.table.include.list", "public.foo")
+            .with_property("debezium.sink.type", "redis")
+            .with_property("debezium.sink.redis.address", format!("{redis_alias}:6379"))
+            .with_network(network)
+            .start()?;
+
+        let url = format!(
+            "redis://{}:{}",
+            redis_node.get_host()?,
+            redis_node.get_host_port_ipv4(crate::redis::REDIS_PORT)?
+        );
+        let mut con = redis::Client::open(url)?.get_connection()?;
+        let len = retry(Fixed::from_millis(500).take(60), || {
+            let len: usize = redis::cmd("XLEN")
+                .arg("test.public.foo")
+                .query(&mut con)
+                .map_err(|err| err.to_string())?;
+            if len > 0 {
+                Ok(len)
+            } else {
+                Err("no change event streamed yet".to_owned())
+            }
+        })
+        .map_err(|err| err.to_string())?;
+        assert_eq!(len, 1);
+        Ok(())
+    }
+}
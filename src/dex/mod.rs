@@ -0,0 +1,231 @@
+use std::borrow::Cow;
+
+use testcontainers::{
+    core::{ContainerPort, WaitFor},
+    CopyDataSource, CopyToContainer, Image,
+};
+
+const NAME: &str = "ghcr.io/dexidp/dex";
+const TAG: &str = "v2.41.1";
+const CONFIG_FILE_PATH: &str = "/etc/dex/testcontainers.yaml";
+
+/// Port of the HTTP endpoint that the [`Dex`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Dex`]: https://dexidp.io/
+pub const DEX_PORT: ContainerPort = ContainerPort::Tcp(5556);
+
+/// Bcrypt hash of the password `password`, to be used with [`Dex::with_static_password`].
+pub const PASSWORD_HASH: &str = "$2a$10$2b2cU8CPhOTaGrs1HRQuAueS7JTT5ZHsHSzYiFPm1leZck7Mc8T4W";
+
+#[derive(Debug, Clone)]
+struct StaticClient {
+    id: String,
+    secret: String,
+    redirect_uri: String,
+}
+
+#[derive(Debug, Clone)]
+struct StaticPassword {
+    email: String,
+    username: String,
+    hash: String,
+}
+
+/// Module to work with [`Dex`] inside of tests.
+///
+/// Starts an OpenID Connect provider based on the official [`Dex docker image`], which keeps its state in memory.
+///
+/// By default the configuration is generated from the issuer (`http://127.0.0.1:5556/dex`, see [`Dex::with_issuer`]),
+/// the static clients ([`Dex::with_static_client`]) and the static passwords ([`Dex::with_static_password`]).
+/// The password grant is enabled, so tests can obtain tokens without a browser based login flow.
+/// Alternatively a complete configuration can be provided with [`Dex::with_config_yaml`].
+///
+/// The HTTP endpoint is exposed on port 5556 ([`DEX_PORT`]).
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{
+///     dex::{Dex, DEX_PORT, PASSWORD_HASH},
+///     testcontainers::{runners::SyncRunner, ImageExt},
+/// };
+///
+/// let dex_instance = Dex::default()
+///     .with_static_client("example-app", "example-secret", "http://127.0.0.1:5555/callback")
+///     .with_static_password("admin@example.com", "admin", PASSWORD_HASH)
+///     .with_mapped_port(5556, DEX_PORT)
+///     .start()
+///     .unwrap();
+/// let discovery_url = "http://127.0.0.1:5556/dex/.well-known/openid-configuration";
+/// ```
+///
+/// [`Dex`]: https://dexidp.io/
+/// [`Dex docker image`]: https://github.com/dexidp/dex/pkgs/container/dex
+#[derive(Debug, Clone)]
+pub struct Dex {
+    issuer: String,
+    static_clients: Vec<StaticClient>,
+    static_passwords: Vec<StaticPassword>,
+    config_file: Option<CopyToContainer>,
+}
+
+impl Default for Dex {
+    fn default() -> Self {
+        Self {
+            issuer: format!("http://127.0.0.1:{}/dex", DEX_PORT.as_u16()),
+            static_clients: Vec::new(),
+            static_passwords: Vec::new(),
+            config_file: None,
+        }
+    }
+}
+
+impl Dex {
+    /// Sets the issuer url of the generated configuration, defaults to `http://127.0.0.1:5556/dex`.
+    ///
+    /// The path of the issuer is used as prefix of all endpoints. As OpenID Connect clients usually validate
+    /// the issuer, it should match the url used to reach the container, e.g. by mapping the port to a fixed host port.
+    pub fn with_issuer(mut self, issuer: &str) -> Self {
+        self.issuer = issuer.to_owned();
+        self
+    }
+
+    /// Registers an OAuth2 client in the generated configuration.
+    pub fn with_static_client(mut self, id: &str, secret: &str, redirect_uri: &str) -> Self {
+        self.static_clients.push(StaticClient {
+            id: id.to_owned(),
+            secret: secret.to_owned(),
+            redirect_uri: redirect_uri.to_owned(),
+        });
+        self
+    }
+
+    /// Registers a user of the local password database in the generated configuration.
+    ///
+    /// The password has to be hashed with bcrypt, e.g. by `htpasswd -bnBC 10 "" password | tr -d ':\n'`,
+    /// or [`PASSWORD_HASH`] can be used for the password `password`.
+    pub fn with_static_password(mut self, email: &str, username: &str, hash: &str) -> Self {
+        self.static_passwords.push(StaticPassword {
+            email: email.to_owned(),
+            username: username.to_owned(),
+            hash: hash.to_owned(),
+        });
+        self
+    }
+
+    /// Registers a complete Dex configuration, which is used instead of the generated one.
+    /// The web server has to listen on port 5556 ([`DEX_PORT`]) to be detected as ready.
+    pub fn with_config_yaml(mut self, config: impl Into<CopyDataSource>) -> Self {
+        self.config_file = Some(CopyToContainer::new(config.into(), CONFIG_FILE_PATH));
+        self
+    }
+
+    fn config(&self) -> String {
+        let mut config = format!(
+            "issuer: {}\nstorage:\n  type: memory\nweb:\n  http: 0.0.0.0:{}\noauth2:\n  passwordConnector: local\nenablePasswordDB: true\n",
+            self.issuer,
+            DEX_PORT.as_u16()
+        );
+        if !self.static_clients.is_empty() {
+            config.push_str("staticClients:\n");
+            for client in &self.static_clients {
+                config.push_str(&format!(
+                    "- id: '{}'\n  secret: '{}'\n  name: '{}'\n  redirectURIs:\n  - '{}'\n",
+                    client.id, client.secret, client.id, client.redirect_uri
+                ));
+            }
+        }
+        if !self.static_passwords.is_empty() {
+            config.push_str("staticPasswords:\n");
+            for (i, password) in self.static_passwords.iter().enumerate() {
+                config.push_str(&format!(
+                    "- email: '{}'\n  hash: '{}'\n  username: '{}'\n  userID: 'testcontainers-{i}'\n",
+                    password.email, password.hash, password.username
+                ));
+            }
+        }
+        config
+    }
+}
+
+impl Image for Dex {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn tag(&self) -> &str {
+        TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![WaitFor::message_on_stderr("listening on")]
+    }
+
+    fn copy_to_sources(&self) -> impl IntoIterator<Item = &CopyToContainer> {
+        &self.config_file
+    }
+
+    fn cmd(&self) -> impl IntoIterator<Item = impl Into<Cow<'_, str>>> {
+        if self.config_file.is_some() {
+            return vec![
+                "dex".to_owned(),
+                "serve".to_owned(),
+                CONFIG_FILE_PATH.to_owned(),
+            ];
+        }
+
+        vec![
+            "sh".to_owned(),
+            "-c".to_owned(),
+            format!(
+                "cat > {CONFIG_FILE_PATH} <<'EOF'\n{}EOF\nexec dex serve {CONFIG_FILE_PATH}",
+                self.config()
+            ),
+        ]
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[DEX_PORT]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testcontainers::runners::SyncRunner;
+
+    #[test]
+    fn dex_password_grant() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = Dex::default()
+            .with_static_client("test-client", "test-secret", "http://127.0.0.1/callback")
+            .with_static_password("admin@example.com", "admin", PASSWORD_HASH)
+            .start()?;
+        let url = format!(
+            "http://{}:{}/dex",
+            node.get_host()?,
+            node.get_host_port_ipv4(DEX_PORT)?
+        );
+
+        let configuration: serde_json::Value =
+            reqwest::blocking::get(format!("{url}/.well-known/openid-configuration"))?
+                .error_for_status()?
+                .json()?;
+        assert_eq!(configuration["issuer"], "http://127.0.0.1:5556/dex");
+
+        let token: serde_json::Value = reqwest::blocking::Client::new()
+            .post(format!("{url}/token"))
+            .basic_auth("test-client", Some("test-secret"))
+            .form(&[
+                ("grant_type", "password"),
+                ("scope", "openid email"),
+                ("username", "admin@example.com"),
+                ("password", "password"),
+            ])
+            .send()?
+            .error_for_status()?
+            .json()?;
+        assert!(token["id_token"].is_string());
+        Ok(())
+    }
+}
@@ -1,17 +1,93 @@
-use testcontainers::{core::WaitFor, Image};
+use std::borrow::Cow;
+
+use testcontainers::{
+    core::{ContainerPort, WaitFor},
+    ContainerAsync, Image, TestcontainersError,
+};
 
 const NAME: &str = "amazon/dynamodb-local";
 const TAG: &str = "2.0.0";
 const DEFAULT_WAIT: u64 = 3000;
 
-#[allow(missing_docs)]
-// not having docs here is currently allowed to address the missing docs problem one place at a time. Helping us by documenting just one of these places helps other devs tremendously
+/// Port that the [`DynamoDB Local`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`DynamoDB Local`]: https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/DynamoDBLocal.html
+pub const DYNAMODB_PORT: ContainerPort = ContainerPort::Tcp(8000);
+
+/// Module to work with [`DynamoDB Local`] inside of tests.
+///
+/// Starts an instance of DynamoDB Local based on the official [`DynamoDB Local docker image`],
+/// serving requests on port 8000 ([`DYNAMODB_PORT`]).
+///
+/// Without further configuration the image keeps its data in memory. Once one of the
+/// [documented options] is configured with the builder methods, the data is stored in a database file,
+/// unless [`DynamoDb::with_in_memory`] is used as well.
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{
+///     dynamodb_local::{DynamoDb, DYNAMODB_PORT},
+///     testcontainers::runners::SyncRunner,
+/// };
+///
+/// let dynamodb_instance = DynamoDb::default().start().unwrap();
+/// let endpoint_url = format!(
+///     "http://{}:{}",
+///     dynamodb_instance.get_host().unwrap(),
+///     dynamodb_instance.get_host_port_ipv4(DYNAMODB_PORT).unwrap()
+/// );
+/// ```
+///
+/// [`DynamoDB Local`]: https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/DynamoDBLocal.html
+/// [`DynamoDB Local docker image`]: https://hub.docker.com/r/amazon/dynamodb-local
+/// [documented options]: https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/DynamoDBLocal.UsageNotes.html
 #[derive(Default, Debug, Clone)]
 pub struct DynamoDb {
-    /// (remove if there is another variable)
-    /// Field is included to prevent this struct to be a unit struct.
-    /// This allows extending functionality (and thus further variables) without breaking changes
-    _priv: (),
+    shared_db: bool,
+    in_memory: bool,
+    cors: Option<String>,
+}
+
+impl DynamoDb {
+    /// Uses a single database for all clients (`-sharedDb`), regardless of their credentials and region.
+    pub fn with_shared_db(mut self) -> Self {
+        self.shared_db = true;
+        self
+    }
+
+    /// Keeps the data in memory instead of a database file (`-inMemory`).
+    pub fn with_in_memory(mut self) -> Self {
+        self.in_memory = true;
+        self
+    }
+
+    /// Enables CORS for the given comma separated list of origins (`-cors`), e.g. `*`.
+    pub fn with_cors(mut self, allowed_origins: &str) -> Self {
+        self.cors = Some(allowed_origins.to_owned());
+        self
+    }
+
+    /// Returns the endpoint url of the given container to be passed to the AWS SDK,
+    /// e.g. via [`aws_config::ConfigLoader::endpoint_url`](https://docs.rs/aws-config/latest/aws_config/struct.ConfigLoader.html#method.endpoint_url).
+    ///
+    /// # Example
+    /// ```
+    /// use testcontainers_modules::{dynamodb_local::DynamoDb, testcontainers::runners::AsyncRunner};
+    ///
+    /// # async fn example() -> Result<(), testcontainers_modules::testcontainers::TestcontainersError> {
+    /// let dynamodb_instance = DynamoDb::default().start().await?;
+    /// let endpoint_url = DynamoDb::endpoint_url(&dynamodb_instance).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn endpoint_url(
+        container: &ContainerAsync<Self>,
+    ) -> Result<String, TestcontainersError> {
+        let host = container.get_host().await?;
+        let port = container.get_host_port_ipv4(DYNAMODB_PORT).await?;
+        Ok(format!("http://{host}:{port}"))
+    }
 }
 
 impl Image for DynamoDb {
@@ -31,12 +107,34 @@ impl Image for DynamoDb {
             WaitFor::millis(DEFAULT_WAIT),
         ]
     }
+
+    fn cmd(&self) -> impl IntoIterator<Item = impl Into<Cow<'_, str>>> {
+        if !self.shared_db && !self.in_memory && self.cors.is_none() {
+            return vec![];
+        }
+
+        let mut cmd = vec!["-jar", "DynamoDBLocal.jar"];
+        if self.shared_db {
+            cmd.push("-sharedDb");
+        }
+        if self.in_memory {
+            cmd.push("-inMemory");
+        }
+        if let Some(cors) = &self.cors {
+            cmd.push("-cors");
+            cmd.push(cors);
+        }
+        cmd
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[DYNAMODB_PORT]
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::fmt::Display;
-
+    use crate::{dynamodb_local::DynamoDb, testcontainers::runners::AsyncRunner};
     use aws_config::{meta::region::RegionProviderChain, BehaviorVersion};
     use aws_sdk_dynamodb::{
         config::Credentials,
@@ -46,16 +144,12 @@ mod tests {
         },
         Client,
     };
-    use testcontainers::core::IntoContainerPort;
-
-    use crate::{dynamodb_local::DynamoDb, testcontainers::runners::AsyncRunner};
 
     #[tokio::test]
     async fn dynamodb_local_create_table() -> Result<(), Box<dyn std::error::Error + 'static>> {
         let _ = pretty_env_logger::try_init();
         let node = DynamoDb::default().start().await?;
-        let host = node.get_host().await?;
-        let host_port = node.get_host_port_ipv4(8000.tcp()).await?;
+        let endpoint_url = DynamoDb::endpoint_url(&node).await?;
 
         let table_name = "books".to_string();
 
@@ -77,7 +171,7 @@ mod tests {
             .build()
             .unwrap();
 
-        let dynamodb = build_dynamodb_client(host, host_port).await;
+        let dynamodb = build_dynamodb_client(&endpoint_url, "us-east-1").await;
         let create_table_result = dynamodb
             .create_table()
             .table_name(table_name)
@@ -95,14 +189,57 @@ mod tests {
         Ok(())
     }
 
-    async fn build_dynamodb_client(host: impl Display, host_port: u16) -> Client {
-        let endpoint_uri = format!("http://{host}:{host_port}");
-        let region_provider = RegionProviderChain::default_provider().or_else("us-east-1");
+    #[tokio::test]
+    async fn dynamodb_local_shared_in_memory_db() -> Result<(), Box<dyn std::error::Error + 'static>>
+    {
+        let _ = pretty_env_logger::try_init();
+        let node = DynamoDb::default()
+            .with_shared_db()
+            .with_in_memory()
+            .with_cors("*")
+            .start()
+            .await?;
+        let endpoint_url = DynamoDb::endpoint_url(&node).await?;
+
+        let first_client = build_dynamodb_client(&endpoint_url, "us-east-1").await;
+        first_client
+            .create_table()
+            .table_name("books")
+            .key_schema(
+                KeySchemaElement::builder()
+                    .attribute_name("title")
+                    .key_type(KeyType::Hash)
+                    .build()?,
+            )
+            .attribute_definitions(
+                AttributeDefinition::builder()
+                    .attribute_name("title")
+                    .attribute_type(ScalarAttributeType::S)
+                    .build()?,
+            )
+            .provisioned_throughput(
+                ProvisionedThroughput::builder()
+                    .read_capacity_units(10)
+                    .write_capacity_units(5)
+                    .build()?,
+            )
+            .send()
+            .await?;
+
+        // with a shared db, clients of other regions see the same tables
+        let second_client = build_dynamodb_client(&endpoint_url, "eu-west-1").await;
+        let list_tables_result = second_client.list_tables().send().await?;
+        assert_eq!(list_tables_result.table_names(), ["books"]);
+        Ok(())
+    }
+
+    async fn build_dynamodb_client(endpoint_url: &str, region: &'static str) -> Client {
+        let region_provider = RegionProviderChain::default_provider().or_else(region);
         let creds = Credentials::new("fakeKey", "fakeSecret", None, None, "test");
 
         let shared_config = aws_config::defaults(BehaviorVersion::latest())
             .region(region_provider)
-            .endpoint_url(endpoint_uri)
+            .endpoint_url(endpoint_url)
             .credentials_provider(creds)
             .load()
             .await;
@@ -1,7 +1,7 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, collections::BTreeMap};
 
 use testcontainers::{
-    core::{ContainerPort, WaitFor},
+    core::{wait::HttpWaitStrategy, ContainerPort, WaitFor},
     Image,
 };
 
@@ -21,14 +21,78 @@ pub const ELASTICSEARCH_API_PORT: ContainerPort = ContainerPort::Tcp(9200);
 /// [`Elasticsearch`]: https://elastic.co/
 pub const ELASTICSEARCH_INTER_NODE_PORT: ContainerPort = ContainerPort::Tcp(9300);
 
-#[allow(missing_docs)]
-// not having docs here is currently allowed to address the missing docs problem one place at a time. Helping us by documenting just one of these places helps other devs tremendously
-#[derive(Debug, Default, Clone)]
+/// Module to work with [`Elasticsearch`] inside of tests.
+///
+/// Starts a single node cluster based on the official [`Elasticsearch docker image`].
+/// The container is considered ready once the cluster health is at least yellow.
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{
+///     elastic_search::{ElasticSearch, ELASTICSEARCH_API_PORT},
+///     testcontainers::runners::SyncRunner,
+/// };
+///
+/// let elastic_instance = ElasticSearch::default()
+///     .with_heap_size("512m")
+///     .start()
+///     .unwrap();
+/// let url = format!(
+///     "http://{}:{}",
+///     elastic_instance.get_host().unwrap(),
+///     elastic_instance
+///         .get_host_port_ipv4(ELASTICSEARCH_API_PORT)
+///         .unwrap()
+/// );
+/// ```
+///
+/// [`Elasticsearch`]: https://elastic.co/
+/// [`Elasticsearch docker image`]: https://www.docker.elastic.co/r/elasticsearch
+#[derive(Debug, Clone)]
 pub struct ElasticSearch {
-    /// (remove if there is another variable)
-    /// Field is included to prevent this struct to be a unit struct.
-    /// This allows extending functionality (and thus further variables) without breaking changes
-    _priv: (),
+    env_vars: BTreeMap<String, String>,
+    password: Option<String>,
+}
+
+impl Default for ElasticSearch {
+    fn default() -> Self {
+        let mut env_vars = BTreeMap::new();
+        env_vars.insert("discovery.type".to_owned(), "single-node".to_owned());
+        Self {
+            env_vars,
+            password: None,
+        }
+    }
+}
+
+impl ElasticSearch {
+    /// Enables the security features (`xpack.security.enabled`) and sets the password of the `elastic` user.
+    pub fn with_password(mut self, password: &str) -> Self {
+        self.env_vars
+            .insert("xpack.security.enabled".to_owned(), "true".to_owned());
+        self.env_vars
+            .insert("ELASTIC_PASSWORD".to_owned(), password.to_owned());
+        self.password = Some(password.to_owned());
+        self
+    }
+
+    /// Disables the security features (`xpack.security.enabled`), thus requests don't need to be authenticated.
+    pub fn with_disable_security(mut self) -> Self {
+        self.env_vars
+            .insert("xpack.security.enabled".to_owned(), "false".to_owned());
+        self.env_vars.remove("ELASTIC_PASSWORD");
+        self.password = None;
+        self
+    }
+
+    /// Sets the minimum and maximum heap size of the JVM (`ES_JAVA_OPTS`), e.g. `512m`.
+    pub fn with_heap_size(mut self, heap_size: &str) -> Self {
+        self.env_vars.insert(
+            "ES_JAVA_OPTS".to_owned(),
+            format!("-Xms{heap_size} -Xmx{heap_size}"),
+        );
+        self
+    }
 }
 
 impl Image for ElasticSearch {
@@ -41,13 +105,19 @@ impl Image for ElasticSearch {
     }
 
     fn ready_conditions(&self) -> Vec<WaitFor> {
-        vec![WaitFor::message_on_stdout("[YELLOW] to [GREEN]")]
+        let mut strategy = HttpWaitStrategy::new("/_cluster/health?wait_for_status=yellow")
+            .with_port(ELASTICSEARCH_API_PORT)
+            .with_expected_status_code(200_u16);
+        if let Some(password) = &self.password {
+            strategy = strategy.with_basic_auth("elastic", password);
+        }
+        vec![WaitFor::http(strategy)]
     }
 
     fn env_vars(
         &self,
     ) -> impl IntoIterator<Item = (impl Into<Cow<'_, str>>, impl Into<Cow<'_, str>>)> {
-        [("discovery.type", "single-node")]
+        &self.env_vars
     }
 
     fn expose_ports(&self) -> &[ContainerPort] {
@@ -56,4 +126,34 @@ impl Image for ElasticSearch {
 }
 
 #[cfg(test)]
-mod tests {}
+mod tests {
+    use super::*;
+    use crate::testcontainers::runners::SyncRunner;
+
+    #[test]
+    fn elasticsearch_with_password() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = ElasticSearch::default()
+            .with_password("changeme")
+            .with_heap_size("512m")
+            .start()?;
+        let url = format!(
+            "http://{}:{}/_cluster/health",
+            node.get_host()?,
+            node.get_host_port_ipv4(ELASTICSEARCH_API_PORT)?
+        );
+
+        let client = reqwest::blocking::Client::new();
+        let response = client.get(&url).send()?;
+        assert_eq!(response.status(), 401);
+
+        let health: serde_json::Value = client
+            .get(&url)
+            .basic_auth("elastic", Some("changeme"))
+            .send()?
+            .error_for_status()?
+            .json()?;
+        assert_ne!(health["status"], "red");
+        Ok(())
+    }
+}
@@ -0,0 +1,159 @@
+use std::borrow::Cow;
+
+use testcontainers::{
+    core::{ContainerPort, WaitFor},
+    Image,
+};
+
+const NAME: &str = "quay.io/coreos/etcd";
+const TAG: &str = "v3.5.17";
+const DEFAULT_ADVERTISED_HOST: &str = "localhost";
+
+/// Port for client requests that the [`etcd`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`etcd`]: https://etcd.io/
+pub const ETCD_CLIENT_PORT: ContainerPort = ContainerPort::Tcp(2379);
+
+/// Module to work with [`etcd`] inside of tests.
+///
+/// Starts a single node instance of etcd based on the official [`etcd docker image`].
+///
+/// Clients connect on port 2379 ([`ETCD_CLIENT_PORT`]). The advertised client url is derived from
+/// the advertised host (`localhost` by default, see [`Etcd::with_advertised_host`]) and the client port.
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{
+///     etcd::{Etcd, ETCD_CLIENT_PORT},
+///     testcontainers::runners::SyncRunner,
+/// };
+///
+/// let etcd_instance = Etcd::default().start().unwrap();
+/// let endpoint = format!(
+///     "http://{}:{}",
+///     etcd_instance.get_host().unwrap(),
+///     etcd_instance.get_host_port_ipv4(ETCD_CLIENT_PORT).unwrap()
+/// );
+/// ```
+///
+/// [`etcd`]: https://etcd.io/
+/// [`etcd docker image`]: https://quay.io/repository/coreos/etcd
+#[derive(Debug, Clone)]
+pub struct Etcd {
+    advertised_host: String,
+    auth_token: Option<String>,
+    v2_api: bool,
+}
+
+impl Default for Etcd {
+    fn default() -> Self {
+        Self {
+            advertised_host: DEFAULT_ADVERTISED_HOST.to_owned(),
+            auth_token: None,
+            v2_api: false,
+        }
+    }
+}
+
+impl Etcd {
+    /// Sets the host used for the advertised client url, defaults to `localhost`.
+    /// Useful to advertise the network alias of the container to other containers on the same network.
+    pub fn with_advertised_host(mut self, host: &str) -> Self {
+        self.advertised_host = host.to_owned();
+        self
+    }
+
+    /// Sets the type of the auth token issued to authenticated clients (`--auth-token`),
+    /// e.g. `simple` or `jwt,pub-key=...,priv-key=...,sign-method=RS256`.
+    pub fn with_auth_token(mut self, auth_token: &str) -> Self {
+        self.auth_token = Some(auth_token.to_owned());
+        self
+    }
+
+    /// Enables the deprecated v2 API (`--enable-v2`).
+    pub fn with_v2_api(mut self) -> Self {
+        self.v2_api = true;
+        self
+    }
+}
+
+impl Image for Etcd {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn tag(&self) -> &str {
+        TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![WaitFor::message_on_stderr("ready to serve client requests")]
+    }
+
+    fn cmd(&self) -> impl IntoIterator<Item = impl Into<Cow<'_, str>>> {
+        let client_port = ETCD_CLIENT_PORT.as_u16();
+        let mut cmd = vec![
+            "etcd".to_owned(),
+            "--name".to_owned(),
+            "default".to_owned(),
+            "--data-dir".to_owned(),
+            "/tmp/etcd-data".to_owned(),
+            "--listen-client-urls".to_owned(),
+            format!("http://0.0.0.0:{client_port}"),
+            "--advertise-client-urls".to_owned(),
+            format!("http://{}:{client_port}", self.advertised_host),
+        ];
+        if let Some(auth_token) = &self.auth_token {
+            cmd.push("--auth-token".to_owned());
+            cmd.push(auth_token.clone());
+        }
+        if self.v2_api {
+            cmd.push("--enable-v2=true".to_owned());
+        }
+        cmd
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[ETCD_CLIENT_PORT]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testcontainers::runners::SyncRunner;
+
+    #[test]
+    fn etcd_health() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = Etcd::default().with_auth_token("simple").start()?;
+        let url = format!(
+            "http://{}:{}",
+            node.get_host()?,
+            node.get_host_port_ipv4(ETCD_CLIENT_PORT)?
+        );
+
+        let health: serde_json::Value = reqwest::blocking::get(format!("{url}/health"))?.json()?;
+        assert_eq!(health["health"], "true");
+        Ok(())
+    }
+
+    #[test]
+    fn etcd_with_v2_api() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = Etcd::default().with_v2_api().start()?;
+        let url = format!(
+            "http://{}:{}",
+            node.get_host()?,
+            node.get_host_port_ipv4(ETCD_CLIENT_PORT)?
+        );
+
+        let response = reqwest::blocking::Client::new()
+            .put(format!("{url}/v2/keys/foo"))
+            .form(&[("value", "bar")])
+            .send()?;
+        assert!(response.status().is_success());
+        Ok(())
+    }
+}
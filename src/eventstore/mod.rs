@@ -0,0 +1,151 @@
+use std::{borrow::Cow, collections::BTreeMap};
+
+use testcontainers::{
+    core::{wait::HttpWaitStrategy, ContainerPort, WaitFor},
+    Image,
+};
+
+const NAME: &str = "eventstore/eventstore";
+const TAG: &str = "24.10";
+
+/// Port of the gRPC and HTTP APIs that the [`EventStoreDB`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`EventStoreDB`]: https://www.eventstore.com/eventstoredb
+pub const EVENTSTORE_PORT: ContainerPort = ContainerPort::Tcp(2113);
+
+/// Module to work with [`EventStoreDB`] inside of tests.
+///
+/// Starts a single node of EventStoreDB based on the official [`EventStoreDB docker image`],
+/// serving the gRPC and HTTP APIs on port 2113 ([`EVENTSTORE_PORT`]).
+///
+/// For development the node runs in insecure mode (without TLS, e.g. `esdb://localhost:2113?tls=false`)
+/// and keeps the events in memory. The `admin` user has the password `changeit`.
+/// Projections are disabled by default, see [`EventStoreDb::with_all_projections`].
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{
+///     eventstore::{EventStoreDb, EVENTSTORE_PORT},
+///     testcontainers::runners::SyncRunner,
+/// };
+///
+/// let eventstore_instance = EventStoreDb::default().start().unwrap();
+/// let connection_string = format!(
+///     "esdb://{}:{}?tls=false",
+///     eventstore_instance.get_host().unwrap(),
+///     eventstore_instance.get_host_port_ipv4(EVENTSTORE_PORT).unwrap()
+/// );
+/// ```
+///
+/// [`EventStoreDB`]: https://www.eventstore.com/eventstoredb
+/// [`EventStoreDB docker image`]: https://hub.docker.com/r/eventstore/eventstore
+#[derive(Debug, Clone)]
+pub struct EventStoreDb {
+    env_vars: BTreeMap<String, String>,
+}
+
+impl Default for EventStoreDb {
+    fn default() -> Self {
+        let mut env_vars = BTreeMap::new();
+        env_vars.insert("EVENTSTORE_INSECURE".to_owned(), "true".to_owned());
+        env_vars.insert("EVENTSTORE_CLUSTER_SIZE".to_owned(), "1".to_owned());
+        env_vars.insert("EVENTSTORE_MEM_DB".to_owned(), "true".to_owned());
+        env_vars.insert("EVENTSTORE_RUN_PROJECTIONS".to_owned(), "None".to_owned());
+        Self { env_vars }
+    }
+}
+
+impl EventStoreDb {
+    /// Runs all projections (`EVENTSTORE_RUN_PROJECTIONS=All`) and starts the system projections
+    /// like `$by_category` and `$by_event_type` (`EVENTSTORE_START_STANDARD_PROJECTIONS`).
+    pub fn with_all_projections(mut self) -> Self {
+        self.env_vars
+            .insert("EVENTSTORE_RUN_PROJECTIONS".to_owned(), "All".to_owned());
+        self.env_vars.insert(
+            "EVENTSTORE_START_STANDARD_PROJECTIONS".to_owned(),
+            "true".to_owned(),
+        );
+        self
+    }
+
+    /// Stores the events on disk in the container instead of in memory (`EVENTSTORE_MEM_DB`).
+    pub fn with_disk_storage(mut self) -> Self {
+        self.env_vars
+            .insert("EVENTSTORE_MEM_DB".to_owned(), "false".to_owned());
+        self
+    }
+}
+
+impl Image for EventStoreDb {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn tag(&self) -> &str {
+        TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![WaitFor::http(
+            HttpWaitStrategy::new("/health/live")
+                .with_port(EVENTSTORE_PORT)
+                .with_expected_status_code(204_u16),
+        )]
+    }
+
+    fn env_vars(
+        &self,
+    ) -> impl IntoIterator<Item = (impl Into<Cow<'_, str>>, impl Into<Cow<'_, str>>)> {
+        &self.env_vars
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[EVENTSTORE_PORT]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testcontainers::runners::SyncRunner;
+
+    #[test]
+    fn eventstore_with_all_projections() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = EventStoreDb::default().with_all_projections().start()?;
+        let url = format!(
+            "http://{}:{}",
+            node.get_host()?,
+            node.get_host_port_ipv4(EVENTSTORE_PORT)?
+        );
+
+        let client = reqwest::blocking::Client::new();
+        let info: serde_json::Value = client
+            .get(format!("{url}/info"))
+            .header("Accept", "application/json")
+            .send()?
+            .error_for_status()?
+            .json()?;
+        assert_eq!(info["features"]["projections"], true);
+
+        let projections: serde_json::Value = client
+            .get(format!("{url}/projections/any"))
+            .basic_auth("admin", Some("changeit"))
+            .header("Accept", "application/json")
+            .send()?
+            .error_for_status()?
+            .json()?;
+        let names = projections["projections"]
+            .as_array()
+            .map(|projections| {
+                projections
+                    .iter()
+                    .map(|projection| projection["name"].clone())
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        assert!(names.contains(&serde_json::json!("$by_category")));
+        Ok(())
+    }
+}
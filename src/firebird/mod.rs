@@ -0,0 +1,157 @@
+use std::{borrow::Cow, collections::BTreeMap};
+
+use testcontainers::{
+    core::{CmdWaitFor, ContainerPort, ContainerState, ExecCommand, WaitFor},
+    Image, TestcontainersError,
+};
+
+const NAME: &str = "firebirdsql/firebird";
+const TAG: &str = "5.0.1";
+/// Maximum time to wait for the server to accept connections, before the start is considered failed.
+const READY_TIMEOUT_SECS: u64 = 60;
+
+/// Port that the [`Firebird`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Firebird`]: https://firebirdsql.org/
+pub const FIREBIRD_PORT: ContainerPort = ContainerPort::Tcp(3050);
+
+/// Module to work with [`Firebird`] inside of tests.
+///
+/// Starts an instance of Firebird based on the official [`Firebird docker image`], accepting clients
+/// on port 3050 ([`FIREBIRD_PORT`]).
+///
+/// The `SYSDBA` user has the password `masterkey` (see [`Firebird::with_sysdba_password`]).
+/// On startup the database `test.fdb` is created in `/var/lib/firebird/data`, owned by the user `test`
+/// with the password `test`, see [`Firebird::with_database`] and [`Firebird::with_user`].
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{
+///     firebird::{Firebird, FIREBIRD_PORT},
+///     testcontainers::runners::SyncRunner,
+/// };
+///
+/// let firebird_instance = Firebird::default().start().unwrap();
+/// let database_url = format!(
+///     "firebird://test:test@{}:{}//var/lib/firebird/data/test.fdb",
+///     firebird_instance.get_host().unwrap(),
+///     firebird_instance.get_host_port_ipv4(FIREBIRD_PORT).unwrap()
+/// );
+/// ```
+///
+/// [`Firebird`]: https://firebirdsql.org/
+/// [`Firebird docker image`]: https://hub.docker.com/r/firebirdsql/firebird
+#[derive(Debug, Clone)]
+pub struct Firebird {
+    env_vars: BTreeMap<String, String>,
+}
+
+impl Default for Firebird {
+    fn default() -> Self {
+        let mut env_vars = BTreeMap::new();
+        env_vars.insert("FIREBIRD_ROOT_PASSWORD".to_owned(), "masterkey".to_owned());
+        env_vars.insert("FIREBIRD_DATABASE".to_owned(), "test.fdb".to_owned());
+        env_vars.insert("FIREBIRD_USER".to_owned(), "test".to_owned());
+        env_vars.insert("FIREBIRD_PASSWORD".to_owned(), "test".to_owned());
+        Self { env_vars }
+    }
+}
+
+impl Firebird {
+    /// Sets the password of the `SYSDBA` user (`FIREBIRD_ROOT_PASSWORD`), defaults to `masterkey`.
+    pub fn with_sysdba_password(mut self, password: &str) -> Self {
+        self.env_vars
+            .insert("FIREBIRD_ROOT_PASSWORD".to_owned(), password.to_owned());
+        self
+    }
+
+    /// Sets the file name of the database created on startup (`FIREBIRD_DATABASE`), defaults to `test.fdb`.
+    /// Relative names are created in `/var/lib/firebird/data`.
+    pub fn with_database(mut self, database: &str) -> Self {
+        self.env_vars
+            .insert("FIREBIRD_DATABASE".to_owned(), database.to_owned());
+        self
+    }
+
+    /// Sets the user created on startup, which owns the database (`FIREBIRD_USER`, `FIREBIRD_PASSWORD`),
+    /// defaults to `test` with the password `test`.
+    pub fn with_user(mut self, user: &str, password: &str) -> Self {
+        self.env_vars
+            .insert("FIREBIRD_USER".to_owned(), user.to_owned());
+        self.env_vars
+            .insert("FIREBIRD_PASSWORD".to_owned(), password.to_owned());
+        self
+    }
+}
+
+impl Image for Firebird {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn tag(&self) -> &str {
+        TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        // the server does not log when it accepts connections, see `exec_after_start`
+        vec![]
+    }
+
+    fn env_vars(
+        &self,
+    ) -> impl IntoIterator<Item = (impl Into<Cow<'_, str>>, impl Into<Cow<'_, str>>)> {
+        &self.env_vars
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[FIREBIRD_PORT]
+    }
+
+    fn exec_after_start(
+        &self,
+        _cs: ContainerState,
+    ) -> Result<Vec<ExecCommand>, TestcontainersError> {
+        Ok(vec![ExecCommand::new([
+            "timeout".to_string(),
+            READY_TIMEOUT_SECS.to_string(),
+            "bash".to_string(),
+            "-c".to_string(),
+            format!(
+                "until (echo > /dev/tcp/127.0.0.1/{}) 2>/dev/null; do sleep 0.1; done",
+                FIREBIRD_PORT.as_u16()
+            ),
+        ])
+        .with_cmd_ready_condition(CmdWaitFor::exit_code(0))])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testcontainers::runners::SyncRunner;
+
+    #[test]
+    fn firebird_select_one() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = Firebird::default()
+            .with_sysdba_password("secret")
+            .with_database("custom.fdb")
+            .with_user("custom_user", "custom_password")
+            .start()?;
+
+        let mut result = node.exec(
+            ExecCommand::new([
+                "bash",
+                "-c",
+                "echo 'SELECT CURRENT_USER FROM RDB$DATABASE;' | /opt/firebird/bin/isql -q \
+                 -user custom_user -password custom_password localhost:/var/lib/firebird/data/custom.fdb",
+            ])
+            .with_cmd_ready_condition(CmdWaitFor::exit_code(0)),
+        )?;
+        let stdout = String::from_utf8(result.stdout_to_vec()?)?;
+        assert!(stdout.contains("CUSTOM_USER"), "{stdout}");
+        Ok(())
+    }
+}
@@ -0,0 +1,186 @@
+use std::{borrow::Cow, collections::BTreeMap};
+
+use testcontainers::{
+    core::{wait::HttpWaitStrategy, CmdWaitFor, ContainerPort, ExecCommand, WaitFor},
+    Image,
+};
+
+const NAME: &str = "flink";
+const TAG: &str = "1.20.0-java17";
+
+/// Port of the REST API and web UI that the [`Apache Flink`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Apache Flink`]: https://flink.apache.org/
+pub const FLINK_REST_PORT: ContainerPort = ContainerPort::Tcp(8081);
+
+/// Port of the jobmanager RPC endpoint (`jobmanager.rpc.port`), only used inside of the container.
+const JOBMANAGER_RPC_PORT: u16 = 6123;
+/// Maximum number of seconds to wait for the jobmanager before the taskmanager is started.
+const JOBMANAGER_TIMEOUT_SECS: u64 = 60;
+
+/// Module to work with [`Apache Flink`] inside of tests.
+///
+/// Starts a Flink session cluster based on the official [`Flink docker image`], running
+/// a jobmanager and a single taskmanager in one container. The taskmanager offers 2 task slots
+/// by default, see [`Flink::with_task_slots`].
+///
+/// The REST API, used by clients to submit and monitor jobs, is exposed on port 8081 ([`FLINK_REST_PORT`]).
+/// Jars available inside of the container (e.g. copied with [`testcontainers::core::ImageExt::with_copy_to`],
+/// or the examples shipped in `/opt/flink/examples`) can be submitted with [`Flink::submit_jar`].
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{
+///     flink::{Flink, FLINK_REST_PORT},
+///     testcontainers::runners::SyncRunner,
+/// };
+///
+/// let flink_instance = Flink::default().start().unwrap();
+/// let rest_url = format!(
+///     "http://{}:{}",
+///     flink_instance.get_host().unwrap(),
+///     flink_instance.get_host_port_ipv4(FLINK_REST_PORT).unwrap()
+/// );
+/// ```
+///
+/// [`Apache Flink`]: https://flink.apache.org/
+/// [`Flink docker image`]: https://hub.docker.com/_/flink
+#[derive(Debug, Clone)]
+pub struct Flink {
+    properties: BTreeMap<String, String>,
+}
+
+impl Default for Flink {
+    fn default() -> Self {
+        let mut properties = BTreeMap::new();
+        properties.insert("taskmanager.numberOfTaskSlots".to_owned(), "2".to_owned());
+        Self { properties }
+    }
+}
+
+impl Flink {
+    /// Sets the number of task slots offered by the taskmanager (`taskmanager.numberOfTaskSlots`), defaults to 2.
+    pub fn with_task_slots(self, task_slots: u16) -> Self {
+        self.with_property("taskmanager.numberOfTaskSlots", task_slots.to_string())
+    }
+
+    /// Sets a Flink configuration option, passed as dynamic property (`-Dkey=value`)
+    /// to the jobmanager and the taskmanager, e.g. `with_property("parallelism.default", "2")`.
+    pub fn with_property(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.properties.insert(key.into(), value.into());
+        self
+    }
+
+    /// Returns a command to submit a jar, located inside of the container, to the running cluster
+    /// in detached mode (`flink run -d`). The command succeeds once the job is submitted.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let flink_node = Flink::default().start().unwrap();
+    /// flink_node
+    ///     .exec(Flink::submit_jar(
+    ///         "/opt/flink/examples/streaming/WordCount.jar",
+    ///         &[],
+    ///     ))
+    ///     .unwrap();
+    /// ```
+    pub fn submit_jar(jar_path: &str, args: &[&str]) -> ExecCommand {
+        let mut cmd = vec![
+            "/opt/flink/bin/flink".to_owned(),
+            "run".to_owned(),
+            "-d".to_owned(),
+            jar_path.to_owned(),
+        ];
+        cmd.extend(args.iter().map(|arg| (*arg).to_owned()));
+        ExecCommand::new(cmd).with_cmd_ready_condition(CmdWaitFor::exit_code(0))
+    }
+}
+
+impl Image for Flink {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn tag(&self) -> &str {
+        TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![
+            WaitFor::message_on_stdout("Successful registration at resource manager"),
+            WaitFor::http(
+                HttpWaitStrategy::new("/overview")
+                    .with_port(FLINK_REST_PORT)
+                    .with_expected_status_code(200_u16),
+            ),
+        ]
+    }
+
+    fn cmd(&self) -> impl IntoIterator<Item = impl Into<Cow<'_, str>>> {
+        let properties = self
+            .properties
+            .iter()
+            .map(|(key, value)| shell_quote(&format!("-D{key}={value}")))
+            .collect::<Vec<_>>()
+            .join(" ");
+        // the taskmanager is started once the jobmanager has prepared the shared configuration
+        // and accepts connections of the taskmanager
+        vec![
+            "bash".to_owned(),
+            "-c".to_owned(),
+            format!(
+                "/docker-entrypoint.sh jobmanager {properties} & \
+                 for _ in $(seq {JOBMANAGER_TIMEOUT_SECS}); do \
+                 (exec 3<>/dev/tcp/127.0.0.1/{JOBMANAGER_RPC_PORT}) 2>/dev/null && break; sleep 1; \
+                 done; \
+                 exec /docker-entrypoint.sh taskmanager {properties}"
+            ),
+        ]
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[FLINK_REST_PORT]
+    }
+}
+
+/// Quotes the value as a single argument for `bash`.
+fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', r"'\''"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testcontainers::runners::SyncRunner;
+
+    #[test]
+    fn flink_submit_jar() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = Flink::default().with_task_slots(4).start()?;
+        let url = format!(
+            "http://{}:{}",
+            node.get_host()?,
+            node.get_host_port_ipv4(FLINK_REST_PORT)?
+        );
+
+        let overview: serde_json::Value =
+            reqwest::blocking::get(format!("{url}/overview"))?.json()?;
+        assert_eq!(overview["taskmanagers"], 1);
+        assert_eq!(overview["slots-total"], 4);
+
+        node.exec(Flink::submit_jar(
+            "/opt/flink/examples/streaming/WordCount.jar",
+            &[],
+        ))?;
+        let jobs: serde_json::Value =
+            reqwest::blocking::get(format!("{url}/jobs/overview"))?.json()?;
+        assert_eq!(jobs["jobs"].as_array().map(Vec::len), Some(1));
+        Ok(())
+    }
+
+    #[test]
+    fn flink_quotes_properties() {
+        assert_eq!(shell_quote("-Dkey=it's"), r"'-Dkey=it'\''s'");
+    }
+}
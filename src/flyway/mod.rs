@@ -0,0 +1,204 @@
+use std::{borrow::Cow, collections::BTreeMap};
+
+use testcontainers::{core::WaitFor, CopyDataSource, CopyToContainer, Image};
+
+const NAME: &str = "flyway/flyway";
+const TAG: &str = "11.0.0";
+const MIGRATIONS_DIR: &str = "/flyway/sql";
+const MIGRATE_SUCCEEDED: &str = "testcontainers: flyway migrate succeeded";
+
+/// Module to run [`Flyway`] migrations inside of tests.
+///
+/// Starts a one-shot container based on the official [`Flyway docker image`], which runs `flyway migrate`
+/// against the database reachable with the JDBC url set with [`Flyway::with_url`], usually another container
+/// in the same network (see [`testcontainers::core::ImageExt::with_network`]). The defaults match the
+/// defaults of `postgres::Postgres` started with the container name `postgres`.
+///
+/// The migrations are registered with [`Flyway::with_migration`] or [`Flyway::with_migrations`].
+/// Starting the container returns once all migrations are applied successfully, a failed migration
+/// stops the container and thus fails the start. Afterwards the container stays idle until it is dropped.
+///
+/// # Example
+/// ```rust,ignore
+/// use testcontainers_modules::{
+///     flyway::Flyway,
+///     postgres::Postgres,
+///     testcontainers::{runners::SyncRunner, ImageExt},
+/// };
+///
+/// let _postgres_instance = Postgres::default()
+///     .with_network("flyway-example")
+///     .with_container_name("postgres")
+///     .start()
+///     .unwrap();
+/// let _flyway_instance = Flyway::default()
+///     .with_migration(
+///         "V1__create_foo.sql",
+///         "CREATE TABLE foo (bar varchar(255));"
+///             .to_string()
+///             .into_bytes(),
+///     )
+///     .with_network("flyway-example")
+///     .start()
+///     .unwrap();
+/// ```
+///
+/// [`Flyway`]: https://www.red-gate.com/products/flyway/community/
+/// [`Flyway docker image`]: https://hub.docker.com/r/flyway/flyway
+#[derive(Debug, Clone)]
+pub struct Flyway {
+    options: BTreeMap<String, String>,
+    migrations: Vec<CopyToContainer>,
+}
+
+impl Default for Flyway {
+    fn default() -> Self {
+        let mut options = BTreeMap::new();
+        options.insert(
+            "url".to_owned(),
+            "jdbc:postgresql://postgres:5432/postgres".to_owned(),
+        );
+        options.insert("user".to_owned(), "postgres".to_owned());
+        options.insert("password".to_owned(), "postgres".to_owned());
+        options.insert(
+            "locations".to_owned(),
+            format!("filesystem:{MIGRATIONS_DIR}"),
+        );
+        Self {
+            options,
+            migrations: Vec::new(),
+        }
+    }
+}
+
+impl Flyway {
+    /// Sets the JDBC url of the database (`-url`), as reachable from inside of the container,
+    /// e.g. `jdbc:mysql://mysql:3306/test`. Defaults to `jdbc:postgresql://postgres:5432/postgres`.
+    pub fn with_url(self, url: &str) -> Self {
+        self.with_option("url", url)
+    }
+
+    /// Sets the user to connect to the database (`-user`), defaults to `postgres`.
+    pub fn with_user(self, user: &str) -> Self {
+        self.with_option("user", user)
+    }
+
+    /// Sets the password to connect to the database (`-password`), defaults to `postgres`.
+    pub fn with_password(self, password: &str) -> Self {
+        self.with_option("password", password)
+    }
+
+    /// Sets a Flyway option, passed as `-key=value`, e.g. `with_option("schemas", "app")`.
+    pub fn with_option(mut self, key: &str, value: &str) -> Self {
+        self.options.insert(key.to_owned(), value.to_owned());
+        self
+    }
+
+    /// Registers a migration script, copied to `/flyway/sql/<file_name>`.
+    /// The file name has to follow the naming conventions of Flyway, e.g. `V1__create_tables.sql`.
+    /// Can be called multiple times to add (not override) migrations.
+    pub fn with_migration(mut self, file_name: &str, migration: impl Into<CopyDataSource>) -> Self {
+        self.migrations.push(CopyToContainer::new(
+            migration.into(),
+            format!("{MIGRATIONS_DIR}/{file_name}"),
+        ));
+        self
+    }
+
+    /// Registers a directory of migration scripts (e.g. `CopyDataSource::File("migrations".into())`),
+    /// copied to `/flyway/sql`.
+    pub fn with_migrations(mut self, migrations: impl Into<CopyDataSource>) -> Self {
+        self.migrations
+            .push(CopyToContainer::new(migrations.into(), MIGRATIONS_DIR));
+        self
+    }
+}
+
+impl Image for Flyway {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn tag(&self) -> &str {
+        TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![WaitFor::message_on_stdout(MIGRATE_SUCCEEDED)]
+    }
+
+    fn copy_to_sources(&self) -> impl IntoIterator<Item = &CopyToContainer> {
+        &self.migrations
+    }
+
+    fn entrypoint(&self) -> Option<&str> {
+        Some("sh")
+    }
+
+    fn cmd(&self) -> impl IntoIterator<Item = impl Into<Cow<'_, str>>> {
+        // keeps the container running after a successful migration, so the result can be awaited as log message
+        let mut cmd = vec![
+            "-c".to_owned(),
+            format!(r#"flyway "$@" migrate && echo "{MIGRATE_SUCCEEDED}" && exec sleep infinity"#),
+            "flyway".to_owned(),
+        ];
+        cmd.extend(
+            self.options
+                .iter()
+                .map(|(key, value)| format!("-{key}={value}")),
+        );
+        cmd
+    }
+}
+
+#[cfg(all(test, feature = "postgres"))]
+mod tests {
+    use testcontainers::ImageExt;
+
+    use super::*;
+    use crate::{postgres::Postgres, testcontainers::runners::SyncRunner};
+
+    #[test]
+    fn flyway_migrate_postgres() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let network = "testcontainers-flyway";
+        let postgres_name = "testcontainers-flyway-postgres";
+        let postgres_node = Postgres::default()
+            .with_network(network)
+            .with_container_name(postgres_name)
+            .start()?;
+        let _flyway_node = Flyway::default()
+            .with_url(&format!("jdbc:postgresql://{postgres_name}:5432/postgres"))
+            .with_migration(
+                "V1__create_foo.sql",
+                "CREATE TABLE foo (bar varchar(255));"
+                    .to_string()
+                    .into_bytes(),
+            )
+            .with_migration(
+                "V2__insert_foo.sql",
+                "INSERT INTO foo (bar) VALUES ('blub');"
+                    .to_string()
+                    .into_bytes(),
+            )
+            .with_network(network)
+            .start()?;
+
+        let connection_string = &format!(
+            "postgres://postgres:postgres@{}:{}/postgres",
+            postgres_node.get_host()?,
+            postgres_node.get_host_port_ipv4(5432)?
+        );
+        let mut conn = postgres::Client::connect(connection_string, postgres::NoTls)?;
+        let rows = conn.query("SELECT bar FROM foo", &[])?;
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].get::<_, String>(0), "blub");
+
+        let rows = conn.query(
+            "SELECT version FROM flyway_schema_history WHERE success ORDER BY installed_rank",
+            &[],
+        )?;
+        assert_eq!(rows.len(), 2);
+        Ok(())
+    }
+}
@@ -1,7 +1,6 @@
 /// Self-hosted git server with https/http/ssh access, uses [Gitea](https://docs.gitea.com/).
 use std::result::Result;
 
-use rcgen::{BasicConstraints, CertificateParams, IsCa, KeyPair};
 use testcontainers::{
     core::{
         wait::HttpWaitStrategy, CmdWaitFor, ContainerPort, ContainerState, ExecCommand, WaitFor,
@@ -9,6 +8,8 @@ use testcontainers::{
     CopyDataSource, CopyToContainer, Image, TestcontainersError,
 };
 
+use crate::tls::TlsCertificates;
+
 /// Container port for SSH listener.
 pub const GITEA_SSH_PORT: ContainerPort = ContainerPort::Tcp(2222);
 /// Container port for HTTPS/HTTP listener.
@@ -54,6 +55,7 @@ const CONFIG_FILE_NAME: &str = "app.ini";
 /// - has a single configured user with admin privileges,
 ///   with pre-defined [username](GITEA_DEFAULT_ADMIN_USERNAME) and [password](GITEA_DEFAULT_ADMIN_PASSWORD);
 /// - configured git server hostname is `localhost`; this is a name which `Gitea` uses in the links to repositories;
+/// - webhooks can be delivered to any host, e.g. to other containers in the same network;
 /// - no repositories are created.
 ///
 /// Additionally to defaults, it's possible to:
@@ -62,6 +64,7 @@ const CONFIG_FILE_NAME: &str = "app.ini";
 /// - change git server hostname, which is used in various links to repos or web-server;
 /// - provide your own admin user credentials as well as its SSH public key to authorize git calls;
 /// - create any number of public or private repositories with provided names during server startup;
+/// - create repositories after startup, see [Gitea::create_repo_command];
 /// - execute set of `gitea admin ...` commands during server startup to customize configuration;
 /// - add environment variables
 ///
@@ -420,6 +423,28 @@ impl Gitea {
         }
     }
 
+    /// Return a command to create a repository through the API of a running container,
+    /// authorized as the admin user.
+    ///
+    /// This is the post-start counterpart of [Gitea::with_repo()], e.g. to create repositories between test steps.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// #[tokio::test]
+    /// async fn test() {
+    ///     let gitea = Gitea::default().start().await.unwrap();
+    ///     gitea
+    ///         .exec(gitea.image().create_repo_command(&GiteaRepo::Public("example-repo".to_string())))
+    ///         .await
+    ///         .unwrap();
+    /// // ...
+    /// }
+    /// ```
+    pub fn create_repo_command(&self, repo: &GiteaRepo) -> ExecCommand {
+        ExecCommand::new(self.create_repo_cmd(repo))
+            .with_cmd_ready_condition(CmdWaitFor::exit_code(0))
+    }
+
     /// Return PEM encoded Root CA certificate of the Gitea servers' certificate issuer.
     ///
     /// If TLS has been enabled using [Gitea::with_tls_certs()] method (with auto-generated self-signed certificate),
@@ -626,36 +651,13 @@ impl GiteaTlsCert {
     /// and generate new server certificate signed by CA.
     ///
     /// SAN list includes "localhost", "127.0.0.1", "::1"
-    /// and provided hostname (if it's different form localhost).
+    /// and provided hostname, see [`TlsCertificates::new`].
     fn new(hostname: impl Into<String>) -> Self {
-        // generate root CA key and cert
-        let ca_key = KeyPair::generate().unwrap();
-        let mut ca_cert = CertificateParams::new(vec!["Gitea root CA".to_string()]).unwrap();
-        ca_cert.is_ca = IsCa::Ca(BasicConstraints::Unconstrained);
-        let ca_cert = ca_cert.self_signed(&ca_key).unwrap();
-
-        // prepare SANs
-        let mut hostnames = vec![
-            "localhost".to_string(),
-            "127.0.0.1".to_string(),
-            "::1".to_string(),
-        ];
-        let hostname = hostname.into();
-        if hostname != "localhost" {
-            hostnames.insert(0, hostname);
-        }
-
-        // and generate server key and cert
-        let key = KeyPair::generate().unwrap();
-        let cert = CertificateParams::new(hostnames)
-            .unwrap()
-            .signed_by(&key, &ca_cert, &ca_key)
-            .unwrap();
-
+        let certificates = TlsCertificates::new([hostname.into()]);
         Self {
-            cert: cert.pem(),
-            key: key.serialize_pem(),
-            ca: Some(ca_cert.pem()),
+            cert: certificates.cert_pem().to_string(),
+            key: certificates.key_pem().to_string(),
+            ca: Some(certificates.ca_cert_pem().to_string()),
         }
     }
 
@@ -873,6 +875,34 @@ mod tests {
         assert_eq!(response.status(), 200);
     }
 
+    #[tokio::test]
+    async fn gitea_create_repo_after_start() {
+        let gitea = Gitea::default().start().await.unwrap();
+
+        gitea
+            .exec(
+                gitea
+                    .image()
+                    .create_repo_command(&GiteaRepo::Public(TEST_PUBLIC_REPO.to_string())),
+            )
+            .await
+            .unwrap();
+
+        let response = reqwest::Client::new()
+            .get(
+                api_url(
+                    &gitea,
+                    &format!("/repos/{GITEA_DEFAULT_ADMIN_USERNAME}/{TEST_PUBLIC_REPO}"),
+                )
+                .await,
+            )
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 200);
+    }
+
     #[tokio::test]
     async fn gitea_admin_commands() {
         let command = vec![
@@ -0,0 +1,173 @@
+use std::{borrow::Cow, collections::BTreeMap, time::Duration};
+
+use testcontainers::{
+    core::{wait::HttpWaitStrategy, ContainerPort, WaitFor},
+    Image,
+};
+
+const NAME: &str = "gitlab/gitlab-ce";
+const TAG: &str = "17.5.2-ce.0";
+
+/// Port of the HTTP listener that the [`GitLab`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`GitLab`]: https://about.gitlab.com/
+pub const GITLAB_HTTP_PORT: ContainerPort = ContainerPort::Tcp(80);
+
+/// Port of the SSH listener that the [`GitLab`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`GitLab`]: https://about.gitlab.com/
+pub const GITLAB_SSH_PORT: ContainerPort = ContainerPort::Tcp(22);
+
+/// Recommended startup timeout for [`GitlabCe`], to be set via
+/// [`testcontainers::core::ImageExt::with_startup_timeout`], as the first start of GitLab
+/// takes several minutes, exceeding the default startup timeout of testcontainers.
+pub const GITLAB_STARTUP_TIMEOUT: Duration = Duration::from_secs(600);
+
+const DEFAULT_ROOT_PASSWORD: &str = "Testcontainers1!";
+
+/// Module to work with [`GitLab`] Community Edition inside of tests.
+///
+/// Starts an omnibus instance of GitLab CE based on the official [`GitLab CE docker image`],
+/// serving HTTP on port 80 ([`GITLAB_HTTP_PORT`]) and SSH on port 22 ([`GITLAB_SSH_PORT`]).
+///
+/// The container is considered ready once `/-/readiness` reports success, which is allowed for any client IP.
+/// To speed up the startup, the bundled monitoring stack is disabled.
+/// The `root` user has the password `Testcontainers1!`, see [`GitlabCe::with_root_password`].
+///
+/// As the startup takes several minutes, a longer startup timeout is required (see [`GITLAB_STARTUP_TIMEOUT`]).
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{
+///     gitlab::{GitlabCe, GITLAB_HTTP_PORT, GITLAB_STARTUP_TIMEOUT},
+///     testcontainers::{runners::SyncRunner, ImageExt},
+/// };
+///
+/// let gitlab_instance = GitlabCe::default()
+///     .with_startup_timeout(GITLAB_STARTUP_TIMEOUT)
+///     .start()
+///     .unwrap();
+/// let url = format!(
+///     "http://{}:{}/api/v4",
+///     gitlab_instance.get_host().unwrap(),
+///     gitlab_instance.get_host_port_ipv4(GITLAB_HTTP_PORT).unwrap()
+/// );
+/// ```
+///
+/// [`GitLab`]: https://about.gitlab.com/
+/// [`GitLab CE docker image`]: https://hub.docker.com/r/gitlab/gitlab-ce
+#[derive(Debug, Clone)]
+pub struct GitlabCe {
+    env_vars: BTreeMap<String, String>,
+    omnibus_config: Vec<String>,
+}
+
+impl Default for GitlabCe {
+    fn default() -> Self {
+        let mut env_vars = BTreeMap::new();
+        env_vars.insert(
+            "GITLAB_ROOT_PASSWORD".to_owned(),
+            DEFAULT_ROOT_PASSWORD.to_owned(),
+        );
+        let omnibus_config = [
+            "external_url 'http://localhost'",
+            "gitlab_rails['monitoring_whitelist'] = ['0.0.0.0/0', '::/0']",
+            "gitlab_rails['usage_ping_enabled'] = false",
+            "prometheus_monitoring['enable'] = false",
+            "sidekiq['metrics_enabled'] = false",
+            "puma['worker_processes'] = 0",
+        ]
+        .map(String::from)
+        .to_vec();
+        Self {
+            env_vars,
+            omnibus_config,
+        }
+    }
+}
+
+impl GitlabCe {
+    /// Sets the initial password of the `root` user (`GITLAB_ROOT_PASSWORD`), defaults to `Testcontainers1!`.
+    /// GitLab rejects weak passwords, which prevents the `root` user from being created.
+    pub fn with_root_password(mut self, password: &str) -> Self {
+        self.env_vars
+            .insert("GITLAB_ROOT_PASSWORD".to_owned(), password.to_owned());
+        self
+    }
+
+    /// Appends a line of Ruby to the omnibus configuration (`GITLAB_OMNIBUS_CONFIG`),
+    /// e.g. `gitlab_rails['initial_shared_runners_registration_token'] = 'token'`.
+    /// Can be called multiple times, later lines override earlier settings.
+    pub fn with_omnibus_config(mut self, config: &str) -> Self {
+        self.omnibus_config.push(config.to_owned());
+        self
+    }
+}
+
+impl Image for GitlabCe {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn tag(&self) -> &str {
+        TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![WaitFor::http(
+            HttpWaitStrategy::new("/-/readiness")
+                .with_port(GITLAB_HTTP_PORT)
+                .with_poll_interval(Duration::from_secs(5))
+                .with_expected_status_code(200_u16),
+        )]
+    }
+
+    fn env_vars(
+        &self,
+    ) -> impl IntoIterator<Item = (impl Into<Cow<'_, str>>, impl Into<Cow<'_, str>>)> {
+        let mut env_vars = self.env_vars.clone();
+        env_vars.insert(
+            "GITLAB_OMNIBUS_CONFIG".to_owned(),
+            self.omnibus_config.join("; "),
+        );
+        env_vars
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[GITLAB_HTTP_PORT, GITLAB_SSH_PORT]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testcontainers::{runners::SyncRunner, ImageExt};
+
+    #[test]
+    fn gitlab_api_version() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = GitlabCe::default()
+            .with_root_password("Sup3rSecret!")
+            .with_omnibus_config("gitlab_rails['time_zone'] = 'UTC'")
+            .with_startup_timeout(GITLAB_STARTUP_TIMEOUT)
+            .start()?;
+        let url = format!(
+            "http://{}:{}",
+            node.get_host()?,
+            node.get_host_port_ipv4(GITLAB_HTTP_PORT)?
+        );
+
+        let readiness: serde_json::Value = reqwest::blocking::get(format!("{url}/-/readiness"))?
+            .error_for_status()?
+            .json()?;
+        assert_eq!(readiness["status"], "ok");
+
+        let projects: serde_json::Value = reqwest::blocking::get(format!("{url}/api/v4/projects"))?
+            .error_for_status()?
+            .json()?;
+        assert!(projects.as_array().is_some());
+        Ok(())
+    }
+}
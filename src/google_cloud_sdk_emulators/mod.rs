@@ -2,7 +2,7 @@ use std::borrow::Cow;
 
 use testcontainers::{
     core::{ContainerPort, WaitFor},
-    Image,
+    ContainerAsync, Image, TestcontainersError,
 };
 
 const NAME: &str = "google/cloud-sdk";
@@ -85,13 +85,35 @@ impl IntoIterator for &CloudSdkCmd {
     }
 }
 
-#[allow(missing_docs)]
-// not having docs here is currently allowed to address the missing docs problem one place at a time. Helping us by documenting just one of these places helps other devs tremendously
+/// Module to work with the [`Google Cloud SDK emulators`] inside of tests.
+///
+/// Starts one of the emulators of the official [`Cloud SDK docker image`],
+/// see [`CloudSdk::bigtable`], [`CloudSdk::datastore`], [`CloudSdk::firestore`], [`CloudSdk::pubsub`] and [`CloudSdk::spanner`].
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{
+///     google_cloud_sdk_emulators::{CloudSdk, PUBSUB_PORT},
+///     testcontainers::runners::SyncRunner,
+/// };
+///
+/// let pubsub = CloudSdk::pubsub().with_project_id("test-project").start().unwrap();
+/// // e.g. to be used as `PUBSUB_EMULATOR_HOST`
+/// let endpoint = format!(
+///     "{}:{}",
+///     pubsub.get_host().unwrap(),
+///     pubsub.get_host_port_ipv4(PUBSUB_PORT).unwrap()
+/// );
+/// ```
+///
+/// [`Google Cloud SDK emulators`]: https://cloud.google.com/sdk/gcloud/reference/beta/emulators
+/// [`Cloud SDK docker image`]: https://hub.docker.com/r/google/cloud-sdk
 #[derive(Debug, Clone)]
 pub struct CloudSdk {
     exposed_ports: Vec<ContainerPort>,
     ready_condition: WaitFor,
     cmd: CloudSdkCmd,
+    project: Option<String>,
 }
 
 impl Image for CloudSdk {
@@ -108,7 +130,12 @@ impl Image for CloudSdk {
     }
 
     fn cmd(&self) -> impl IntoIterator<Item = impl Into<Cow<'_, str>>> {
-        &self.cmd
+        let mut cmd: Vec<String> = self.cmd.into_iter().collect();
+        if let Some(project) = &self.project {
+            // right after `gcloud beta emulators <emulator> start`
+            cmd.splice(5..5, ["--project".to_owned(), project.clone()]);
+        }
+        cmd
     }
 
     fn expose_ports(&self) -> &[ContainerPort] {
@@ -127,11 +154,44 @@ impl CloudSdk {
             exposed_ports: vec![ContainerPort::Tcp(port)],
             ready_condition,
             cmd,
+            project: None,
         }
     }
 
-    // not having docs here is currently allowed to address the missing docs problem one place at a time. Helping us by documenting just one of these places helps other devs tremendously
-    #[allow(missing_docs)]
+    /// Sets the id of the project used by the emulator (`--project`).
+    pub fn with_project_id(mut self, project_id: &str) -> Self {
+        match &mut self.cmd.emulator {
+            Emulator::Datastore { project } => *project = project_id.to_owned(),
+            _ => self.project = Some(project_id.to_owned()),
+        }
+        self
+    }
+
+    /// Returns the endpoint (`host:port`) of the emulator running in the given container,
+    /// as expected by the client libraries in the `*_EMULATOR_HOST` environment variables
+    /// (e.g. `PUBSUB_EMULATOR_HOST`).
+    ///
+    /// # Example
+    /// ```
+    /// use testcontainers_modules::{
+    ///     google_cloud_sdk_emulators::CloudSdk, testcontainers::runners::AsyncRunner,
+    /// };
+    ///
+    /// # async fn example() -> Result<(), testcontainers_modules::testcontainers::TestcontainersError> {
+    /// let pubsub = CloudSdk::pubsub().start().await?;
+    /// let endpoint = CloudSdk::endpoint(&pubsub).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn endpoint(container: &ContainerAsync<Self>) -> Result<String, TestcontainersError> {
+        let host = container.get_host().await?;
+        let port = container
+            .get_host_port_ipv4(container.image().cmd.port)
+            .await?;
+        Ok(format!("{host}:{port}"))
+    }
+
+    /// Creates the Bigtable emulator, listening on port 8086 ([`BIGTABLE_PORT`]).
     pub fn bigtable() -> Self {
         Self::new(
             BIGTABLE_PORT,
@@ -140,8 +200,7 @@ impl CloudSdk {
         )
     }
 
-    // not having docs here is currently allowed to address the missing docs problem one place at a time. Helping us by documenting just one of these places helps other devs tremendously
-    #[allow(missing_docs)]
+    /// Creates the Firestore emulator, listening on port 8080 ([`FIRESTORE_PORT`]).
     pub fn firestore() -> Self {
         Self::new(
             FIRESTORE_PORT,
@@ -150,8 +209,7 @@ impl CloudSdk {
         )
     }
 
-    // not having docs here is currently allowed to address the missing docs problem one place at a time. Helping us by documenting just one of these places helps other devs tremendously
-    #[allow(missing_docs)]
+    /// Creates the Datastore emulator for the given project, listening on port 8081 ([`DATASTORE_PORT`]).
     pub fn datastore(project: impl Into<String>) -> Self {
         let project = project.into();
         Self::new(
@@ -161,8 +219,7 @@ impl CloudSdk {
         )
     }
 
-    // not having docs here is currently allowed to address the missing docs problem one place at a time. Helping us by documenting just one of these places helps other devs tremendously
-    #[allow(missing_docs)]
+    /// Creates the Pub/Sub emulator, listening on port 8085 ([`PUBSUB_PORT`]).
     pub fn pubsub() -> Self {
         Self::new(
             PUBSUB_PORT,
@@ -171,8 +228,7 @@ impl CloudSdk {
         )
     }
 
-    // not having docs here is currently allowed to address the missing docs problem one place at a time. Helping us by documenting just one of these places helps other devs tremendously
-    #[allow(missing_docs)]
+    /// Creates the Spanner emulator, listening on port 9010 ([`SPANNER_PORT`]) for gRPC requests.
     pub fn spanner() -> Self {
         Self::new(
             SPANNER_PORT, // gRPC port
@@ -234,4 +290,24 @@ mod tests {
         assert!(RANDOM_PORTS.contains(&port), "Port {port} not found");
         Ok(())
     }
+
+    #[tokio::test]
+    async fn pubsub_emulator_with_project_id() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let image = google_cloud_sdk_emulators::CloudSdk::pubsub().with_project_id("test-project");
+        let node = crate::testcontainers::runners::AsyncRunner::start(image).await?;
+        let endpoint = google_cloud_sdk_emulators::CloudSdk::endpoint(&node).await?;
+
+        let response = reqwest::Client::new()
+            .put(format!(
+                "http://{endpoint}/v1/projects/test-project/topics/test-topic"
+            ))
+            .send()
+            .await?
+            .error_for_status()?
+            .json::<serde_json::Value>()
+            .await?;
+        assert_eq!(response["name"], "projects/test-project/topics/test-topic");
+        Ok(())
+    }
 }
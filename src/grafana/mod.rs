@@ -0,0 +1,199 @@
+use std::{borrow::Cow, collections::BTreeMap};
+
+use testcontainers::{
+    core::{wait::HttpWaitStrategy, ContainerPort, WaitFor},
+    CopyDataSource, CopyToContainer, Image,
+};
+
+const NAME: &str = "grafana/grafana";
+const TAG: &str = "11.3.1";
+const PROVISIONING_DIR: &str = "/etc/grafana/provisioning";
+
+/// Port of the HTTP API and web UI that the [`Grafana`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Grafana`]: https://grafana.com/
+pub const GRAFANA_PORT: ContainerPort = ContainerPort::Tcp(3000);
+
+/// Module to work with [`Grafana`] inside of tests.
+///
+/// Starts an instance of Grafana based on the official [`Grafana docker image`],
+/// serving the HTTP API and web UI on port 3000 ([`GRAFANA_PORT`]).
+///
+/// The admin user is `admin` with the password `admin`, see [`Grafana::with_admin_user`]
+/// and [`Grafana::with_admin_password`]. Datasources and dashboards can be provisioned
+/// on startup with [`Grafana::with_provisioning`].
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{
+///     grafana::{Grafana, GRAFANA_PORT},
+///     testcontainers::runners::SyncRunner,
+/// };
+///
+/// let grafana_instance = Grafana::default().start().unwrap();
+/// let url = format!(
+///     "http://{}:{}",
+///     grafana_instance.get_host().unwrap(),
+///     grafana_instance.get_host_port_ipv4(GRAFANA_PORT).unwrap()
+/// );
+/// ```
+///
+/// [`Grafana`]: https://grafana.com/
+/// [`Grafana docker image`]: https://hub.docker.com/r/grafana/grafana
+#[derive(Debug, Clone)]
+pub struct Grafana {
+    env_vars: BTreeMap<String, String>,
+    provisioning: Vec<CopyToContainer>,
+}
+
+impl Default for Grafana {
+    fn default() -> Self {
+        let mut env_vars = BTreeMap::new();
+        env_vars.insert("GF_SECURITY_ADMIN_USER".to_owned(), "admin".to_owned());
+        env_vars.insert("GF_SECURITY_ADMIN_PASSWORD".to_owned(), "admin".to_owned());
+        env_vars.insert(
+            "GF_ANALYTICS_REPORTING_ENABLED".to_owned(),
+            "false".to_owned(),
+        );
+        env_vars.insert(
+            "GF_ANALYTICS_CHECK_FOR_UPDATES".to_owned(),
+            "false".to_owned(),
+        );
+        Self {
+            env_vars,
+            provisioning: Vec::new(),
+        }
+    }
+}
+
+impl Grafana {
+    /// Sets the name of the admin user (`GF_SECURITY_ADMIN_USER`), defaults to `admin`.
+    pub fn with_admin_user(mut self, user: &str) -> Self {
+        self.env_vars
+            .insert("GF_SECURITY_ADMIN_USER".to_owned(), user.to_owned());
+        self
+    }
+
+    /// Sets the password of the admin user (`GF_SECURITY_ADMIN_PASSWORD`), defaults to `admin`.
+    pub fn with_admin_password(mut self, password: &str) -> Self {
+        self.env_vars
+            .insert("GF_SECURITY_ADMIN_PASSWORD".to_owned(), password.to_owned());
+        self
+    }
+
+    /// Enables the anonymous access, granting anonymous users the given organization role,
+    /// e.g. `Viewer`, `Editor` or `Admin`.
+    pub fn with_anonymous_auth(mut self, org_role: &str) -> Self {
+        self.env_vars
+            .insert("GF_AUTH_ANONYMOUS_ENABLED".to_owned(), "true".to_owned());
+        self.env_vars
+            .insert("GF_AUTH_ANONYMOUS_ORG_ROLE".to_owned(), org_role.to_owned());
+        self
+    }
+
+    /// Registers a [provisioning](https://grafana.com/docs/grafana/latest/administration/provisioning/) file,
+    /// which is copied to the given path relative to `/etc/grafana/provisioning`,
+    /// e.g. `datasources/prometheus.yaml` or `dashboards/default.yaml`.
+    /// Can be called multiple times to add (not override) files.
+    ///
+    /// # Example
+    /// ```
+    /// use testcontainers_modules::grafana::Grafana;
+    ///
+    /// let grafana = Grafana::default().with_provisioning(
+    ///     "datasources/prometheus.yaml",
+    ///     "apiVersion: 1\ndatasources:\n  - name: Prometheus\n    type: prometheus\n    url: http://prometheus:9090\n"
+    ///         .to_string()
+    ///         .into_bytes(),
+    /// );
+    /// ```
+    pub fn with_provisioning(mut self, path: &str, source: impl Into<CopyDataSource>) -> Self {
+        let target = format!("{PROVISIONING_DIR}/{}", path.trim_start_matches('/'));
+        self.provisioning
+            .push(CopyToContainer::new(source.into(), target));
+        self
+    }
+}
+
+impl Image for Grafana {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn tag(&self) -> &str {
+        TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![WaitFor::http(
+            HttpWaitStrategy::new("/api/health")
+                .with_port(GRAFANA_PORT)
+                .with_expected_status_code(200_u16),
+        )]
+    }
+
+    fn env_vars(
+        &self,
+    ) -> impl IntoIterator<Item = (impl Into<Cow<'_, str>>, impl Into<Cow<'_, str>>)> {
+        &self.env_vars
+    }
+
+    fn copy_to_sources(&self) -> impl IntoIterator<Item = &CopyToContainer> {
+        &self.provisioning
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[GRAFANA_PORT]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testcontainers::runners::SyncRunner;
+
+    #[test]
+    fn grafana_with_provisioned_datasource() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = Grafana::default()
+            .with_admin_user("root")
+            .with_admin_password("secret")
+            .with_provisioning(
+                "datasources/prometheus.yaml",
+                "apiVersion: 1\ndatasources:\n  - name: testcontainers\n    type: prometheus\n    url: http://prometheus:9090\n"
+                    .to_string()
+                    .into_bytes(),
+            )
+            .start()?;
+        let url = format!(
+            "http://{}:{}",
+            node.get_host()?,
+            node.get_host_port_ipv4(GRAFANA_PORT)?
+        );
+
+        let datasources: serde_json::Value = reqwest::blocking::Client::new()
+            .get(format!("{url}/api/datasources"))
+            .basic_auth("root", Some("secret"))
+            .send()?
+            .error_for_status()?
+            .json()?;
+        assert_eq!(datasources[0]["name"], "testcontainers");
+        Ok(())
+    }
+
+    #[test]
+    fn grafana_with_anonymous_auth() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = Grafana::default().with_anonymous_auth("Viewer").start()?;
+        let url = format!(
+            "http://{}:{}",
+            node.get_host()?,
+            node.get_host_port_ipv4(GRAFANA_PORT)?
+        );
+
+        let response = reqwest::blocking::get(format!("{url}/api/search"))?;
+        assert!(response.status().is_success());
+        Ok(())
+    }
+}
@@ -0,0 +1,211 @@
+use std::{borrow::Cow, collections::BTreeMap};
+
+use testcontainers::{
+    core::{wait::HttpWaitStrategy, ContainerPort, WaitFor},
+    Image,
+};
+
+const NAME: &str = "greenmail/standalone";
+const TAG: &str = "2.1.2";
+
+/// Port of the SMTP server that the [`GreenMail`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`GreenMail`]: https://greenmail-mail-test.github.io/greenmail/
+pub const GREENMAIL_SMTP_PORT: ContainerPort = ContainerPort::Tcp(3025);
+
+/// Port of the POP3 server that the [`GreenMail`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`GreenMail`]: https://greenmail-mail-test.github.io/greenmail/
+pub const GREENMAIL_POP3_PORT: ContainerPort = ContainerPort::Tcp(3110);
+
+/// Port of the IMAP server that the [`GreenMail`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`GreenMail`]: https://greenmail-mail-test.github.io/greenmail/
+pub const GREENMAIL_IMAP_PORT: ContainerPort = ContainerPort::Tcp(3143);
+
+/// Port of the HTTP API that the [`GreenMail`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`GreenMail`]: https://greenmail-mail-test.github.io/greenmail/
+pub const GREENMAIL_API_PORT: ContainerPort = ContainerPort::Tcp(8080);
+
+/// Module to work with [`GreenMail`] inside of tests.
+///
+/// Starts an instance of the GreenMail mail server based on the official [`GreenMail docker image`],
+/// with SMTP on port 3025 ([`GREENMAIL_SMTP_PORT`]), POP3 on port 3110 ([`GREENMAIL_POP3_PORT`])
+/// and IMAP on port 3143 ([`GREENMAIL_IMAP_PORT`]).
+///
+/// Without users (see [`GreenMail::with_users`]) authentication is disabled and mailboxes are created
+/// on demand for every recipient, with the email address as login and password.
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{
+///     greenmail::{GreenMail, GREENMAIL_SMTP_PORT},
+///     testcontainers::runners::SyncRunner,
+/// };
+///
+/// let greenmail_instance = GreenMail::default()
+///     .with_users(&[("alice", "secret")])
+///     .start()
+///     .unwrap();
+/// let smtp_address = format!(
+///     "{}:{}",
+///     greenmail_instance.get_host().unwrap(),
+///     greenmail_instance
+///         .get_host_port_ipv4(GREENMAIL_SMTP_PORT)
+///         .unwrap()
+/// );
+/// ```
+///
+/// [`GreenMail`]: https://greenmail-mail-test.github.io/greenmail/
+/// [`GreenMail docker image`]: https://hub.docker.com/r/greenmail/standalone
+#[derive(Debug, Clone, Default)]
+pub struct GreenMail {
+    users: Vec<(String, String)>,
+    env_vars: BTreeMap<String, String>,
+}
+
+impl GreenMail {
+    /// Provisions mailboxes for the given `(login, password)` pairs (`-Dgreenmail.users`) and enables authentication.
+    /// The login is used as the address of the mailbox, e.g. `alice@example.com`.
+    pub fn with_users(mut self, users: &[(&str, &str)]) -> Self {
+        self.users = users
+            .iter()
+            .map(|(login, password)| (login.to_string(), password.to_string()))
+            .collect();
+        self.env_vars
+            .insert("GREENMAIL_OPTS".to_owned(), self.greenmail_opts());
+        self
+    }
+
+    fn greenmail_opts(&self) -> String {
+        let mut opts = vec![
+            "-Dgreenmail.setup.test.all".to_owned(),
+            "-Dgreenmail.hostname=0.0.0.0".to_owned(),
+            "-Dgreenmail.verbose".to_owned(),
+        ];
+        if self.users.is_empty() {
+            opts.push("-Dgreenmail.auth.disabled".to_owned());
+        } else {
+            let users: Vec<String> = self
+                .users
+                .iter()
+                .map(|(login, password)| format!("{login}:{password}"))
+                .collect();
+            opts.push(format!("-Dgreenmail.users={}", users.join(",")));
+        }
+        opts.join(" ")
+    }
+}
+
+impl Image for GreenMail {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn tag(&self) -> &str {
+        TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![WaitFor::http(
+            HttpWaitStrategy::new("/api/service/readiness")
+                .with_port(GREENMAIL_API_PORT)
+                .with_expected_status_code(200_u16),
+        )]
+    }
+
+    fn env_vars(
+        &self,
+    ) -> impl IntoIterator<Item = (impl Into<Cow<'_, str>>, impl Into<Cow<'_, str>>)> {
+        &self.env_vars
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[
+            GREENMAIL_SMTP_PORT,
+            GREENMAIL_POP3_PORT,
+            GREENMAIL_IMAP_PORT,
+            GREENMAIL_API_PORT,
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{self, BufRead, BufReader, Write},
+        net::TcpStream,
+    };
+
+    use super::*;
+    use crate::testcontainers::runners::SyncRunner;
+
+    /// Sends the commands line by line and checks that each reply starts with the expected prefix.
+    fn converse(
+        address: (&str, u16),
+        greeting: &str,
+        commands: &[(&str, &str)],
+    ) -> io::Result<Vec<String>> {
+        let stream = TcpStream::connect(address)?;
+        let mut reader = BufReader::new(stream.try_clone()?);
+        let mut writer = stream;
+        let mut replies = Vec::new();
+        let mut read_reply =
+            |reader: &mut BufReader<TcpStream>, expected: &str| -> io::Result<()> {
+                let mut line = String::new();
+                reader.read_line(&mut line)?;
+                if !line.starts_with(expected) {
+                    return Err(io::Error::other(format!("unexpected reply: {line}")));
+                }
+                replies.push(line);
+                Ok(())
+            };
+
+        read_reply(&mut reader, greeting)?;
+        for (command, expected) in commands {
+            writer.write_all(command.as_bytes())?;
+            read_reply(&mut reader, expected)?;
+        }
+        Ok(replies)
+    }
+
+    #[test]
+    fn greenmail_send_and_receive() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = GreenMail::default()
+            .with_users(&[("alice@example.com", "secret")])
+            .start()?;
+        let host = node.get_host()?.to_string();
+
+        converse(
+            (&host, node.get_host_port_ipv4(GREENMAIL_SMTP_PORT)?),
+            "220",
+            &[
+                ("HELO localhost\r\n", "250"),
+                ("MAIL FROM:<bob@example.com>\r\n", "250"),
+                ("RCPT TO:<alice@example.com>\r\n", "250"),
+                ("DATA\r\n", "354"),
+                ("Subject: testcontainers\r\n\r\nHello Alice\r\n.\r\n", "250"),
+                ("QUIT\r\n", "221"),
+            ],
+        )?;
+
+        let replies = converse(
+            (&host, node.get_host_port_ipv4(GREENMAIL_POP3_PORT)?),
+            "+OK",
+            &[
+                ("USER alice@example.com\r\n", "+OK"),
+                ("PASS secret\r\n", "+OK"),
+                ("STAT\r\n", "+OK"),
+                ("QUIT\r\n", "+OK"),
+            ],
+        )?;
+        assert!(replies[3].starts_with("+OK 1 "));
+        Ok(())
+    }
+}
@@ -1,20 +1,33 @@
 use std::{borrow::Cow, collections::BTreeMap};
 
-use testcontainers::{core::WaitFor, Image};
+use testcontainers::{
+    core::{CmdWaitFor, ContainerPort, ContainerState, ExecCommand, WaitFor},
+    Image, TestcontainersError,
+};
 
 const DEFAULT_IMAGE_NAME: &str = "hashicorp/vault";
 const DEFAULT_IMAGE_TAG: &str = "1.17";
+const ROOT_TOKEN_ENV: &str = "VAULT_DEV_ROOT_TOKEN_ID";
+
+/// Port of the HTTP API that the [`Hashicorp Vault`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Hashicorp Vault`]: https://github.com/hashicorp/vault
+pub const VAULT_PORT: ContainerPort = ContainerPort::Tcp(8200);
 
 /// Module to work with [`Hashicorp Vault`] inside of tests.
 ///
-/// This module is based on the official [`Hashicorp Vault docker image`].
+/// This module is based on the official [`Hashicorp Vault docker image`] and starts the server in dev mode,
+/// which is unsealed on startup and uses `myroot` as root token by default (see [`HashicorpVault::with_root_token`]).
 ///
 /// # Example
 /// ```
 /// use testcontainers_modules::{hashicorp_vault, testcontainers::runners::SyncRunner};
 ///
 /// let vault = hashicorp_vault::HashicorpVault::default().start().unwrap();
-/// let http_port = vault.get_host_port_ipv4(8200).unwrap();
+/// let http_port = vault
+///     .get_host_port_ipv4(hashicorp_vault::VAULT_PORT)
+///     .unwrap();
 ///
 /// // do something with the running vault instance..
 /// ```
@@ -27,6 +40,7 @@ pub struct HashicorpVault {
     name: String,
     tag: String,
     env_vars: BTreeMap<String, String>,
+    secret_engines: Vec<(String, String)>,
 }
 
 impl Default for HashicorpVault {
@@ -36,7 +50,7 @@ impl Default for HashicorpVault {
      */
     fn default() -> Self {
         let mut env_vars = BTreeMap::new();
-        env_vars.insert("VAULT_DEV_ROOT_TOKEN_ID".to_string(), "myroot".to_string());
+        env_vars.insert(ROOT_TOKEN_ENV.to_string(), "myroot".to_string());
         HashicorpVault::new(
             DEFAULT_IMAGE_NAME.to_string(),
             DEFAULT_IMAGE_TAG.to_string(),
@@ -51,8 +65,36 @@ impl HashicorpVault {
             name,
             tag,
             env_vars,
+            secret_engines: Vec::new(),
         }
     }
+
+    /// Sets the root token of the dev server (`VAULT_DEV_ROOT_TOKEN_ID`), defaults to `myroot`.
+    pub fn with_root_token(mut self, token: &str) -> Self {
+        self.env_vars
+            .insert(ROOT_TOKEN_ENV.to_string(), token.to_string());
+        self
+    }
+
+    /// Enables a secrets engine of the given type at the given path once the server is started,
+    /// like `vault secrets enable -path=<path> <engine_type>` does.
+    /// Can be called multiple times to enable several engines.
+    ///
+    /// Note that the dev server already mounts a `kv-v2` engine at `secret/`.
+    ///
+    /// # Example
+    /// ```
+    /// use testcontainers_modules::hashicorp_vault::HashicorpVault;
+    ///
+    /// let vault = HashicorpVault::default()
+    ///     .with_secret_engine("kv-v2", "kv")
+    ///     .with_secret_engine("transit", "transit");
+    /// ```
+    pub fn with_secret_engine(mut self, engine_type: &str, path: &str) -> Self {
+        self.secret_engines
+            .push((engine_type.to_string(), path.to_string()));
+        self
+    }
 }
 
 impl Image for HashicorpVault {
@@ -65,7 +107,10 @@ impl Image for HashicorpVault {
     }
 
     fn ready_conditions(&self) -> Vec<WaitFor> {
-        vec![WaitFor::message_on_stdout("Vault server started!")]
+        vec![
+            WaitFor::message_on_stdout("Vault server started!"),
+            WaitFor::message_on_stdout("Unseal Key:"),
+        ]
     }
 
     fn env_vars(
@@ -73,6 +118,36 @@ impl Image for HashicorpVault {
     ) -> impl IntoIterator<Item = (impl Into<Cow<'_, str>>, impl Into<Cow<'_, str>>)> {
         &self.env_vars
     }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[VAULT_PORT]
+    }
+
+    fn exec_after_start(
+        &self,
+        _cs: ContainerState,
+    ) -> Result<Vec<ExecCommand>, TestcontainersError> {
+        let token = self
+            .env_vars
+            .get(ROOT_TOKEN_ENV)
+            .map(String::as_str)
+            .unwrap_or_default();
+        Ok(self
+            .secret_engines
+            .iter()
+            .map(|(engine_type, path)| {
+                ExecCommand::new([
+                    "sh".to_string(),
+                    "-c".to_string(),
+                    format!(
+                        "VAULT_ADDR=http://127.0.0.1:{} VAULT_TOKEN='{token}' vault secrets enable -path='{path}' '{engine_type}'",
+                        VAULT_PORT.as_u16()
+                    ),
+                ])
+                .with_cmd_ready_condition(CmdWaitFor::exit_code(0))
+            })
+            .collect())
+    }
 }
 
 #[cfg(test)]
@@ -120,4 +195,38 @@ mod tests {
         assert_eq!(secret.password, "secret");
         Ok(())
     }
+
+    #[tokio::test]
+    async fn hashicorp_vault_with_root_token_and_secret_engine(
+    ) -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let vault = HashicorpVault::default()
+            .with_root_token("custom-root")
+            .with_secret_engine("kv-v2", "kv")
+            .start()
+            .await?;
+        let endpoint = format!(
+            "http://0.0.0.0:{}",
+            vault.get_host_port_ipv4(VAULT_PORT).await?
+        );
+
+        let client = VaultClient::new(
+            VaultClientSettingsBuilder::default()
+                .address(endpoint)
+                .token("custom-root")
+                .build()
+                .unwrap(),
+        )
+        .unwrap();
+
+        let secret = MySecret {
+            key: "foo".to_string(),
+            password: "bar".to_string(),
+        };
+        kv2::set(&client, "kv", "mysecret", &secret).await?;
+
+        let secret: MySecret = kv2::read(&client, "kv", "mysecret").await?;
+        assert_eq!(secret.key, "foo");
+        assert_eq!(secret.password, "bar");
+        Ok(())
+    }
 }
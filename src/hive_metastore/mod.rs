@@ -0,0 +1,196 @@
+use std::{borrow::Cow, collections::BTreeMap};
+
+use testcontainers::{
+    core::{CmdWaitFor, ContainerPort, ContainerState, ExecCommand, WaitFor},
+    Image, TestcontainersError,
+};
+
+const NAME: &str = "apache/hive";
+const TAG: &str = "4.0.1";
+/// Maximum time to wait for the metastore to accept connections, before the start is considered failed.
+const READY_TIMEOUT_SECS: u64 = 120;
+
+/// Port of the Thrift API that the [`Hive Metastore`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Hive Metastore`]: https://hive.apache.org/
+pub const HIVE_METASTORE_PORT: ContainerPort = ContainerPort::Tcp(9083);
+
+/// Module to work with a standalone [`Hive Metastore`] inside of tests.
+///
+/// Starts a metastore based on the official [`Apache Hive docker image`], serving the Thrift API
+/// on port 9083 ([`HIVE_METASTORE_PORT`]) for table formats and engines like Iceberg, Delta Lake, Spark or Trino.
+///
+/// By default the metadata is stored in an embedded Derby database and the tables in the local
+/// directory `/opt/hive/data/warehouse`, see [`HiveMetastore::with_warehouse_dir`] to use e.g. an S3 bucket.
+/// The metadata can be stored in another container in the same network
+/// (see [`testcontainers::core::ImageExt::with_network`]) with [`HiveMetastore::with_postgres_database`]
+/// or [`HiveMetastore::with_mysql_database`].
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{
+///     hive_metastore::{HiveMetastore, HIVE_METASTORE_PORT},
+///     testcontainers::runners::SyncRunner,
+/// };
+///
+/// let metastore_instance = HiveMetastore::default().start().unwrap();
+/// let thrift_uri = format!(
+///     "thrift://{}:{}",
+///     metastore_instance.get_host().unwrap(),
+///     metastore_instance.get_host_port_ipv4(HIVE_METASTORE_PORT).unwrap()
+/// );
+/// ```
+///
+/// [`Hive Metastore`]: https://hive.apache.org/
+/// [`Apache Hive docker image`]: https://hub.docker.com/r/apache/hive
+#[derive(Debug, Clone)]
+pub struct HiveMetastore {
+    env_vars: BTreeMap<String, String>,
+    service_opts: BTreeMap<String, String>,
+}
+
+impl Default for HiveMetastore {
+    fn default() -> Self {
+        let mut env_vars = BTreeMap::new();
+        env_vars.insert("SERVICE_NAME".to_owned(), "metastore".to_owned());
+        env_vars.insert("DB_DRIVER".to_owned(), "derby".to_owned());
+        Self {
+            env_vars,
+            service_opts: BTreeMap::new(),
+        }
+    }
+}
+
+impl HiveMetastore {
+    /// Sets a Hive configuration property, passed as system property (`-Dkey=value`) with `SERVICE_OPTS`,
+    /// e.g. `with_property("fs.s3a.endpoint", "http://minio:9000")`.
+    pub fn with_property(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.service_opts.insert(key.into(), value.into());
+        let service_opts = self
+            .service_opts
+            .iter()
+            .map(|(key, value)| format!("-D{key}={value}"))
+            .collect::<Vec<_>>()
+            .join(" ");
+        self.env_vars
+            .insert("SERVICE_OPTS".to_owned(), service_opts);
+        self
+    }
+
+    /// Sets the location of managed tables (`hive.metastore.warehouse.dir`),
+    /// e.g. `s3a://warehouse/` or a local directory, defaults to `/opt/hive/data/warehouse`.
+    pub fn with_warehouse_dir(self, warehouse_dir: &str) -> Self {
+        self.with_property("hive.metastore.warehouse.dir", warehouse_dir)
+    }
+
+    /// Stores the metadata in the Postgres database reachable with the given JDBC url
+    /// (e.g. `jdbc:postgresql://postgres:5432/postgres`), instead of the embedded Derby database.
+    /// The schema is created on startup.
+    ///
+    /// The image does not ship the JDBC driver, which has to be copied to `/opt/hive/lib/`
+    /// with [`testcontainers::core::ImageExt::with_copy_to`].
+    pub fn with_postgres_database(self, url: &str, user: &str, password: &str) -> Self {
+        self.with_database("postgres", "org.postgresql.Driver", url, user, password)
+    }
+
+    /// Stores the metadata in the MySQL database reachable with the given JDBC url
+    /// (e.g. `jdbc:mysql://mysql:3306/metastore`), instead of the embedded Derby database.
+    /// The schema is created on startup.
+    ///
+    /// The image does not ship the JDBC driver, which has to be copied to `/opt/hive/lib/`
+    /// with [`testcontainers::core::ImageExt::with_copy_to`].
+    pub fn with_mysql_database(self, url: &str, user: &str, password: &str) -> Self {
+        self.with_database("mysql", "com.mysql.cj.jdbc.Driver", url, user, password)
+    }
+
+    fn with_database(
+        mut self,
+        db_driver: &str,
+        driver_class: &str,
+        url: &str,
+        user: &str,
+        password: &str,
+    ) -> Self {
+        self.env_vars
+            .insert("DB_DRIVER".to_owned(), db_driver.to_owned());
+        self.with_property("javax.jdo.option.ConnectionDriverName", driver_class)
+            .with_property("javax.jdo.option.ConnectionURL", url)
+            .with_property("javax.jdo.option.ConnectionUserName", user)
+            .with_property("javax.jdo.option.ConnectionPassword", password)
+    }
+}
+
+impl Image for HiveMetastore {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn tag(&self) -> &str {
+        TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        // the metastore does not log to the console when it accepts connections, see `exec_after_start`
+        vec![]
+    }
+
+    fn env_vars(
+        &self,
+    ) -> impl IntoIterator<Item = (impl Into<Cow<'_, str>>, impl Into<Cow<'_, str>>)> {
+        &self.env_vars
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[HIVE_METASTORE_PORT]
+    }
+
+    fn exec_after_start(
+        &self,
+        _cs: ContainerState,
+    ) -> Result<Vec<ExecCommand>, TestcontainersError> {
+        Ok(vec![ExecCommand::new([
+            "timeout".to_string(),
+            READY_TIMEOUT_SECS.to_string(),
+            "bash".to_string(),
+            "-c".to_string(),
+            format!(
+                "until (echo > /dev/tcp/127.0.0.1/{}) 2>/dev/null; do sleep 0.5; done",
+                HIVE_METASTORE_PORT.as_u16()
+            ),
+        ])
+        .with_cmd_ready_condition(CmdWaitFor::exit_code(0))])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::TcpStream;
+
+    use super::*;
+    use crate::testcontainers::runners::SyncRunner;
+
+    #[test]
+    fn hive_metastore_accepts_connections() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = HiveMetastore::default()
+            .with_warehouse_dir("/tmp/warehouse")
+            .start()?;
+
+        TcpStream::connect((
+            node.get_host()?.to_string(),
+            node.get_host_port_ipv4(HIVE_METASTORE_PORT)?,
+        ))?;
+
+        let mut result = node.exec(
+            ExecCommand::new(["bash", "-c", "echo $SERVICE_OPTS"])
+                .with_cmd_ready_condition(CmdWaitFor::exit_code(0)),
+        )?;
+        let service_opts = String::from_utf8(result.stdout_to_vec()?)?;
+        assert_eq!(
+            service_opts.trim(),
+            "-Dhive.metastore.warehouse.dir=/tmp/warehouse"
+        );
+        Ok(())
+    }
+}
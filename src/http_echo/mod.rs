@@ -0,0 +1,94 @@
+use testcontainers::{
+    core::{wait::HttpWaitStrategy, ContainerPort, WaitFor},
+    Image,
+};
+
+const NAME: &str = "mccutchen/go-httpbin";
+const TAG: &str = "v2.15.0";
+
+/// Port of the HTTP server that the [`HttpBin`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`HttpBin`]: https://github.com/mccutchen/go-httpbin
+pub const HTTPBIN_PORT: ContainerPort = ContainerPort::Tcp(8080);
+
+/// Module to work with [`go-httpbin`] inside of tests.
+///
+/// Starts an instance of go-httpbin, a Go port of [`httpbin.org`], based on the official [`go-httpbin docker image`].
+/// It serves endpoints echoing the received requests (e.g. `/get`, `/post`, `/headers`)
+/// or returning specific responses (e.g. `/status/{code}`, `/delay/{seconds}`) on port 8080 ([`HTTPBIN_PORT`]),
+/// which makes it a generic target for HTTP client and proxy tests.
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{
+///     http_echo::{HttpBin, HTTPBIN_PORT},
+///     testcontainers::runners::SyncRunner,
+/// };
+///
+/// let httpbin_instance = HttpBin::default().start().unwrap();
+/// let url = format!(
+///     "http://{}:{}/get",
+///     httpbin_instance.get_host().unwrap(),
+///     httpbin_instance.get_host_port_ipv4(HTTPBIN_PORT).unwrap()
+/// );
+/// ```
+///
+/// [`go-httpbin`]: https://github.com/mccutchen/go-httpbin
+/// [`httpbin.org`]: https://httpbin.org/
+/// [`go-httpbin docker image`]: https://hub.docker.com/r/mccutchen/go-httpbin
+#[derive(Debug, Clone, Default)]
+pub struct HttpBin {
+    /// (remove if there is another variable)
+    /// Field is included to prevent this struct to be a unit struct.
+    /// This allows extending functionality (and thus further variables) without breaking changes
+    _priv: (),
+}
+
+impl Image for HttpBin {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn tag(&self) -> &str {
+        TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![WaitFor::http(
+            HttpWaitStrategy::new("/get")
+                .with_port(HTTPBIN_PORT)
+                .with_expected_status_code(200_u16),
+        )]
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[HTTPBIN_PORT]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testcontainers::runners::SyncRunner;
+
+    #[test]
+    fn httpbin_echo_request() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = HttpBin::default().start()?;
+        let url = format!(
+            "http://{}:{}",
+            node.get_host()?,
+            node.get_host_port_ipv4(HTTPBIN_PORT)?
+        );
+
+        let echo: serde_json::Value = reqwest::blocking::get(format!("{url}/get?foo=bar"))?
+            .error_for_status()?
+            .json()?;
+        assert_eq!(echo["args"]["foo"][0], "bar");
+
+        let response = reqwest::blocking::get(format!("{url}/status/418"))?;
+        assert_eq!(response.status(), 418);
+        Ok(())
+    }
+}
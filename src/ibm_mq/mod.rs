@@ -0,0 +1,160 @@
+use std::{borrow::Cow, collections::BTreeMap};
+
+use testcontainers::{
+    core::{ContainerPort, WaitFor},
+    Image,
+};
+
+const NAME: &str = "icr.io/ibm-messaging/mq";
+const TAG: &str = "9.4.1.0-r1";
+
+/// Port of the queue manager listener that the [`IBM MQ`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`IBM MQ`]: https://www.ibm.com/products/mq
+pub const IBM_MQ_PORT: ContainerPort = ContainerPort::Tcp(1414);
+
+/// Port of the web console and REST API (HTTPS) that the [`IBM MQ`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`IBM MQ`]: https://www.ibm.com/products/mq
+pub const IBM_MQ_WEB_PORT: ContainerPort = ContainerPort::Tcp(9443);
+
+/// Module to work with [`IBM MQ`] inside of tests.
+///
+/// Starts a queue manager based on the official [`IBM MQ Advanced for Developers docker image`].
+/// Clients connect on port 1414 ([`IBM_MQ_PORT`]) through the channel `DEV.APP.SVRCONN` as the `app` user,
+/// the web console and the REST API are served with a self-signed certificate on port 9443 ([`IBM_MQ_WEB_PORT`])
+/// for the `admin` user. The developer defaults include the queues `DEV.QUEUE.1` to `DEV.QUEUE.3`.
+///
+/// The queue manager is named `QM1` and both users have the password `passw0rd`, see [`IbmMq::with_queue_manager`],
+/// [`IbmMq::with_app_password`] and [`IbmMq::with_admin_password`].
+/// Due to licensing restrictions the license has to be accepted explicitly with [`IbmMq::with_accept_license`].
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{
+///     ibm_mq::{IbmMq, IBM_MQ_PORT},
+///     testcontainers::runners::SyncRunner,
+/// };
+///
+/// let mq_instance = IbmMq::default().with_accept_license().start().unwrap();
+/// let connection_name = format!(
+///     "{}({})",
+///     mq_instance.get_host().unwrap(),
+///     mq_instance.get_host_port_ipv4(IBM_MQ_PORT).unwrap()
+/// );
+/// ```
+///
+/// [`IBM MQ`]: https://www.ibm.com/products/mq
+/// [`IBM MQ Advanced for Developers docker image`]: https://github.com/ibm-messaging/mq-container
+#[derive(Debug, Clone)]
+pub struct IbmMq {
+    env_vars: BTreeMap<String, String>,
+}
+
+impl Default for IbmMq {
+    fn default() -> Self {
+        let mut env_vars = BTreeMap::new();
+        env_vars.insert("MQ_QMGR_NAME".to_owned(), "QM1".to_owned());
+        env_vars.insert("MQ_APP_PASSWORD".to_owned(), "passw0rd".to_owned());
+        env_vars.insert("MQ_ADMIN_PASSWORD".to_owned(), "passw0rd".to_owned());
+        Self { env_vars }
+    }
+}
+
+impl IbmMq {
+    /// Due to licensing restrictions you are required to explicitly accept the license of
+    /// IBM MQ Advanced for Developers (`LICENSE=accept`), otherwise the container does not start.
+    pub fn with_accept_license(mut self) -> Self {
+        self.env_vars
+            .insert("LICENSE".to_owned(), "accept".to_owned());
+        self
+    }
+
+    /// Sets the name of the queue manager (`MQ_QMGR_NAME`), defaults to `QM1`.
+    pub fn with_queue_manager(mut self, name: &str) -> Self {
+        self.env_vars
+            .insert("MQ_QMGR_NAME".to_owned(), name.to_owned());
+        self
+    }
+
+    /// Sets the password of the `app` user (`MQ_APP_PASSWORD`), defaults to `passw0rd`.
+    pub fn with_app_password(mut self, password: &str) -> Self {
+        self.env_vars
+            .insert("MQ_APP_PASSWORD".to_owned(), password.to_owned());
+        self
+    }
+
+    /// Sets the password of the `admin` user (`MQ_ADMIN_PASSWORD`), defaults to `passw0rd`.
+    pub fn with_admin_password(mut self, password: &str) -> Self {
+        self.env_vars
+            .insert("MQ_ADMIN_PASSWORD".to_owned(), password.to_owned());
+        self
+    }
+}
+
+impl Image for IbmMq {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn tag(&self) -> &str {
+        TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![WaitFor::message_on_stdout("Started queue manager")]
+    }
+
+    fn env_vars(
+        &self,
+    ) -> impl IntoIterator<Item = (impl Into<Cow<'_, str>>, impl Into<Cow<'_, str>>)> {
+        &self.env_vars
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[IBM_MQ_PORT, IBM_MQ_WEB_PORT]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use retry::{delay::Fixed, retry};
+
+    use super::*;
+    use crate::testcontainers::runners::SyncRunner;
+
+    #[test]
+    fn ibm_mq_rest_api() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = IbmMq::default()
+            .with_accept_license()
+            .with_queue_manager("TESTQM")
+            .with_admin_password("secret")
+            .start()?;
+        let url = format!(
+            "https://{}:{}/ibmmq/rest/v2/admin/qmgr",
+            node.get_host()?,
+            node.get_host_port_ipv4(IBM_MQ_WEB_PORT)?
+        );
+
+        // the web server is started after the queue manager
+        let client = reqwest::blocking::Client::builder()
+            .danger_accept_invalid_certs(true)
+            .build()?;
+        let qmgrs = retry(Fixed::from_millis(1000).take(60), || {
+            client
+                .get(&url)
+                .basic_auth("admin", Some("secret"))
+                .send()
+                .and_then(|response| response.error_for_status())
+                .and_then(|response| response.json::<serde_json::Value>())
+                .map_err(|err| err.to_string())
+        })
+        .map_err(|err| err.to_string())?;
+        assert_eq!(qmgrs["qmgr"][0]["name"], "TESTQM");
+        assert_eq!(qmgrs["qmgr"][0]["state"], "running");
+        Ok(())
+    }
+}
@@ -0,0 +1,182 @@
+use std::{borrow::Cow, collections::BTreeMap};
+
+use testcontainers::{
+    core::{wait::HttpWaitStrategy, ContainerPort, WaitFor},
+    Image,
+};
+
+const NAME: &str = "influxdb";
+const TAG: &str = "2.7";
+
+/// Port of the HTTP API that the [`InfluxDB`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`InfluxDB`]: https://www.influxdata.com/
+pub const INFLUXDB_PORT: ContainerPort = ContainerPort::Tcp(8086);
+
+/// Module to work with [`InfluxDB`] 2.x inside of tests.
+///
+/// Starts an instance of InfluxDB based on the official [`InfluxDB docker image`],
+/// serving the HTTP API on port 8086 ([`INFLUXDB_PORT`]).
+///
+/// The instance is set up automatically on startup with the following defaults, which can be
+/// changed with the builder methods:
+/// - user `admin` with password `password`
+/// - organization `testcontainers`
+/// - bucket `test`
+/// - admin token `testcontainers-token`
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{
+///     influxdb::{InfluxDb2, INFLUXDB_PORT},
+///     testcontainers::runners::SyncRunner,
+/// };
+///
+/// let influxdb_instance = InfluxDb2::default()
+///     .with_org("my-org")
+///     .with_bucket("my-bucket")
+///     .start()
+///     .unwrap();
+/// let url = format!(
+///     "http://{}:{}",
+///     influxdb_instance.get_host().unwrap(),
+///     influxdb_instance.get_host_port_ipv4(INFLUXDB_PORT).unwrap()
+/// );
+/// ```
+///
+/// [`InfluxDB`]: https://www.influxdata.com/
+/// [`InfluxDB docker image`]: https://hub.docker.com/_/influxdb
+#[derive(Debug, Clone)]
+pub struct InfluxDb2 {
+    env_vars: BTreeMap<String, String>,
+}
+
+impl Default for InfluxDb2 {
+    fn default() -> Self {
+        let mut env_vars = BTreeMap::new();
+        env_vars.insert("DOCKER_INFLUXDB_INIT_MODE".to_owned(), "setup".to_owned());
+        env_vars.insert(
+            "DOCKER_INFLUXDB_INIT_USERNAME".to_owned(),
+            "admin".to_owned(),
+        );
+        env_vars.insert(
+            "DOCKER_INFLUXDB_INIT_PASSWORD".to_owned(),
+            "password".to_owned(),
+        );
+        env_vars.insert(
+            "DOCKER_INFLUXDB_INIT_ORG".to_owned(),
+            "testcontainers".to_owned(),
+        );
+        env_vars.insert("DOCKER_INFLUXDB_INIT_BUCKET".to_owned(), "test".to_owned());
+        env_vars.insert(
+            "DOCKER_INFLUXDB_INIT_ADMIN_TOKEN".to_owned(),
+            "testcontainers-token".to_owned(),
+        );
+        Self { env_vars }
+    }
+}
+
+impl InfluxDb2 {
+    /// Sets the name of the initial organization (`DOCKER_INFLUXDB_INIT_ORG`), defaults to `testcontainers`.
+    pub fn with_org(mut self, org: &str) -> Self {
+        self.env_vars
+            .insert("DOCKER_INFLUXDB_INIT_ORG".to_owned(), org.to_owned());
+        self
+    }
+
+    /// Sets the name of the initial bucket (`DOCKER_INFLUXDB_INIT_BUCKET`), defaults to `test`.
+    pub fn with_bucket(mut self, bucket: &str) -> Self {
+        self.env_vars
+            .insert("DOCKER_INFLUXDB_INIT_BUCKET".to_owned(), bucket.to_owned());
+        self
+    }
+
+    /// Sets the token of the initial admin user (`DOCKER_INFLUXDB_INIT_ADMIN_TOKEN`), defaults to `testcontainers-token`.
+    pub fn with_admin_token(mut self, token: &str) -> Self {
+        self.env_vars.insert(
+            "DOCKER_INFLUXDB_INIT_ADMIN_TOKEN".to_owned(),
+            token.to_owned(),
+        );
+        self
+    }
+
+    /// Sets the name of the initial admin user (`DOCKER_INFLUXDB_INIT_USERNAME`), defaults to `admin`.
+    pub fn with_username(mut self, username: &str) -> Self {
+        self.env_vars.insert(
+            "DOCKER_INFLUXDB_INIT_USERNAME".to_owned(),
+            username.to_owned(),
+        );
+        self
+    }
+
+    /// Sets the password of the initial admin user (`DOCKER_INFLUXDB_INIT_PASSWORD`), defaults to `password`.
+    /// Has to be at least 8 characters long.
+    pub fn with_password(mut self, password: &str) -> Self {
+        self.env_vars.insert(
+            "DOCKER_INFLUXDB_INIT_PASSWORD".to_owned(),
+            password.to_owned(),
+        );
+        self
+    }
+}
+
+impl Image for InfluxDb2 {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn tag(&self) -> &str {
+        TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![WaitFor::http(
+            HttpWaitStrategy::new("/health")
+                .with_port(INFLUXDB_PORT)
+                .with_expected_status_code(200_u16),
+        )]
+    }
+
+    fn env_vars(
+        &self,
+    ) -> impl IntoIterator<Item = (impl Into<Cow<'_, str>>, impl Into<Cow<'_, str>>)> {
+        &self.env_vars
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[INFLUXDB_PORT]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testcontainers::runners::SyncRunner;
+
+    #[test]
+    fn influxdb2_setup() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = InfluxDb2::default()
+            .with_org("test-org")
+            .with_bucket("test-bucket")
+            .with_admin_token("test-token")
+            .with_username("test-user")
+            .with_password("test-password")
+            .start()?;
+        let url = format!(
+            "http://{}:{}/api/v2/buckets?org=test-org&name=test-bucket",
+            node.get_host()?,
+            node.get_host_port_ipv4(INFLUXDB_PORT)?
+        );
+
+        let buckets: serde_json::Value = reqwest::blocking::Client::new()
+            .get(url)
+            .header("Authorization", "Token test-token")
+            .send()?
+            .error_for_status()?
+            .json()?;
+        assert_eq!(buckets["buckets"][0]["name"], "test-bucket");
+        Ok(())
+    }
+}
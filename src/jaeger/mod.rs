@@ -0,0 +1,165 @@
+use std::{borrow::Cow, collections::BTreeMap};
+
+use testcontainers::{
+    core::{wait::HttpWaitStrategy, ContainerPort, WaitFor},
+    Image,
+};
+
+const NAME: &str = "jaegertracing/all-in-one";
+const TAG: &str = "1.63.0";
+
+/// Port of the OTLP gRPC receiver that the [`Jaeger`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Jaeger`]: https://www.jaegertracing.io/
+pub const JAEGER_OTLP_GRPC_PORT: ContainerPort = ContainerPort::Tcp(4317);
+
+/// Port of the OTLP HTTP receiver that the [`Jaeger`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Jaeger`]: https://www.jaegertracing.io/
+pub const JAEGER_OTLP_HTTP_PORT: ContainerPort = ContainerPort::Tcp(4318);
+
+/// Port of the web UI and the query API (`/api/...`) that the [`Jaeger`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Jaeger`]: https://www.jaegertracing.io/
+pub const JAEGER_UI_PORT: ContainerPort = ContainerPort::Tcp(16686);
+
+/// Module to work with [`Jaeger`] inside of tests.
+///
+/// Starts an all-in-one instance of Jaeger (collector, in-memory storage and query service)
+/// based on the official [`Jaeger docker image`].
+///
+/// Traces are received via OTLP on ports 4317 ([`JAEGER_OTLP_GRPC_PORT`]) and 4318 ([`JAEGER_OTLP_HTTP_PORT`]),
+/// see [`Jaeger::with_otlp_enabled`]. The web UI and the query API are served on port 16686 ([`JAEGER_UI_PORT`]).
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{
+///     jaeger::{Jaeger, JAEGER_OTLP_HTTP_PORT},
+///     testcontainers::runners::SyncRunner,
+/// };
+///
+/// let jaeger_instance = Jaeger::default().with_otlp_enabled().start().unwrap();
+/// let otlp_endpoint = format!(
+///     "http://{}:{}",
+///     jaeger_instance.get_host().unwrap(),
+///     jaeger_instance
+///         .get_host_port_ipv4(JAEGER_OTLP_HTTP_PORT)
+///         .unwrap()
+/// );
+/// ```
+///
+/// [`Jaeger`]: https://www.jaegertracing.io/
+/// [`Jaeger docker image`]: https://hub.docker.com/r/jaegertracing/all-in-one
+#[derive(Debug, Clone, Default)]
+pub struct Jaeger {
+    env_vars: BTreeMap<String, String>,
+}
+
+impl Jaeger {
+    /// Enables the OTLP receivers (`COLLECTOR_OTLP_ENABLED`) on ports 4317 and 4318.
+    /// Recent versions of Jaeger enable them by default, this makes it explicit.
+    pub fn with_otlp_enabled(mut self) -> Self {
+        self.env_vars
+            .insert("COLLECTOR_OTLP_ENABLED".to_owned(), "true".to_owned());
+        self
+    }
+}
+
+impl Image for Jaeger {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn tag(&self) -> &str {
+        TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![WaitFor::http(
+            HttpWaitStrategy::new("/")
+                .with_port(JAEGER_UI_PORT)
+                .with_expected_status_code(200_u16),
+        )]
+    }
+
+    fn env_vars(
+        &self,
+    ) -> impl IntoIterator<Item = (impl Into<Cow<'_, str>>, impl Into<Cow<'_, str>>)> {
+        &self.env_vars
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[JAEGER_OTLP_GRPC_PORT, JAEGER_OTLP_HTTP_PORT, JAEGER_UI_PORT]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use retry::{delay::Fixed, retry};
+
+    use super::*;
+    use crate::testcontainers::runners::SyncRunner;
+
+    #[test]
+    fn jaeger_receives_otlp_trace() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = Jaeger::default().with_otlp_enabled().start()?;
+        let host = node.get_host()?;
+        let otlp_url = format!(
+            "http://{host}:{}/v1/traces",
+            node.get_host_port_ipv4(JAEGER_OTLP_HTTP_PORT)?
+        );
+        let query_url = format!(
+            "http://{host}:{}/api/services",
+            node.get_host_port_ipv4(JAEGER_UI_PORT)?
+        );
+
+        let trace = serde_json::json!({
+            "resourceSpans": [{
+                "resource": {
+                    "attributes": [{
+                        "key": "service.name",
+                        "value": { "stringValue": "testcontainers" }
+                    }]
+                },
+                "scopeSpans": [{
+                    "spans": [{
+                        "traceId": "5b8efff798038103d269b633813fc60c",
+                        "spanId": "eee19b7ec3c1b174",
+                        "name": "test-span",
+                        "kind": 1,
+                        "startTimeUnixNano": "1700000000000000000",
+                        "endTimeUnixNano": "1700000001000000000"
+                    }]
+                }]
+            }]
+        });
+        reqwest::blocking::Client::new()
+            .post(otlp_url)
+            .json(&trace)
+            .send()?
+            .error_for_status()?;
+
+        let services = retry(Fixed::from(Duration::from_millis(500)).take(20), || {
+            let services: serde_json::Value = reqwest::blocking::get(&query_url)
+                .and_then(|response| response.json())
+                .map_err(|err| err.to_string())?;
+            if services["data"]
+                .as_array()
+                .is_some_and(|data| data.iter().any(|service| service == "testcontainers"))
+            {
+                Ok(services)
+            } else {
+                Err(format!("service not yet reported: {services}"))
+            }
+        })
+        .map_err(|err| err.to_string())?;
+        assert!(services["data"].as_array().is_some());
+        Ok(())
+    }
+}
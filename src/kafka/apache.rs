@@ -1,7 +1,7 @@
 use std::{borrow::Cow, collections::HashMap};
 
 use testcontainers::{
-    core::{ContainerPort, ContainerState, ExecCommand, WaitFor},
+    core::{CmdWaitFor, ContainerPort, ContainerState, ExecCommand, WaitFor},
     Image,
 };
 
@@ -19,6 +19,9 @@ const START_SCRIPT: &str = "/opt/kafka/testcontainers_start.sh";
 const DEFAULT_INTERNAL_TOPIC_RF: usize = 1;
 const DEFAULT_CLUSTER_ID: &str = "5L6g3nShT-eMCtK--X86sw";
 const DEFAULT_BROKER_ID: usize = 1;
+const DEFAULT_ADVERTISED_HOST: &str = "127.0.0.1";
+const BROKER_PORT: u16 = 9093;
+const KAFKA_CONFIGS_BIN: &str = "/opt/kafka/bin/kafka-configs.sh";
 
 /// Module to work with [`Apache Kafka`] broker
 ///
@@ -44,6 +47,38 @@ const DEFAULT_BROKER_ID: usize = 1;
 pub struct Kafka {
     env_vars: HashMap<String, String>,
     image_name: String,
+    advertised_host: String,
+    internal_network_alias: Option<String>,
+    listeners: Vec<Listener>,
+    sasl_mechanisms: Vec<String>,
+    scram_users: Vec<(ScramMechanism, String, String)>,
+    tls: bool,
+}
+
+/// SCRAM mechanisms supported by [`Kafka::with_sasl_scram`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScramMechanism {
+    /// `SCRAM-SHA-256`
+    Sha256,
+    /// `SCRAM-SHA-512`
+    Sha512,
+}
+
+impl ScramMechanism {
+    /// Returns the name of the mechanism, as used in the `sasl.mechanism` client configuration.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Sha256 => "SCRAM-SHA-256",
+            Self::Sha512 => "SCRAM-SHA-512",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Listener {
+    name: String,
+    port: u16,
+    advertised_host: String,
 }
 
 impl Default for Kafka {
@@ -52,7 +87,7 @@ impl Default for Kafka {
         env_vars.insert(
             "KAFKA_LISTENERS".to_owned(),
             format!(
-                "PLAINTEXT://0.0.0.0:{},BROKER://0.0.0.0:9093,CONTROLLER://0.0.0.0:9094",
+                "PLAINTEXT://0.0.0.0:{},BROKER://0.0.0.0:{BROKER_PORT},CONTROLLER://0.0.0.0:9094",
                 KAFKA_PORT.as_u16()
             ),
         );
@@ -94,6 +129,12 @@ impl Default for Kafka {
         Self {
             env_vars,
             image_name: KAFKA_NATIVE_IMAGE_NAME.to_string(),
+            advertised_host: DEFAULT_ADVERTISED_HOST.to_owned(),
+            internal_network_alias: None,
+            listeners: Vec::new(),
+            sasl_mechanisms: Vec::new(),
+            scram_users: Vec::new(),
+            tls: false,
         }
     }
 }
@@ -105,6 +146,210 @@ impl Kafka {
 
         self
     }
+
+    /// Sets the host advertised to clients connecting through [`KAFKA_PORT`],
+    /// defaults to `127.0.0.1`.
+    ///
+    /// Useful when the tests are not running on the docker host, e.g. in CI running inside of a container.
+    pub fn with_advertised_host(mut self, host: &str) -> Self {
+        self.advertised_host = host.to_owned();
+        self
+    }
+
+    /// Sets the network alias of the container, which is advertised for the internal `BROKER`
+    /// listener (port 9093), so other containers on the same network can connect via `<alias>:9093`.
+    ///
+    /// Note that the container has to be started with the same alias,
+    /// see [`testcontainers::core::ImageExt::with_network`] and [`testcontainers::core::ImageExt::with_container_name`].
+    pub fn with_internal_network_alias(mut self, alias: &str) -> Self {
+        self.internal_network_alias = Some(alias.to_owned());
+        self
+    }
+
+    /// Adds an additional `PLAINTEXT` listener with the given name, listening on the given port
+    /// inside of the container and advertising `<advertised_host>:<port>`.
+    /// Can be called multiple times to add several listeners, e.g. one per docker network.
+    ///
+    /// # Example
+    /// ```
+    /// use testcontainers_modules::kafka::apache;
+    ///
+    /// let kafka = apache::Kafka::default().with_listener("DOCKER", 19092, "kafka");
+    /// ```
+    pub fn with_listener(mut self, name: &str, port: u16, advertised_host: &str) -> Self {
+        let name = name.to_uppercase();
+        for (key, value) in [
+            ("KAFKA_LISTENERS", format!("{name}://0.0.0.0:{port}")),
+            (
+                "KAFKA_LISTENER_SECURITY_PROTOCOL_MAP",
+                format!("{name}:PLAINTEXT"),
+            ),
+        ] {
+            let entry = self.env_vars.entry(key.to_owned()).or_default();
+            entry.push(',');
+            entry.push_str(&value);
+        }
+        self.listeners.push(Listener {
+            name,
+            port,
+            advertised_host: advertised_host.to_owned(),
+        });
+        self
+    }
+
+    /// Enables `SASL/PLAIN` authentication for clients connecting through [`KAFKA_PORT`]
+    /// (security protocol `SASL_PLAINTEXT`), with the given `(username, password)` pairs.
+    /// The internal `BROKER` listener stays unauthenticated.
+    ///
+    /// # Example
+    /// ```
+    /// use testcontainers_modules::kafka::apache;
+    ///
+    /// let kafka = apache::Kafka::default().with_sasl_plain(&[("alice", "alice-secret")]);
+    /// ```
+    pub fn with_sasl_plain(mut self, users: &[(&str, &str)]) -> Self {
+        let users: String = users
+            .iter()
+            .map(|(username, password)| format!(" user_{username}={}", jaas_quote(password)))
+            .collect();
+        self.enable_sasl(
+            "PLAIN",
+            format!("org.apache.kafka.common.security.plain.PlainLoginModule required{users};"),
+        );
+        self
+    }
+
+    /// Enables `SASL/SCRAM` authentication with the given mechanism for clients connecting through
+    /// [`KAFKA_PORT`] (security protocol `SASL_PLAINTEXT`), with the given `(username, password)` pairs.
+    /// The internal `BROKER` listener stays unauthenticated.
+    ///
+    /// The credentials are created with `kafka-configs.sh` once the broker is started,
+    /// which is not part of the GraalVM image. Thus the JVM image has to be used (see [`Kafka::with_jvm_image`]),
+    /// otherwise starting the container fails.
+    ///
+    /// # Example
+    /// ```
+    /// use testcontainers_modules::kafka::apache;
+    ///
+    /// let kafka = apache::Kafka::default()
+    ///     .with_jvm_image()
+    ///     .with_sasl_scram(apache::ScramMechanism::Sha512, &[("alice", "alice-secret")]);
+    /// ```
+    pub fn with_sasl_scram(mut self, mechanism: ScramMechanism, users: &[(&str, &str)]) -> Self {
+        self.enable_sasl(
+            mechanism.as_str(),
+            "org.apache.kafka.common.security.scram.ScramLoginModule required;".to_owned(),
+        );
+        self.scram_users.extend(
+            users.iter().map(|(username, password)| {
+                (mechanism, username.to_string(), password.to_string())
+            }),
+        );
+        self
+    }
+
+    /// Enables TLS for clients connecting through [`KAFKA_PORT`] (security protocol `SSL`,
+    /// or `SASL_SSL` in combination with SASL), using the server certificate and key of the given [`TlsCertificates`].
+    /// Clients can verify the broker with [`TlsCertificates::ca_cert_pem`], e.g. via the `ssl.ca.pem`
+    /// client configuration, client certificates are not required.
+    /// The internal `BROKER` listener stays unencrypted.
+    ///
+    /// # Example
+    /// ```
+    /// use testcontainers_modules::{kafka::apache, tls::TlsCertificates};
+    ///
+    /// let certificates = TlsCertificates::default();
+    /// let kafka = apache::Kafka::default().with_tls(&certificates);
+    /// ```
+    ///
+    /// [`TlsCertificates`]: crate::tls::TlsCertificates
+    /// [`TlsCertificates::ca_cert_pem`]: crate::tls::TlsCertificates::ca_cert_pem
+    #[cfg(feature = "tls")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "tls")))]
+    pub fn with_tls(mut self, certificates: &crate::tls::TlsCertificates) -> Self {
+        self.tls = true;
+        self.update_client_protocol();
+        // the broker config is generated from the env vars line by line,
+        // the PEM parser of Kafka ignores whitespace within the base64 content
+        for (key, value) in [
+            ("KAFKA_LISTENER_NAME_PLAINTEXT_SSL_KEYSTORE_TYPE", "PEM"),
+            (
+                "KAFKA_LISTENER_NAME_PLAINTEXT_SSL_KEYSTORE_CERTIFICATE_CHAIN",
+                certificates.cert_pem(),
+            ),
+            (
+                "KAFKA_LISTENER_NAME_PLAINTEXT_SSL_KEYSTORE_KEY",
+                certificates.key_pem(),
+            ),
+        ] {
+            self.env_vars
+                .insert(key.to_owned(), value.trim().replace('\n', " "));
+        }
+        self
+    }
+
+    /// Sets the security protocol of the `PLAINTEXT` listener according to the enabled SASL mechanisms and TLS.
+    fn update_client_protocol(&mut self) {
+        let protocol = match (self.sasl_mechanisms.is_empty(), self.tls) {
+            (true, false) => "PLAINTEXT",
+            (true, true) => "SSL",
+            (false, false) => "SASL_PLAINTEXT",
+            (false, true) => "SASL_SSL",
+        };
+        if let Some(protocol_map) = self
+            .env_vars
+            .get_mut("KAFKA_LISTENER_SECURITY_PROTOCOL_MAP")
+        {
+            *protocol_map = protocol_map
+                .split(',')
+                .map(|entry| match entry.split_once(':') {
+                    Some(("PLAINTEXT", _)) => format!("PLAINTEXT:{protocol}"),
+                    _ => entry.to_owned(),
+                })
+                .collect::<Vec<_>>()
+                .join(",");
+        }
+    }
+
+    fn enable_sasl(&mut self, mechanism: &str, jaas_config: String) {
+        if !self.sasl_mechanisms.iter().any(|m| m == mechanism) {
+            self.sasl_mechanisms.push(mechanism.to_owned());
+        }
+        self.update_client_protocol();
+        self.env_vars.insert(
+            "KAFKA_SASL_ENABLED_MECHANISMS".to_owned(),
+            self.sasl_mechanisms.join(","),
+        );
+        // the image maps `_` to `.` and `___` to `-` when converting env vars to broker properties
+        self.env_vars.insert(
+            format!(
+                "KAFKA_LISTENER_NAME_PLAINTEXT_{}_SASL_JAAS_CONFIG",
+                mechanism.replace('-', "___")
+            ),
+            jaas_config,
+        );
+    }
+
+    fn is_jvm_image(&self) -> bool {
+        self.image_name == KAFKA_IMAGE_NAME
+    }
+
+    fn advertised_listeners(&self, host_port: u16) -> String {
+        let broker_host = self
+            .internal_network_alias
+            .as_deref()
+            .unwrap_or("localhost");
+        let mut listeners = vec![
+            format!("PLAINTEXT://{}:{host_port}", self.advertised_host),
+            format!("BROKER://{broker_host}:{BROKER_PORT}"),
+        ];
+        listeners.extend(
+            self.listeners
+                .iter()
+                .map(|l| format!("{}://{}:{}", l.name, l.advertised_host, l.port)),
+        );
+        listeners.join(",")
+    }
 }
 
 impl Image for Kafka {
@@ -156,6 +401,12 @@ impl Image for Kafka {
         &self,
         cs: ContainerState,
     ) -> Result<Vec<ExecCommand>, testcontainers::TestcontainersError> {
+        if !self.scram_users.is_empty() && !self.is_jvm_image() {
+            return Err(testcontainers::TestcontainersError::other(
+                "SASL/SCRAM requires the JVM image of Kafka, see `Kafka::with_jvm_image`",
+            ));
+        }
+
         let mut commands = vec![];
         // with container running, port which will accept kafka connections is known
         // so we can proceed with creating a script which starts kafka broker
@@ -166,8 +417,8 @@ impl Image for Kafka {
             "sh".to_string(),
             "-c".to_string(),
             format!(
-                "echo '#!/usr/bin/env bash\nexport KAFKA_ADVERTISED_LISTENERS=PLAINTEXT://127.0.0.1:{},BROKER://localhost:9093\n/etc/kafka/docker/run \n' > {}",
-                cs.host_port_ipv4(KAFKA_PORT)?,
+                "echo '#!/usr/bin/env bash\nexport KAFKA_ADVERTISED_LISTENERS={}\n/etc/kafka/docker/run \n' > {}",
+                self.advertised_listeners(cs.host_port_ipv4(KAFKA_PORT)?),
                 START_SCRIPT
             ),
         ];
@@ -176,17 +427,40 @@ impl Image for Kafka {
         // for the message in container log, not script output.
         commands.push(ExecCommand::new(cmd).with_container_ready_conditions(ready_conditions));
 
+        // SCRAM credentials are stored in the cluster metadata,
+        // thus can only be created once the broker is running
+        for (mechanism, username, password) in &self.scram_users {
+            let cmd = vec![
+                KAFKA_CONFIGS_BIN.to_string(),
+                "--bootstrap-server".to_string(),
+                format!("localhost:{BROKER_PORT}"),
+                "--alter".to_string(),
+                "--add-config".to_string(),
+                format!("{}=[password={password}]", mechanism.as_str()),
+                "--entity-type".to_string(),
+                "users".to_string(),
+                "--entity-name".to_string(),
+                username.clone(),
+            ];
+            commands.push(ExecCommand::new(cmd).with_cmd_ready_condition(CmdWaitFor::exit_code(0)));
+        }
+
         Ok(commands)
     }
 }
 
+/// Quotes a value of a JAAS configuration option, escaping `\` and `"`.
+fn jaas_quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
 #[cfg(test)]
 mod tests {
     use std::time::Duration;
 
     use futures::StreamExt;
     use rdkafka::{
-        consumer::{Consumer, StreamConsumer},
+        consumer::{BaseConsumer, Consumer, StreamConsumer},
         producer::{FutureProducer, FutureRecord},
         ClientConfig, Message,
     };
@@ -194,6 +468,44 @@ mod tests {
 
     use crate::kafka::apache;
 
+    /// Produces a message to the given topic and consumes it again, using the given client configuration.
+    async fn produce_and_consume_message(mut client_config: ClientConfig, topic: &str) {
+        let producer = client_config
+            .clone()
+            .set("message.timeout.ms", "5000")
+            .create::<FutureProducer>()
+            .expect("Failed to create Kafka FutureProducer");
+
+        let consumer = client_config
+            .set("group.id", "testcontainer-rs")
+            .set("session.timeout.ms", "6000")
+            .set("enable.auto.commit", "false")
+            .set("auto.offset.reset", "earliest")
+            .create::<StreamConsumer>()
+            .expect("Failed to create Kafka StreamConsumer");
+
+        producer
+            .send(
+                FutureRecord::to(topic).payload("Message").key("Key"),
+                Duration::from_secs(0),
+            )
+            .await
+            .unwrap();
+
+        consumer
+            .subscribe(&[topic])
+            .expect("Failed to subscribe to a topic");
+
+        let borrowed_message = tokio::time::timeout(Duration::from_secs(10), consumer.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            "Message",
+            borrowed_message.payload_view::<str>().unwrap().unwrap()
+        );
+    }
+
     #[tokio::test]
     async fn produce_and_consume_messages_graalvm(
     ) -> Result<(), Box<dyn std::error::Error + 'static>> {
@@ -333,4 +645,158 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn advertises_configured_host() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let kafka_node = apache::Kafka::default()
+            .with_advertised_host("localhost")
+            .with_listener("docker", 19092, "kafka")
+            .start()
+            .await?;
+        let host_port = kafka_node.get_host_port_ipv4(apache::KAFKA_PORT).await?;
+
+        let consumer = ClientConfig::new()
+            .set("bootstrap.servers", format!("127.0.0.1:{host_port}"))
+            .create::<BaseConsumer>()
+            .expect("Failed to create Kafka BaseConsumer");
+        let metadata = consumer.fetch_metadata(None, Duration::from_secs(10))?;
+
+        assert_eq!(1, metadata.brokers().len());
+        assert_eq!("localhost", metadata.brokers()[0].host());
+        assert_eq!(i32::from(host_port), metadata.brokers()[0].port());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn produce_and_consume_messages_with_sasl_plain(
+    ) -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let kafka_node = apache::Kafka::default()
+            .with_sasl_plain(&[("alice", "alice-secret")])
+            .start()
+            .await?;
+
+        let bootstrap_servers = format!(
+            "127.0.0.1:{}",
+            kafka_node.get_host_port_ipv4(apache::KAFKA_PORT).await?
+        );
+
+        let mut client_config = ClientConfig::new();
+        client_config
+            .set("bootstrap.servers", &bootstrap_servers)
+            .set("security.protocol", "SASL_PLAINTEXT")
+            .set("sasl.mechanisms", "PLAIN")
+            .set("sasl.username", "alice")
+            .set("sasl.password", "alice-secret");
+        produce_and_consume_message(client_config, "test-topic").await;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn produce_and_consume_messages_with_sasl_scram(
+    ) -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let kafka_node = apache::Kafka::default()
+            .with_jvm_image()
+            .with_sasl_scram(apache::ScramMechanism::Sha256, &[("alice", "alice-secret")])
+            .with_sasl_scram(apache::ScramMechanism::Sha512, &[("bob", "bob-secret")])
+            .start()
+            .await?;
+
+        let bootstrap_servers = format!(
+            "127.0.0.1:{}",
+            kafka_node.get_host_port_ipv4(apache::KAFKA_PORT).await?
+        );
+
+        for (mechanism, username, password, topic) in [
+            (
+                apache::ScramMechanism::Sha256,
+                "alice",
+                "alice-secret",
+                "topic-256",
+            ),
+            (
+                apache::ScramMechanism::Sha512,
+                "bob",
+                "bob-secret",
+                "topic-512",
+            ),
+        ] {
+            let mut client_config = ClientConfig::new();
+            client_config
+                .set("bootstrap.servers", &bootstrap_servers)
+                .set("security.protocol", "SASL_PLAINTEXT")
+                .set("sasl.mechanisms", mechanism.as_str())
+                .set("sasl.username", username)
+                .set("sasl.password", password);
+            produce_and_consume_message(client_config, topic).await;
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "tls")]
+    #[tokio::test]
+    async fn produce_and_consume_messages_with_tls(
+    ) -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let certificates = crate::tls::TlsCertificates::default();
+        let kafka_node = apache::Kafka::default()
+            .with_jvm_image()
+            .with_tls(&certificates)
+            .start()
+            .await?;
+
+        let bootstrap_servers = format!(
+            "127.0.0.1:{}",
+            kafka_node.get_host_port_ipv4(apache::KAFKA_PORT).await?
+        );
+
+        let mut client_config = ClientConfig::new();
+        client_config
+            .set("bootstrap.servers", &bootstrap_servers)
+            .set("security.protocol", "SSL")
+            .set("ssl.ca.pem", certificates.ca_cert_pem());
+        produce_and_consume_message(client_config, "test-topic").await;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "tls")]
+    #[test]
+    fn tls_and_sasl_combine_to_sasl_ssl() {
+        let certificates = crate::tls::TlsCertificates::default();
+        let expected = "BROKER:PLAINTEXT,PLAINTEXT:SASL_SSL,CONTROLLER:PLAINTEXT";
+        for kafka in [
+            apache::Kafka::default()
+                .with_tls(&certificates)
+                .with_sasl_plain(&[("alice", "alice-secret")]),
+            apache::Kafka::default()
+                .with_sasl_plain(&[("alice", "alice-secret")])
+                .with_tls(&certificates),
+        ] {
+            assert_eq!(
+                kafka.env_vars["KAFKA_LISTENER_SECURITY_PROTOCOL_MAP"],
+                expected
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn sasl_scram_requires_jvm_image() {
+        let _ = pretty_env_logger::try_init();
+        let result = apache::Kafka::default()
+            .with_sasl_scram(apache::ScramMechanism::Sha256, &[("alice", "alice-secret")])
+            .start()
+            .await;
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn jaas_quote_escapes_quotes_and_backslashes() {
+        assert_eq!(apache::jaas_quote("secret"), "\"secret\"");
+        assert_eq!(apache::jaas_quote(r#"a"b\c"#), r#""a\"b\\c""#);
+    }
 }
@@ -0,0 +1,272 @@
+use std::{borrow::Cow, collections::BTreeMap};
+
+use testcontainers::{
+    core::{
+        wait::HttpWaitStrategy, CmdWaitFor, ContainerPort, ContainerState, ExecCommand, WaitFor,
+    },
+    Image, TestcontainersError,
+};
+
+const NAME: &str = "confluentinc/cp-kafka-connect";
+const TAG: &str = "7.7.1";
+
+/// Port of the REST API that the [`Kafka Connect`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Kafka Connect`]: https://docs.confluent.io/platform/current/connect/index.html
+pub const KAFKA_CONNECT_PORT: ContainerPort = ContainerPort::Tcp(8083);
+
+/// Module to work with [`Kafka Connect`] inside of tests.
+///
+/// Starts a distributed Kafka Connect worker based on the official [`Kafka Connect docker image`],
+/// serving the REST API on port 8083 ([`KAFKA_CONNECT_PORT`]).
+///
+/// The worker stores its configs, offsets and statuses in Kafka topics (with a replication factor of 1),
+/// thus it has to reach a Kafka broker, usually another container in the same network
+/// (see [`testcontainers::core::ImageExt::with_network`]), whose address is set with [`KafkaConnect::with_bootstrap_servers`].
+/// It defaults to `kafka:9093`, the internal listener of `kafka::apache::Kafka` started with the network alias `kafka`.
+///
+/// Connectors can be registered on startup with [`KafkaConnect::with_connector`],
+/// or later with [`KafkaConnect::register_connector_command`].
+///
+/// # Example
+/// ```rust,ignore
+/// use testcontainers_modules::{
+///     kafka::apache,
+///     kafka_connect::{KafkaConnect, KAFKA_CONNECT_PORT},
+///     testcontainers::{runners::SyncRunner, ImageExt},
+/// };
+///
+/// let _kafka_node = apache::Kafka::default()
+///     .with_internal_network_alias("kafka")
+///     .with_network("kafka-connect-example")
+///     .with_container_name("kafka")
+///     .start()
+///     .unwrap();
+/// let connect_node = KafkaConnect::default()
+///     .with_bootstrap_servers("kafka:9093")
+///     .with_network("kafka-connect-example")
+///     .start()
+///     .unwrap();
+/// let url = format!(
+///     "http://{}:{}/connectors",
+///     connect_node.get_host().unwrap(),
+///     connect_node.get_host_port_ipv4(KAFKA_CONNECT_PORT).unwrap()
+/// );
+/// ```
+///
+/// [`Kafka Connect`]: https://docs.confluent.io/platform/current/connect/index.html
+/// [`Kafka Connect docker image`]: https://hub.docker.com/r/confluentinc/cp-kafka-connect
+#[derive(Debug, Clone)]
+pub struct KafkaConnect {
+    env_vars: BTreeMap<String, String>,
+    connectors: Vec<String>,
+}
+
+impl Default for KafkaConnect {
+    fn default() -> Self {
+        let mut env_vars = BTreeMap::new();
+        for (key, value) in [
+            ("CONNECT_BOOTSTRAP_SERVERS", "kafka:9093"),
+            ("CONNECT_REST_PORT", "8083"),
+            ("CONNECT_REST_ADVERTISED_HOST_NAME", "localhost"),
+            ("CONNECT_GROUP_ID", "testcontainers"),
+            ("CONNECT_CONFIG_STORAGE_TOPIC", "testcontainers-configs"),
+            ("CONNECT_OFFSET_STORAGE_TOPIC", "testcontainers-offsets"),
+            ("CONNECT_STATUS_STORAGE_TOPIC", "testcontainers-statuses"),
+            ("CONNECT_CONFIG_STORAGE_REPLICATION_FACTOR", "1"),
+            ("CONNECT_OFFSET_STORAGE_REPLICATION_FACTOR", "1"),
+            ("CONNECT_STATUS_STORAGE_REPLICATION_FACTOR", "1"),
+            (
+                "CONNECT_KEY_CONVERTER",
+                "org.apache.kafka.connect.json.JsonConverter",
+            ),
+            (
+                "CONNECT_VALUE_CONVERTER",
+                "org.apache.kafka.connect.json.JsonConverter",
+            ),
+            (
+                "CONNECT_PLUGIN_PATH",
+                "/usr/share/java,/usr/share/confluent-hub-components",
+            ),
+        ] {
+            env_vars.insert(key.to_owned(), value.to_owned());
+        }
+        Self {
+            env_vars,
+            connectors: Vec::new(),
+        }
+    }
+}
+
+impl KafkaConnect {
+    /// Sets the Kafka brokers (`CONNECT_BOOTSTRAP_SERVERS`), a comma separated list of `host:port`,
+    /// as reachable from inside of the container.
+    pub fn with_bootstrap_servers(mut self, bootstrap_servers: &str) -> Self {
+        self.env_vars.insert(
+            "CONNECT_BOOTSTRAP_SERVERS".to_owned(),
+            bootstrap_servers.to_owned(),
+        );
+        self
+    }
+
+    /// Sets the group id of the worker (`CONNECT_GROUP_ID`), defaults to `testcontainers`.
+    /// The storage topics are named after the group id, so several clusters can share a broker.
+    pub fn with_group_id(mut self, group_id: &str) -> Self {
+        self.env_vars
+            .insert("CONNECT_GROUP_ID".to_owned(), group_id.to_owned());
+        for kind in ["CONFIG", "OFFSET", "STATUS"] {
+            self.env_vars.insert(
+                format!("CONNECT_{kind}_STORAGE_TOPIC"),
+                format!("{group_id}-{}s", kind.to_lowercase()),
+            );
+        }
+        self
+    }
+
+    /// Sets the converter classes of the record keys and values
+    /// (`CONNECT_KEY_CONVERTER`, `CONNECT_VALUE_CONVERTER`), defaults to the `JsonConverter`.
+    pub fn with_converters(mut self, key_converter: &str, value_converter: &str) -> Self {
+        self.env_vars
+            .insert("CONNECT_KEY_CONVERTER".to_owned(), key_converter.to_owned());
+        self.env_vars.insert(
+            "CONNECT_VALUE_CONVERTER".to_owned(),
+            value_converter.to_owned(),
+        );
+        self
+    }
+
+    /// Sets the directories containing connector plugins (`CONNECT_PLUGIN_PATH`),
+    /// a comma separated list, defaults to `/usr/share/java,/usr/share/confluent-hub-components`.
+    /// Plugins can be copied into the container with [`testcontainers::core::ImageExt::with_copy_to`].
+    pub fn with_plugin_path(mut self, plugin_path: &str) -> Self {
+        self.env_vars
+            .insert("CONNECT_PLUGIN_PATH".to_owned(), plugin_path.to_owned());
+        self
+    }
+
+    /// Registers a connector on startup, given as the JSON accepted by `POST /connectors`,
+    /// i.e. `{"name": "...", "config": {...}}`. Can be called multiple times to add (not override) connectors.
+    pub fn with_connector(mut self, connector_json: &str) -> Self {
+        self.connectors.push(connector_json.to_owned());
+        self
+    }
+
+    /// Returns a command to register a connector through the REST API of a running container,
+    /// given as the JSON accepted by `POST /connectors`.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let connect_node = KafkaConnect::default().start().unwrap();
+    /// connect_node
+    ///     .exec(KafkaConnect::register_connector_command(
+    ///         r#"{"name": "example", "config": {"connector.class": "..."}}"#,
+    ///     ))
+    ///     .unwrap();
+    /// ```
+    pub fn register_connector_command(connector_json: &str) -> ExecCommand {
+        ExecCommand::new([
+            "curl".to_owned(),
+            "-sf".to_owned(),
+            "-X".to_owned(),
+            "POST".to_owned(),
+            "-H".to_owned(),
+            "Content-Type: application/json".to_owned(),
+            "-d".to_owned(),
+            connector_json.to_owned(),
+            format!(
+                "http://localhost:{}/connectors",
+                KAFKA_CONNECT_PORT.as_u16()
+            ),
+        ])
+        .with_cmd_ready_condition(CmdWaitFor::exit_code(0))
+    }
+}
+
+impl Image for KafkaConnect {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn tag(&self) -> &str {
+        TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![WaitFor::http(
+            HttpWaitStrategy::new("/connectors")
+                .with_port(KAFKA_CONNECT_PORT)
+                .with_expected_status_code(200_u16),
+        )]
+    }
+
+    fn env_vars(
+        &self,
+    ) -> impl IntoIterator<Item = (impl Into<Cow<'_, str>>, impl Into<Cow<'_, str>>)> {
+        &self.env_vars
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[KAFKA_CONNECT_PORT]
+    }
+
+    fn exec_after_start(
+        &self,
+        _cs: ContainerState,
+    ) -> Result<Vec<ExecCommand>, TestcontainersError> {
+        Ok(self
+            .connectors
+            .iter()
+            .map(|connector| Self::register_connector_command(connector))
+            .collect())
+    }
+}
+
+#[cfg(all(test, feature = "kafka"))]
+mod tests {
+    use super::*;
+    use crate::{
+        kafka::apache,
+        testcontainers::{runners::SyncRunner, ImageExt},
+    };
+
+    #[test]
+    fn kafka_connect_register_connector() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let network = "testcontainers-kafka-connect";
+        let kafka_alias = "testcontainers-kafka-connect-kafka";
+        let _kafka_node = apache::Kafka::default()
+            .with_internal_network_alias(kafka_alias)
+            .with_network(network)
+            .with_container_name(kafka_alias)
+            .start()?;
+        let bootstrap_servers = format!("{kafka_alias}:9093");
+        let connector = serde_json::json!({
+            "name": "heartbeat",
+            "config": {
+                "connector.class": "org.apache.kafka.connect.mirror.MirrorHeartbeatConnector",
+                "source.cluster.alias": "source",
+                "target.cluster.alias": "target",
+                "source.cluster.bootstrap.servers": bootstrap_servers,
+                "target.cluster.bootstrap.servers": bootstrap_servers,
+            }
+        });
+        let node = KafkaConnect::default()
+            .with_bootstrap_servers(&bootstrap_servers)
+            .with_group_id("test-connect")
+            .with_connector(&connector.to_string())
+            .with_network(network)
+            .start()?;
+        let url = format!(
+            "http://{}:{}",
+            node.get_host()?,
+            node.get_host_port_ipv4(KAFKA_CONNECT_PORT)?
+        );
+
+        let connectors: serde_json::Value = reqwest::blocking::get(format!("{url}/connectors"))?
+            .error_for_status()?
+            .json()?;
+        assert_eq!(connectors, serde_json::json!(["heartbeat"]));
+        Ok(())
+    }
+}
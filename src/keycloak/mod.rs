@@ -0,0 +1,190 @@
+use std::{borrow::Cow, collections::BTreeMap};
+
+use testcontainers::{
+    core::{ContainerPort, WaitFor},
+    CopyDataSource, CopyToContainer, Image,
+};
+
+const NAME: &str = "quay.io/keycloak/keycloak";
+const TAG: &str = "26.0";
+const IMPORT_DIR: &str = "/opt/keycloak/data/import";
+
+/// Port of the HTTP endpoint that the [`Keycloak`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Keycloak`]: https://www.keycloak.org/
+pub const KEYCLOAK_PORT: ContainerPort = ContainerPort::Tcp(8080);
+
+/// Module to work with [`Keycloak`] inside of tests.
+///
+/// Starts an instance of Keycloak in development mode (`start-dev`) based on the official [`Keycloak docker image`].
+///
+/// The admin console and the OpenID Connect endpoints are served on port 8080 ([`KEYCLOAK_PORT`]).
+/// The username and password of the bootstrap admin user default to `admin`,
+/// see [`Keycloak::with_admin_username`] and [`Keycloak::with_admin_password`].
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{
+///     keycloak::{Keycloak, KEYCLOAK_PORT},
+///     testcontainers::runners::SyncRunner,
+/// };
+///
+/// let keycloak_instance = Keycloak::default().start().unwrap();
+/// let url = format!(
+///     "http://{}:{}",
+///     keycloak_instance.get_host().unwrap(),
+///     keycloak_instance.get_host_port_ipv4(KEYCLOAK_PORT).unwrap()
+/// );
+/// ```
+///
+/// [`Keycloak`]: https://www.keycloak.org/
+/// [`Keycloak docker image`]: https://quay.io/repository/keycloak/keycloak
+#[derive(Debug, Clone)]
+pub struct Keycloak {
+    env_vars: BTreeMap<String, String>,
+    realm_imports: Vec<CopyToContainer>,
+}
+
+impl Default for Keycloak {
+    fn default() -> Self {
+        let mut env_vars = BTreeMap::new();
+        env_vars.insert("KC_BOOTSTRAP_ADMIN_USERNAME".to_owned(), "admin".to_owned());
+        env_vars.insert("KC_BOOTSTRAP_ADMIN_PASSWORD".to_owned(), "admin".to_owned());
+        Self {
+            env_vars,
+            realm_imports: Vec::new(),
+        }
+    }
+}
+
+impl Keycloak {
+    /// Sets the username of the bootstrap admin user (`KC_BOOTSTRAP_ADMIN_USERNAME`), defaults to `admin`.
+    pub fn with_admin_username(mut self, username: &str) -> Self {
+        self.env_vars.insert(
+            "KC_BOOTSTRAP_ADMIN_USERNAME".to_owned(),
+            username.to_owned(),
+        );
+        self
+    }
+
+    /// Sets the password of the bootstrap admin user (`KC_BOOTSTRAP_ADMIN_PASSWORD`), defaults to `admin`.
+    pub fn with_admin_password(mut self, password: &str) -> Self {
+        self.env_vars.insert(
+            "KC_BOOTSTRAP_ADMIN_PASSWORD".to_owned(),
+            password.to_owned(),
+        );
+        self
+    }
+
+    /// Registers a realm export (JSON), which is imported on startup (`--import-realm`).
+    /// Can be called multiple times to import several realms.
+    ///
+    /// # Example
+    /// ```
+    /// use testcontainers_modules::keycloak::Keycloak;
+    ///
+    /// let keycloak = Keycloak::default().with_realm_import_json(
+    ///     r#"{"realm": "test", "enabled": true}"#.to_string().into_bytes(),
+    /// );
+    /// ```
+    pub fn with_realm_import_json(mut self, realm: impl Into<CopyDataSource>) -> Self {
+        let target = format!("{IMPORT_DIR}/realm_{}.json", self.realm_imports.len());
+        self.realm_imports
+            .push(CopyToContainer::new(realm.into(), target));
+        self
+    }
+}
+
+impl Image for Keycloak {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn tag(&self) -> &str {
+        TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![WaitFor::message_on_stdout("Listening on")]
+    }
+
+    fn env_vars(
+        &self,
+    ) -> impl IntoIterator<Item = (impl Into<Cow<'_, str>>, impl Into<Cow<'_, str>>)> {
+        &self.env_vars
+    }
+
+    fn cmd(&self) -> impl IntoIterator<Item = impl Into<Cow<'_, str>>> {
+        let mut cmd = vec!["start-dev"];
+        if !self.realm_imports.is_empty() {
+            cmd.push("--import-realm");
+        }
+        cmd
+    }
+
+    fn copy_to_sources(&self) -> impl IntoIterator<Item = &CopyToContainer> {
+        &self.realm_imports
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[KEYCLOAK_PORT]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testcontainers::runners::SyncRunner;
+
+    #[test]
+    fn keycloak_admin_token() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = Keycloak::default()
+            .with_admin_username("root")
+            .with_admin_password("secret")
+            .start()?;
+        let url = format!(
+            "http://{}:{}",
+            node.get_host()?,
+            node.get_host_port_ipv4(KEYCLOAK_PORT)?
+        );
+
+        let response: serde_json::Value = reqwest::blocking::Client::new()
+            .post(format!("{url}/realms/master/protocol/openid-connect/token"))
+            .form(&[
+                ("grant_type", "password"),
+                ("client_id", "admin-cli"),
+                ("username", "root"),
+                ("password", "secret"),
+            ])
+            .send()?
+            .error_for_status()?
+            .json()?;
+        assert!(response["access_token"].is_string());
+        Ok(())
+    }
+
+    #[test]
+    fn keycloak_with_realm_import() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = Keycloak::default()
+            .with_realm_import_json(
+                r#"{"realm": "test-realm", "enabled": true}"#.to_string().into_bytes(),
+            )
+            .start()?;
+        let url = format!(
+            "http://{}:{}",
+            node.get_host()?,
+            node.get_host_port_ipv4(KEYCLOAK_PORT)?
+        );
+
+        let configuration: serde_json::Value = reqwest::blocking::get(format!(
+            "{url}/realms/test-realm/.well-known/openid-configuration"
+        ))?
+        .error_for_status()?
+        .json()?;
+        assert_eq!(configuration["issuer"], format!("{url}/realms/test-realm"));
+        Ok(())
+    }
+}
@@ -8,10 +8,38 @@
 #![doc = include_str!("../README.md")]
 //! Please have a look at the documentation of the separate modules for examples on how to use the module.
 
+#[cfg(feature = "airflow")]
+#[cfg_attr(docsrs, doc(cfg(feature = "airflow")))]
+/// **Apache Airflow** (workflow orchestration) testcontainer
+pub mod airflow;
 #[cfg(feature = "anvil")]
 #[cfg_attr(docsrs, doc(cfg(feature = "anvil")))]
 /// **Anvil** (local blockchain emulator for EVM-compatible development) testcontainer
 pub mod anvil;
+#[cfg(feature = "arangodb")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arangodb")))]
+/// **ArangoDB** (multi-model database) testcontainer
+pub mod arangodb;
+#[cfg(feature = "artemis")]
+#[cfg_attr(docsrs, doc(cfg(feature = "artemis")))]
+/// **Apache ActiveMQ Artemis** (message broker) testcontainer
+pub mod artemis;
+#[cfg(feature = "azurite")]
+#[cfg_attr(docsrs, doc(cfg(feature = "azurite")))]
+/// **Azurite** (Azure Storage emulator) testcontainer
+pub mod azurite;
+#[cfg(feature = "caddy")]
+#[cfg_attr(docsrs, doc(cfg(feature = "caddy")))]
+/// **Caddy** (web server and reverse proxy) testcontainer
+pub mod caddy;
+#[cfg(feature = "cassandra")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cassandra")))]
+/// **Apache Cassandra** (wide-column store) testcontainer
+pub mod cassandra;
+#[cfg(feature = "centrifugo")]
+#[cfg_attr(docsrs, doc(cfg(feature = "centrifugo")))]
+/// **Centrifugo** (real-time messaging server) testcontainer
+pub mod centrifugo;
 #[cfg(feature = "clickhouse")]
 #[cfg_attr(docsrs, doc(cfg(feature = "clickhouse")))]
 /// **Clickhouse** (analytics database) testcontainer
@@ -28,10 +56,30 @@ pub mod cockroach_db;
 #[cfg_attr(docsrs, doc(cfg(feature = "consul")))]
 /// **Consul** (identity-based networking) testcontainer
 pub mod consul;
+#[cfg(feature = "couchdb")]
+#[cfg_attr(docsrs, doc(cfg(feature = "couchdb")))]
+/// **CouchDB** (document database) testcontainer
+pub mod couchdb;
+#[cfg(feature = "cratedb")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cratedb")))]
+/// **CrateDB** (distributed sql database) testcontainer
+pub mod cratedb;
 #[cfg(feature = "databend")]
 #[cfg_attr(docsrs, doc(cfg(feature = "databend")))]
 /// **Databend** (analytics database) testcontainer
 pub mod databend;
+#[cfg(feature = "db2")]
+#[cfg_attr(docsrs, doc(cfg(feature = "db2")))]
+/// **IBM Db2** (relational database) testcontainer
+pub mod db2;
+#[cfg(feature = "debezium")]
+#[cfg_attr(docsrs, doc(cfg(feature = "debezium")))]
+/// **Debezium Server** (change data capture) testcontainer
+pub mod debezium;
+#[cfg(feature = "dex")]
+#[cfg_attr(docsrs, doc(cfg(feature = "dex")))]
+/// **Dex** (OpenID Connect provider) testcontainer
+pub mod dex;
 #[cfg(feature = "dynamodb")]
 #[cfg_attr(docsrs, doc(cfg(feature = "dynamodb")))]
 /// **DynamoDB** (NoSQL database) testcontainer
@@ -44,18 +92,74 @@ pub mod elastic_search;
 #[cfg_attr(docsrs, doc(cfg(feature = "elasticmq")))]
 /// **ElasticMQ** (message queue) testcontainer
 pub mod elasticmq;
+#[cfg(feature = "etcd")]
+#[cfg_attr(docsrs, doc(cfg(feature = "etcd")))]
+/// **etcd** (distributed key-value store) testcontainer
+pub mod etcd;
+#[cfg(feature = "eventstore")]
+#[cfg_attr(docsrs, doc(cfg(feature = "eventstore")))]
+/// **EventStoreDB** (event sourcing database) testcontainer
+pub mod eventstore;
+#[cfg(feature = "ferretdb")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ferretdb")))]
+/// **FerretDB** (MongoDB compatible database) testcontainer
+pub mod ferretdb;
+#[cfg(feature = "firebird")]
+#[cfg_attr(docsrs, doc(cfg(feature = "firebird")))]
+/// **Firebird** (relational database) testcontainer
+pub mod firebird;
+#[cfg(feature = "flink")]
+#[cfg_attr(docsrs, doc(cfg(feature = "flink")))]
+/// **Apache Flink** (stream processing) testcontainer
+pub mod flink;
+#[cfg(feature = "flyway")]
+#[cfg_attr(docsrs, doc(cfg(feature = "flyway")))]
+/// **Flyway** (database migrations) testcontainer
+pub mod flyway;
 #[cfg(feature = "gitea")]
 #[cfg_attr(docsrs, doc(cfg(feature = "gitea")))]
 /// **Gitea** (self-hosted Git service) testcontainer
 pub mod gitea;
+#[cfg(feature = "gitlab")]
+#[cfg_attr(docsrs, doc(cfg(feature = "gitlab")))]
+/// **GitLab CE** (DevOps platform) testcontainer
+pub mod gitlab;
 #[cfg(feature = "google_cloud_sdk_emulators")]
 #[cfg_attr(docsrs, doc(cfg(feature = "google_cloud_sdk_emulators")))]
 /// **googles cloud sdk emulator** testcontainer
 pub mod google_cloud_sdk_emulators;
+#[cfg(feature = "grafana")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grafana")))]
+/// **Grafana** (observability dashboards) testcontainer
+pub mod grafana;
+#[cfg(feature = "greenmail")]
+#[cfg_attr(docsrs, doc(cfg(feature = "greenmail")))]
+/// **GreenMail** (SMTP, POP3 and IMAP test mail server) testcontainer
+pub mod greenmail;
 #[cfg(feature = "hashicorp_vault")]
 #[cfg_attr(docsrs, doc(cfg(feature = "hashicorp_vault")))]
 /// ‎**HashiCorp Vault** (secrets management) testcontainer
 pub mod hashicorp_vault;
+#[cfg(feature = "hive_metastore")]
+#[cfg_attr(docsrs, doc(cfg(feature = "hive_metastore")))]
+/// **Apache Hive Metastore** (table metadata catalog) testcontainer
+pub mod hive_metastore;
+#[cfg(feature = "http_echo")]
+#[cfg_attr(docsrs, doc(cfg(feature = "http_echo")))]
+/// **httpbin** (HTTP request and response service) testcontainer
+pub mod http_echo;
+#[cfg(feature = "ibm_mq")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ibm_mq")))]
+/// **IBM MQ** (message broker) testcontainer
+pub mod ibm_mq;
+#[cfg(feature = "influxdb")]
+#[cfg_attr(docsrs, doc(cfg(feature = "influxdb")))]
+/// **InfluxDB** (time series database) testcontainer
+pub mod influxdb;
+#[cfg(feature = "jaeger")]
+#[cfg_attr(docsrs, doc(cfg(feature = "jaeger")))]
+/// **Jaeger** (distributed tracing) testcontainer
+pub mod jaeger;
 #[cfg(feature = "k3s")]
 #[cfg_attr(docsrs, doc(cfg(feature = "k3s")))]
 /// **K3s** (lightweight kubernetes) testcontainer
@@ -64,6 +168,14 @@ pub mod k3s;
 #[cfg_attr(docsrs, doc(cfg(feature = "kafka")))]
 /// **Apache Kafka** (data streaming) testcontainer
 pub mod kafka;
+#[cfg(feature = "kafka_connect")]
+#[cfg_attr(docsrs, doc(cfg(feature = "kafka_connect")))]
+/// **Kafka Connect** (Kafka integration framework) testcontainer
+pub mod kafka_connect;
+#[cfg(feature = "keycloak")]
+#[cfg_attr(docsrs, doc(cfg(feature = "keycloak")))]
+/// **Keycloak** (identity and access management) testcontainer
+pub mod keycloak;
 #[cfg(feature = "kwok")]
 #[cfg_attr(docsrs, doc(cfg(feature = "kwok")))]
 /// **KWOK Cluster** (Kubernetes WithOut Kubelet) testcontainer
@@ -72,6 +184,10 @@ pub mod kwok;
 #[cfg_attr(docsrs, doc(cfg(feature = "localstack")))]
 /// **LocalStack** (local AWS emulation) testcontainer
 pub mod localstack;
+#[cfg(feature = "mailpit")]
+#[cfg_attr(docsrs, doc(cfg(feature = "mailpit")))]
+/// **Mailpit** (email testing) testcontainer
+pub mod mailpit;
 #[cfg(feature = "mariadb")]
 #[cfg_attr(docsrs, doc(cfg(feature = "mariadb")))]
 /// **MariaDB** (relational database) testcontainer
@@ -80,6 +196,14 @@ pub mod mariadb;
 #[cfg_attr(docsrs, doc(cfg(feature = "meilisearch")))]
 /// **Meilisearch** (search engine) testcontainer
 pub mod meilisearch;
+#[cfg(feature = "memgraph")]
+#[cfg_attr(docsrs, doc(cfg(feature = "memgraph")))]
+/// **Memgraph** (graph database) testcontainer
+pub mod memgraph;
+#[cfg(feature = "milvus")]
+#[cfg_attr(docsrs, doc(cfg(feature = "milvus")))]
+/// **Milvus** (vector database) testcontainer
+pub mod milvus;
 #[cfg(feature = "minio")]
 #[cfg_attr(docsrs, doc(cfg(feature = "minio")))]
 /// **minio** (object storage) testcontainer
@@ -108,10 +232,22 @@ pub mod nats;
 #[cfg_attr(docsrs, doc(cfg(feature = "neo4j")))]
 /// **Neo4j** (graph database) testcontainer
 pub mod neo4j;
+#[cfg(feature = "nessie")]
+#[cfg_attr(docsrs, doc(cfg(feature = "nessie")))]
+/// **Nessie** (Iceberg REST catalog) testcontainer
+pub mod nessie;
+#[cfg(feature = "nginx")]
+#[cfg_attr(docsrs, doc(cfg(feature = "nginx")))]
+/// **Nginx** (web server and reverse proxy) testcontainer
+pub mod nginx;
 #[cfg(feature = "openldap")]
 #[cfg_attr(docsrs, doc(cfg(feature = "openldap")))]
 /// **Openldap** (ldap authentification) testcontainer
 pub mod openldap;
+#[cfg(feature = "opensearch")]
+#[cfg_attr(docsrs, doc(cfg(feature = "opensearch")))]
+/// **OpenSearch** (search engine) testcontainer
+pub mod opensearch;
 #[cfg(feature = "oracle")]
 #[cfg_attr(docsrs, doc(cfg(feature = "oracle")))]
 /// **oracle** (relational database) testcontainer
@@ -120,21 +256,46 @@ pub mod oracle;
 #[cfg_attr(docsrs, doc(cfg(feature = "orientdb")))]
 /// **orientdb** (nosql database) testcontainer
 pub mod orientdb;
+#[cfg(feature = "otel_collector")]
+#[cfg_attr(docsrs, doc(cfg(feature = "otel_collector")))]
+/// **OpenTelemetry Collector** (telemetry pipeline) testcontainer
+pub mod otel_collector;
 #[cfg(feature = "parity")]
 #[cfg_attr(docsrs, doc(cfg(feature = "parity")))]
 /// **parity_parity** (etherium client) testcontainer
 pub mod parity_parity;
+#[cfg(feature = "percona")]
+#[cfg_attr(docsrs, doc(cfg(feature = "percona")))]
+/// **Percona Server** (relational database) testcontainer
+pub mod percona;
+#[cfg(feature = "pgvector")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pgvector")))]
+/// **pgvector** (vector similarity search extension for postgres) testcontainer
+pub mod pgvector;
 #[cfg(feature = "postgis")]
 #[cfg_attr(docsrs, doc(cfg(feature = "postgis")))]
+/// **PostGIS** (geospatial extension for postgres) testcontainer
 pub mod postgis;
 #[cfg(feature = "postgres")]
 #[cfg_attr(docsrs, doc(cfg(feature = "postgres")))]
 /// **Postgres** (relational database) testcontainer
 pub mod postgres;
+#[cfg(feature = "prometheus")]
+#[cfg_attr(docsrs, doc(cfg(feature = "prometheus")))]
+/// **Prometheus** (monitoring system) testcontainer
+pub mod prometheus;
 #[cfg(feature = "pulsar")]
 #[cfg_attr(docsrs, doc(cfg(feature = "pulsar")))]
 /// **Apache Pulsar** (Cloud-Native, Distributed Messaging and Streaming) testcontainer
 pub mod pulsar;
+#[cfg(feature = "qdrant")]
+#[cfg_attr(docsrs, doc(cfg(feature = "qdrant")))]
+/// **Qdrant** (vector database) testcontainer
+pub mod qdrant;
+#[cfg(feature = "questdb")]
+#[cfg_attr(docsrs, doc(cfg(feature = "questdb")))]
+/// **QuestDB** (time series database) testcontainer
+pub mod questdb;
 #[cfg(feature = "rabbitmq")]
 #[cfg_attr(docsrs, doc(cfg(feature = "rabbitmq")))]
 /// **rabbitmq** (message broker) testcontainer
@@ -143,34 +304,131 @@ pub mod rabbitmq;
 #[cfg_attr(docsrs, doc(cfg(feature = "redis")))]
 /// **redis** (in memory nosql database) testcontainer
 pub mod redis;
+#[cfg(feature = "redpanda")]
+#[cfg_attr(docsrs, doc(cfg(feature = "redpanda")))]
+/// **Redpanda** (kafka compatible streaming platform) testcontainer
+pub mod redpanda;
+#[cfg(feature = "rethinkdb")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rethinkdb")))]
+/// **RethinkDB** (document database) testcontainer
+pub mod rethinkdb;
 #[cfg(feature = "rqlite")]
 #[cfg_attr(docsrs, doc(cfg(feature = "rqlite")))]
 /// **RQLite** (lightweight, user-friendly, distributed relational database) testcontainer
 pub mod rqlite;
+#[cfg(feature = "samba_ad")]
+#[cfg_attr(docsrs, doc(cfg(feature = "samba_ad")))]
+/// **Samba AD** (Active Directory domain controller) testcontainer
+pub mod samba_ad;
+#[cfg(feature = "schema_registry")]
+#[cfg_attr(docsrs, doc(cfg(feature = "schema_registry")))]
+/// **Schema Registry** (Kafka schema registry) testcontainer
+pub mod schema_registry;
+#[cfg(feature = "scylladb")]
+#[cfg_attr(docsrs, doc(cfg(feature = "scylladb")))]
+/// **ScyllaDB** (wide-column store) testcontainer
+pub mod scylladb;
+#[cfg(feature = "sftp")]
+#[cfg_attr(docsrs, doc(cfg(feature = "sftp")))]
+/// **SFTP and FTP** (file transfer servers) testcontainer
+pub mod sftp;
+#[cfg(feature = "solace")]
+#[cfg_attr(docsrs, doc(cfg(feature = "solace")))]
+/// **Solace PubSub+** (event broker) testcontainer
+pub mod solace;
 #[cfg(feature = "solr")]
 #[cfg_attr(docsrs, doc(cfg(feature = "solr")))]
 /// **Apache Solr** (distributed search engine) testcontainer
 pub mod solr;
+#[cfg(feature = "spark")]
+#[cfg_attr(docsrs, doc(cfg(feature = "spark")))]
+/// **Apache Spark** (cluster computing) testcontainer
+pub mod spark;
 #[cfg(feature = "surrealdb")]
 #[cfg_attr(docsrs, doc(cfg(feature = "surrealdb")))]
 /// **surrealdb** (mutli model database) testcontainer
 pub mod surrealdb;
+#[cfg(feature = "temporal")]
+#[cfg_attr(docsrs, doc(cfg(feature = "temporal")))]
+/// **Temporal** (durable workflow engine) testcontainer
+pub mod temporal;
+#[cfg(feature = "tidb")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tidb")))]
+/// **TiDB** (distributed MySQL-compatible database) testcontainer
+pub mod tidb;
+#[cfg(feature = "timescale")]
+#[cfg_attr(docsrs, doc(cfg(feature = "timescale")))]
+/// **TimescaleDB** (time-series extension for postgres) testcontainer
+pub mod timescale;
+#[cfg(feature = "tls")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tls")))]
+/// **TLS** (self-signed certificates for modules) utilities
+pub mod tls;
+#[cfg(feature = "toxiproxy")]
+#[cfg_attr(docsrs, doc(cfg(feature = "toxiproxy")))]
+/// **Toxiproxy** (network fault injection proxy) testcontainer
+pub mod toxiproxy;
+#[cfg(feature = "traefik")]
+#[cfg_attr(docsrs, doc(cfg(feature = "traefik")))]
+/// **Traefik** (edge router and reverse proxy) testcontainer
+pub mod traefik;
+#[cfg(feature = "trino")]
+#[cfg_attr(docsrs, doc(cfg(feature = "trino")))]
+/// **Trino** (distributed SQL query engine) testcontainer
+pub mod trino;
 #[cfg(feature = "trufflesuite_ganachecli")]
 #[cfg_attr(docsrs, doc(cfg(feature = "trufflesuite_ganachecli")))]
 /// **Trufflesuite Ganache CLI** (ethereum simulator) testcontainer
 pub mod trufflesuite_ganachecli;
+#[cfg(feature = "typesense")]
+#[cfg_attr(docsrs, doc(cfg(feature = "typesense")))]
+/// **Typesense** (search engine) testcontainer
+pub mod typesense;
 #[cfg(feature = "valkey")]
 #[cfg_attr(docsrs, doc(cfg(feature = "valkey")))]
 /// **Valkey** (in memory nosql database) testcontainer
 pub mod valkey;
+#[cfg(feature = "verdaccio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "verdaccio")))]
+/// **Verdaccio** (npm registry) testcontainer
+pub mod verdaccio;
 #[cfg(feature = "victoria_metrics")]
 #[cfg_attr(docsrs, doc(cfg(feature = "victoria_metrics")))]
 /// **VictoriaMetrics** (monitoring and time series metrics database) testcontainer
 pub mod victoria_metrics;
+#[cfg(feature = "weaviate")]
+#[cfg_attr(docsrs, doc(cfg(feature = "weaviate")))]
+/// **Weaviate** (vector database) testcontainer
+pub mod weaviate;
+#[cfg(feature = "webdav")]
+#[cfg_attr(docsrs, doc(cfg(feature = "webdav")))]
+/// **WebDAV** (HTTP file server) testcontainer
+pub mod webdav;
+#[cfg(feature = "wiremock")]
+#[cfg_attr(docsrs, doc(cfg(feature = "wiremock")))]
+/// **WireMock** (HTTP mock server) testcontainer
+pub mod wiremock;
+#[cfg(feature = "yugabytedb")]
+#[cfg_attr(docsrs, doc(cfg(feature = "yugabytedb")))]
+/// **YugabyteDB** (distributed sql database) testcontainer
+pub mod yugabytedb;
+#[cfg(feature = "zeebe")]
+#[cfg_attr(docsrs, doc(cfg(feature = "zeebe")))]
+/// **Zeebe** (Camunda 8 workflow engine) testcontainer
+pub mod zeebe;
+#[cfg(feature = "zipkin")]
+#[cfg_attr(docsrs, doc(cfg(feature = "zipkin")))]
+/// **Zipkin** (distributed tracing) testcontainer
+pub mod zipkin;
 #[cfg(feature = "zookeeper")]
 #[cfg_attr(docsrs, doc(cfg(feature = "zookeeper")))]
 /// **Apache ZooKeeper** (locking and configuratin management) testcontainer
 pub mod zookeeper;
 
+/// Override of the image registry, available for all modules
+pub mod registry;
+/// Composition of several modules on a shared network, available for all modules
+pub mod scenarios;
+
 /// Re-exported version of `testcontainers` to avoid version conflicts
 pub use testcontainers;
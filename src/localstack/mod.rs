@@ -1,39 +1,134 @@
+use std::{borrow::Cow, collections::BTreeMap};
+
 pub use pro::LocalStackPro;
-use testcontainers::{core::WaitFor, Image};
+use testcontainers::{
+    core::{wait::HttpWaitStrategy, ContainerPort, WaitFor},
+    Image,
+};
 
 /// LocalStack Pro
 pub mod pro;
 
 const NAME: &str = "localstack/localstack";
 const TAG: &str = "3.0";
-const DEFAULT_WAIT: u64 = 3000;
+
+/// Port of the edge service that the [`LocalStack`] container has internally,
+/// serving the APIs of all AWS services.
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`LocalStack`]: https://www.localstack.cloud/
+pub const LOCALSTACK_PORT: ContainerPort = ContainerPort::Tcp(4566);
+
+/// AWS service emulated by [`LocalStack`], see [`LocalStack::with_services`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(missing_docs)]
+pub enum Service {
+    ApiGateway,
+    CloudFormation,
+    CloudWatch,
+    DynamoDb,
+    Events,
+    Firehose,
+    Iam,
+    Kinesis,
+    Kms,
+    Lambda,
+    Logs,
+    S3,
+    SecretsManager,
+    Ses,
+    Sns,
+    Sqs,
+    Ssm,
+    StepFunctions,
+    Sts,
+}
+
+impl Service {
+    /// Name of the service as used by LocalStack, e.g. in the `SERVICES` environment variable.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Service::ApiGateway => "apigateway",
+            Service::CloudFormation => "cloudformation",
+            Service::CloudWatch => "cloudwatch",
+            Service::DynamoDb => "dynamodb",
+            Service::Events => "events",
+            Service::Firehose => "firehose",
+            Service::Iam => "iam",
+            Service::Kinesis => "kinesis",
+            Service::Kms => "kms",
+            Service::Lambda => "lambda",
+            Service::Logs => "logs",
+            Service::S3 => "s3",
+            Service::SecretsManager => "secretsmanager",
+            Service::Ses => "ses",
+            Service::Sns => "sns",
+            Service::Sqs => "sqs",
+            Service::Ssm => "ssm",
+            Service::StepFunctions => "stepfunctions",
+            Service::Sts => "sts",
+        }
+    }
+}
 
 /// This module provides [LocalStack](https://www.localstack.cloud/) (Community Edition).
 ///
 /// Currently pinned to [version `3.0`](https://hub.docker.com/layers/localstack/localstack/3.0/images/sha256-73698e485240939490134aadd7e429ac87ff068cd5ad09f5de8ccb76727c13e1?context=explore)
 ///
+/// All AWS APIs are served on port 4566 ([`LOCALSTACK_PORT`]).
+/// The container is considered ready, once the [health endpoint](https://docs.localstack.cloud/references/internal-endpoints/)
+/// reports all services given to [`LocalStack::with_services`] as available.
+///
 /// # Configuration
 ///
 /// For configuration, LocalStack uses environment variables. You can go [here](https://docs.localstack.cloud/references/configuration/)
 /// for the full list.
 ///
-/// Testcontainers support setting environment variables with the method
-/// `RunnableImage::with_env_var((impl Into<String>, impl Into<String>))`. You will have to convert
-/// the Image into a RunnableImage first.
+/// The most common ones can be set with the builder methods, any other variable can be set with
+/// [`testcontainers::ImageExt::with_env_var`].
 ///
 /// ```
-/// use testcontainers_modules::{localstack::LocalStack, testcontainers::ImageExt};
+/// use testcontainers_modules::{
+///     localstack::{LocalStack, Service},
+///     testcontainers::ImageExt,
+/// };
 ///
-/// let container_request = LocalStack::default().with_env_var("SERVICES", "s3");
+/// let container_request = LocalStack::default()
+///     .with_services(&[Service::S3, Service::Sqs])
+///     .with_env_var("DEBUG", "1");
 /// ```
 ///
 /// No environment variables are required.
 #[derive(Default, Debug, Clone)]
 pub struct LocalStack {
-    /// (remove if there is another variable)
-    /// Field is included to prevent this struct to be a unit struct.
-    /// This allows extending functionality (and thus further variables) without breaking changes
-    _priv: (),
+    env_vars: BTreeMap<String, String>,
+    services: Vec<Service>,
+}
+
+impl LocalStack {
+    /// Restricts the emulated services to the given ones (`SERVICES`), which are started eagerly.
+    /// The container is considered ready once all of them are available.
+    pub fn with_services(mut self, services: &[Service]) -> Self {
+        self.services = services.to_vec();
+        let services = self
+            .services
+            .iter()
+            .map(Service::as_str)
+            .collect::<Vec<_>>()
+            .join(",");
+        self.env_vars.insert("SERVICES".to_owned(), services);
+        self.env_vars
+            .insert("EAGER_SERVICE_LOADING".to_owned(), "1".to_owned());
+        self
+    }
+
+    /// Sets the default region (`AWS_DEFAULT_REGION`) used inside of the container, e.g. by init hooks.
+    /// Clients still have to be configured with the same region.
+    pub fn with_region(mut self, region: &str) -> Self {
+        self.env_vars
+            .insert("AWS_DEFAULT_REGION".to_owned(), region.to_owned());
+        self
+    }
 }
 
 impl Image for LocalStack {
@@ -46,10 +141,37 @@ impl Image for LocalStack {
     }
 
     fn ready_conditions(&self) -> Vec<WaitFor> {
-        vec![
-            WaitFor::message_on_stdout("Ready."),
-            WaitFor::millis(DEFAULT_WAIT),
-        ]
+        let services: Vec<&'static str> = self.services.iter().map(Service::as_str).collect();
+        vec![WaitFor::http(
+            HttpWaitStrategy::new("/_localstack/health")
+                .with_port(LOCALSTACK_PORT)
+                .with_response_matcher_async(move |response| {
+                    let services = services.clone();
+                    async move {
+                        if !response.status().is_success() {
+                            return false;
+                        }
+                        let Ok(body) = response.text().await else {
+                            return false;
+                        };
+                        let body: String = body.chars().filter(|c| !c.is_whitespace()).collect();
+                        services.iter().all(|service| {
+                            body.contains(&format!("\"{service}\":\"available\""))
+                                || body.contains(&format!("\"{service}\":\"running\""))
+                        })
+                    }
+                }),
+        )]
+    }
+
+    fn env_vars(
+        &self,
+    ) -> impl IntoIterator<Item = (impl Into<Cow<'_, str>>, impl Into<Cow<'_, str>>)> {
+        &self.env_vars
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[LOCALSTACK_PORT]
     }
 }
 
@@ -59,7 +181,7 @@ mod tests {
     use aws_sdk_sqs as sqs;
     use testcontainers::runners::AsyncRunner;
 
-    use super::LocalStack;
+    use super::{LocalStack, Service, LOCALSTACK_PORT};
 
     #[tokio::test]
     #[allow(clippy::result_large_err)]
@@ -89,4 +211,36 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    #[allow(clippy::result_large_err)]
+    async fn with_services_and_region() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let node = LocalStack::default()
+            .with_services(&[Service::Sqs])
+            .with_region("eu-central-1")
+            .start()
+            .await?;
+        let host_ip = node.get_host().await?;
+        let host_port = node.get_host_port_ipv4(LOCALSTACK_PORT).await?;
+
+        let creds = sqs::config::Credentials::new("fake", "fake", None, None, "test");
+        let config = aws_config::defaults(BehaviorVersion::v2024_03_28())
+            .region("eu-central-1")
+            .credentials_provider(creds)
+            .endpoint_url(format!("http://{host_ip}:{host_port}"))
+            .load()
+            .await;
+        let client = sqs::Client::new(&config);
+
+        let queue_url = client
+            .create_queue()
+            .queue_name("example-queue")
+            .send()
+            .await?
+            .queue_url
+            .unwrap();
+        assert!(queue_url.contains("eu-central-1"));
+
+        Ok(())
+    }
 }
@@ -0,0 +1,236 @@
+use std::{borrow::Cow, collections::BTreeMap, fmt::Display};
+
+use testcontainers::{
+    core::{wait::HttpWaitStrategy, ContainerPort, WaitFor},
+    Image,
+};
+
+const NAME: &str = "axllent/mailpit";
+const TAG: &str = "v1.21";
+
+/// Port of the SMTP server that the [`Mailpit`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Mailpit`]: https://mailpit.axllent.org/
+pub const MAILPIT_SMTP_PORT: ContainerPort = ContainerPort::Tcp(1025);
+
+/// Port of the HTTP API and web UI that the [`Mailpit`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Mailpit`]: https://mailpit.axllent.org/
+pub const MAILPIT_HTTP_PORT: ContainerPort = ContainerPort::Tcp(8025);
+
+/// Module to work with [`Mailpit`] inside of tests.
+///
+/// Starts an instance of Mailpit, an email testing tool, based on the official [`Mailpit docker image`].
+///
+/// All emails sent to the SMTP server on port 1025 ([`MAILPIT_SMTP_PORT`]) are captured and can be inspected
+/// via the [HTTP API](https://mailpit.axllent.org/docs/api-v1/) on port 8025 ([`MAILPIT_HTTP_PORT`]).
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{
+///     mailpit::{Mailpit, MAILPIT_HTTP_PORT, MAILPIT_SMTP_PORT},
+///     testcontainers::runners::SyncRunner,
+/// };
+///
+/// let mailpit_instance = Mailpit::default().start().unwrap();
+/// let host = mailpit_instance.get_host().unwrap();
+/// let smtp_port = mailpit_instance
+///     .get_host_port_ipv4(MAILPIT_SMTP_PORT)
+///     .unwrap();
+///
+/// // send emails to `host:smtp_port` ...
+///
+/// let messages_url = Mailpit::messages_url(
+///     &host,
+///     mailpit_instance
+///         .get_host_port_ipv4(MAILPIT_HTTP_PORT)
+///         .unwrap(),
+/// );
+/// ```
+///
+/// [`Mailpit`]: https://mailpit.axllent.org/
+/// [`Mailpit docker image`]: https://hub.docker.com/r/axllent/mailpit
+#[derive(Debug, Clone, Default)]
+pub struct Mailpit {
+    env_vars: BTreeMap<String, String>,
+}
+
+impl Mailpit {
+    /// Requires SMTP authentication with the given credentials (`MP_SMTP_AUTH`).
+    /// Authentication without TLS is allowed (`MP_SMTP_AUTH_ALLOW_INSECURE`).
+    pub fn with_smtp_auth(mut self, username: &str, password: &str) -> Self {
+        self.env_vars
+            .insert("MP_SMTP_AUTH".to_owned(), format!("{username}:{password}"));
+        self.env_vars
+            .insert("MP_SMTP_AUTH_ALLOW_INSECURE".to_owned(), "true".to_owned());
+        self
+    }
+
+    /// Sets the maximum number of stored messages (`MP_MAX_MESSAGES`),
+    /// older messages are deleted once it is exceeded. Defaults to 500, `0` disables the limit.
+    pub fn with_max_messages(mut self, max_messages: u32) -> Self {
+        self.env_vars
+            .insert("MP_MAX_MESSAGES".to_owned(), max_messages.to_string());
+        self
+    }
+
+    /// Returns the url of the HTTP API listing the captured messages (`GET /api/v1/messages`),
+    /// for the given host and (host-mapped) HTTP port ([`MAILPIT_HTTP_PORT`]).
+    ///
+    /// # Example
+    /// ```
+    /// use testcontainers_modules::mailpit::Mailpit;
+    ///
+    /// assert_eq!(
+    ///     Mailpit::messages_url("localhost", 8025),
+    ///     "http://localhost:8025/api/v1/messages"
+    /// );
+    /// ```
+    pub fn messages_url(host: impl Display, port: u16) -> String {
+        format!("http://{host}:{port}/api/v1/messages")
+    }
+
+    /// Returns the url of the HTTP API returning a single captured message (`GET /api/v1/message/<id>`),
+    /// the id `latest` refers to the most recent message. See [`Mailpit::messages_url`].
+    pub fn message_url(host: impl Display, port: u16, id: &str) -> String {
+        format!("http://{host}:{port}/api/v1/message/{id}")
+    }
+}
+
+impl Image for Mailpit {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn tag(&self) -> &str {
+        TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![WaitFor::http(
+            HttpWaitStrategy::new("/readyz")
+                .with_port(MAILPIT_HTTP_PORT)
+                .with_expected_status_code(200_u16),
+        )]
+    }
+
+    fn env_vars(
+        &self,
+    ) -> impl IntoIterator<Item = (impl Into<Cow<'_, str>>, impl Into<Cow<'_, str>>)> {
+        &self.env_vars
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[MAILPIT_SMTP_PORT, MAILPIT_HTTP_PORT]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{self, BufRead, BufReader, Write},
+        net::TcpStream,
+    };
+
+    use super::*;
+    use crate::testcontainers::runners::SyncRunner;
+
+    /// `AUTH PLAIN` credentials of `user` with password `secret`
+    const PLAIN_CREDENTIALS: &str = "AHVzZXIAc2VjcmV0";
+
+    fn send_mail(host: &str, port: u16, subject: &str, auth: Option<&str>) -> io::Result<()> {
+        let stream = TcpStream::connect((host, port))?;
+        let mut reader = BufReader::new(stream.try_clone()?);
+        let mut writer = stream;
+        let mut line = String::new();
+        let mut expect = |reader: &mut BufReader<TcpStream>, code: &str| -> io::Result<()> {
+            // skip the continuation lines of multiline replies, e.g. to `EHLO`
+            loop {
+                line.clear();
+                reader.read_line(&mut line)?;
+                if !line.starts_with(&format!("{code}-")) {
+                    break;
+                }
+            }
+            if line.starts_with(code) {
+                Ok(())
+            } else {
+                Err(io::Error::other(format!("unexpected smtp reply: {line}")))
+            }
+        };
+
+        expect(&mut reader, "220")?;
+        let mut commands = vec![("EHLO localhost\r\n".to_owned(), "250")];
+        if let Some(credentials) = auth {
+            commands.push((format!("AUTH PLAIN {credentials}\r\n"), "235"));
+        }
+        commands.extend([
+            ("MAIL FROM:<sender@example.com>\r\n".to_owned(), "250"),
+            ("RCPT TO:<receiver@example.com>\r\n".to_owned(), "250"),
+            ("DATA\r\n".to_owned(), "354"),
+            (
+                format!("Subject: {subject}\r\n\r\nHello from testcontainers\r\n.\r\n"),
+                "250",
+            ),
+            ("QUIT\r\n".to_owned(), "221"),
+        ]);
+        for (command, code) in commands {
+            writer.write_all(command.as_bytes())?;
+            expect(&mut reader, code)?;
+        }
+        Ok(())
+    }
+
+    fn get_json(url: String) -> Result<serde_json::Value, reqwest::Error> {
+        reqwest::blocking::get(url)?.error_for_status()?.json()
+    }
+
+    #[test]
+    fn mailpit_capture_message() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = Mailpit::default().with_max_messages(10).start()?;
+        let host = node.get_host()?.to_string();
+        let http_port = node.get_host_port_ipv4(MAILPIT_HTTP_PORT)?;
+
+        send_mail(
+            &host,
+            node.get_host_port_ipv4(MAILPIT_SMTP_PORT)?,
+            "testcontainers",
+            None,
+        )?;
+
+        let messages = get_json(Mailpit::messages_url(&host, http_port))?;
+        assert_eq!(messages["total"], 1);
+        assert_eq!(messages["messages"][0]["Subject"], "testcontainers");
+
+        let message = get_json(Mailpit::message_url(&host, http_port, "latest"))?;
+        assert!(message["Text"]
+            .as_str()
+            .unwrap()
+            .contains("Hello from testcontainers"));
+        Ok(())
+    }
+
+    #[test]
+    fn mailpit_with_smtp_auth() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = Mailpit::default()
+            .with_smtp_auth("user", "secret")
+            .start()?;
+        let host = node.get_host()?.to_string();
+        let smtp_port = node.get_host_port_ipv4(MAILPIT_SMTP_PORT)?;
+
+        assert!(send_mail(&host, smtp_port, "anonymous", None).is_err());
+        send_mail(&host, smtp_port, "authenticated", Some(PLAIN_CREDENTIALS))?;
+
+        let messages = get_json(Mailpit::messages_url(
+            &host,
+            node.get_host_port_ipv4(MAILPIT_HTTP_PORT)?,
+        ))?;
+        assert_eq!(messages["total"], 1);
+        assert_eq!(messages["messages"][0]["Subject"], "authenticated");
+        Ok(())
+    }
+}
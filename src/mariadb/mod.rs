@@ -1,9 +1,10 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, collections::BTreeMap};
 
 use testcontainers::{core::WaitFor, CopyDataSource, CopyToContainer, Image};
 
 const NAME: &str = "mariadb";
 const TAG: &str = "11.3";
+const CONFIG_FILE_PATH: &str = "/etc/mysql/conf.d/testcontainers.cnf";
 
 /// Module to work with [`MariaDB`] inside of tests.
 ///
@@ -25,9 +26,27 @@ const TAG: &str = "11.3";
 ///
 /// [`MariaDB`]: https://www.mariadb.com/
 /// [`MariaDB docker image`]: https://hub.docker.com/_/mariadb
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Clone)]
 pub struct Mariadb {
+    env_vars: BTreeMap<String, String>,
     copy_to_sources: Vec<CopyToContainer>,
+    config_file: Option<CopyToContainer>,
+}
+
+impl Default for Mariadb {
+    fn default() -> Self {
+        let mut env_vars = BTreeMap::new();
+        env_vars.insert("MARIADB_DATABASE".to_owned(), "test".to_owned());
+        env_vars.insert(
+            "MARIADB_ALLOW_EMPTY_ROOT_PASSWORD".to_owned(),
+            "1".to_owned(),
+        );
+        Self {
+            env_vars,
+            copy_to_sources: Vec::new(),
+            config_file: None,
+        }
+    }
 }
 
 impl Mariadb {
@@ -59,6 +78,40 @@ impl Mariadb {
             .push(CopyToContainer::new(init_sql.into(), target));
         self
     }
+
+    /// Sets the password of the root user (`MARIADB_ROOT_PASSWORD`), instead of allowing an empty password.
+    pub fn with_root_password(mut self, password: &str) -> Self {
+        self.env_vars.remove("MARIADB_ALLOW_EMPTY_ROOT_PASSWORD");
+        self.env_vars
+            .insert("MARIADB_ROOT_PASSWORD".to_owned(), password.to_owned());
+        self
+    }
+
+    /// Sets the name of the database created on startup (`MARIADB_DATABASE`), defaults to `test`.
+    pub fn with_database(mut self, database: &str) -> Self {
+        self.env_vars
+            .insert("MARIADB_DATABASE".to_owned(), database.to_owned());
+        self
+    }
+
+    /// Registers a custom `my.cnf` to be used by the MariaDB instance.
+    ///
+    /// The file is copied to `/etc/mysql/conf.d/`, thus its options take precedence over the defaults of the image.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use testcontainers_modules::mariadb::Mariadb;
+    /// let mariadb_image = Mariadb::default().with_config_file(
+    ///     "[mysqld]\nmax_connections = 42\n"
+    ///         .to_string()
+    ///         .into_bytes(),
+    /// );
+    /// ```
+    pub fn with_config_file(mut self, config_file: impl Into<CopyDataSource>) -> Self {
+        self.config_file = Some(CopyToContainer::new(config_file.into(), CONFIG_FILE_PATH));
+        self
+    }
 }
 
 impl Image for Mariadb {
@@ -80,13 +133,10 @@ impl Image for Mariadb {
     fn env_vars(
         &self,
     ) -> impl IntoIterator<Item = (impl Into<Cow<'_, str>>, impl Into<Cow<'_, str>>)> {
-        [
-            ("MARIADB_DATABASE", "test"),
-            ("MARIADB_ALLOW_EMPTY_ROOT_PASSWORD", "1"),
-        ]
+        &self.env_vars
     }
     fn copy_to_sources(&self) -> impl IntoIterator<Item = &CopyToContainer> {
-        &self.copy_to_sources
+        self.copy_to_sources.iter().chain(&self.config_file)
     }
 }
 
@@ -165,4 +215,25 @@ mod tests {
         );
         Ok(())
     }
+
+    #[test]
+    fn mariadb_with_root_password_database_and_config_file(
+    ) -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let node = MariadbImage::default()
+            .with_root_password("secret")
+            .with_database("custom_db")
+            .with_config_file("[mysqld]\nmax_connections = 42\n".to_string().into_bytes())
+            .start()?;
+
+        let connection_string = &format!(
+            "mysql://root:secret@{}:{}/custom_db",
+            node.get_host()?,
+            node.get_host_port_ipv4(3306)?
+        );
+        let mut conn = mysql::Conn::new(mysql::Opts::from_url(connection_string).unwrap()).unwrap();
+
+        let max_connections: Option<u32> = conn.query_first("SELECT @@max_connections").unwrap();
+        assert_eq!(max_connections, Some(42));
+        Ok(())
+    }
 }
@@ -28,7 +28,9 @@ pub const MEILISEARCH_PORT: ContainerPort = ContainerPort::Tcp(7700);
 /// let dashboard = format!(
 ///     "http://{}:{}",
 ///     meilisearch_instance.get_host().unwrap(),
-///     meilisearch_instance.get_host_port_ipv4(7700).unwrap()
+///     meilisearch_instance
+///         .get_host_port_ipv4(meilisearch::MEILISEARCH_PORT)
+///         .unwrap()
 /// );
 /// ```
 ///
@@ -151,6 +153,7 @@ impl Image for Meilisearch {
         // => using the `/health` endpoint is the best strategy
         vec![WaitFor::http(
             HttpWaitStrategy::new("/health")
+                .with_port(MEILISEARCH_PORT)
                 .with_expected_status_code(200_u16)
                 .with_body(r#"{ "status": "available" }"#.as_bytes()),
         )]
@@ -231,7 +234,7 @@ mod tests {
         let connection_string = &format!(
             "http://{}:{}",
             node.get_host().await?,
-            node.get_host_port_ipv4(7700).await?,
+            node.get_host_port_ipv4(MEILISEARCH_PORT).await?,
         );
         let auth: Option<String> = None; // not currently possible to type-infer String or that it is not nessesary
         let client = Client::new(connection_string, auth).unwrap();
@@ -276,7 +279,7 @@ mod tests {
         let connection_string = &format!(
             "http://{}:{}",
             node.get_host().await?,
-            node.get_host_port_ipv4(7700).await?,
+            node.get_host_port_ipv4(MEILISEARCH_PORT).await?,
         );
         let client = Client::new(connection_string, Some(master_key)).unwrap();
 
@@ -309,7 +312,7 @@ mod tests {
         let connection_string = &format!(
             "http://{}:{}",
             node.get_host().await?,
-            node.get_host_port_ipv4(7700).await?,
+            node.get_host_port_ipv4(MEILISEARCH_PORT).await?,
         );
         let client = Client::new(connection_string, Some(master_key)).unwrap();
 
@@ -0,0 +1,188 @@
+use std::{borrow::Cow, collections::BTreeMap};
+
+use testcontainers::{
+    core::{CmdWaitFor, ContainerPort, ContainerState, ExecCommand, WaitFor},
+    CopyDataSource, CopyToContainer, Image, TestcontainersError,
+};
+
+const NAME: &str = "memgraph/memgraph";
+const TAG: &str = "2.21.0";
+const INIT_FILE_PATH: &str = "/tmp/testcontainers_init.cypherl";
+/// Maximum time to wait for the Bolt port to accept connections, before the start is considered failed.
+const READY_TIMEOUT_SECS: u64 = 60;
+
+/// Port of the Bolt protocol that the [`Memgraph`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Memgraph`]: https://memgraph.com/
+pub const MEMGRAPH_BOLT_PORT: ContainerPort = ContainerPort::Tcp(7687);
+
+/// Module to work with [`Memgraph`] inside of tests.
+///
+/// Starts an instance of Memgraph based on the official [`Memgraph docker image`],
+/// accepting Bolt connections on port 7687 ([`MEMGRAPH_BOLT_PORT`]), thus any Neo4j driver can be used.
+///
+/// Authentication is disabled by default, see [`Memgraph::with_user`] and [`Memgraph::with_password`].
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{
+///     memgraph::{Memgraph, MEMGRAPH_BOLT_PORT},
+///     testcontainers::runners::SyncRunner,
+/// };
+///
+/// let memgraph_instance = Memgraph::default().start().unwrap();
+/// let uri = format!(
+///     "bolt://{}:{}",
+///     memgraph_instance.get_host().unwrap(),
+///     memgraph_instance
+///         .get_host_port_ipv4(MEMGRAPH_BOLT_PORT)
+///         .unwrap()
+/// );
+/// ```
+///
+/// [`Memgraph`]: https://memgraph.com/
+/// [`Memgraph docker image`]: https://hub.docker.com/r/memgraph/memgraph
+#[derive(Debug, Clone, Default)]
+pub struct Memgraph {
+    env_vars: BTreeMap<String, String>,
+    log_level: Option<String>,
+    init_file: Option<CopyToContainer>,
+}
+
+impl Memgraph {
+    /// Sets the log level (`--log-level`), e.g. `TRACE`, `DEBUG`, `INFO`, `WARNING`, `ERROR` or `CRITICAL`.
+    pub fn with_log_level(mut self, log_level: &str) -> Self {
+        self.log_level = Some(log_level.to_owned());
+        self
+    }
+
+    /// Creates a user with the given name on startup (`MEMGRAPH_USER`), which enables authentication.
+    pub fn with_user(mut self, user: &str) -> Self {
+        self.env_vars
+            .insert("MEMGRAPH_USER".to_owned(), user.to_owned());
+        self
+    }
+
+    /// Sets the password of the user created on startup (`MEMGRAPH_PASSWORD`), see [`Memgraph::with_user`].
+    pub fn with_password(mut self, password: &str) -> Self {
+        self.env_vars
+            .insert("MEMGRAPH_PASSWORD".to_owned(), password.to_owned());
+        self
+    }
+
+    /// Registers Cypher queries (one per line), which are executed on startup (`--init-data-file`)
+    /// to seed the graph before clients can connect.
+    ///
+    /// # Example
+    /// ```
+    /// use testcontainers_modules::memgraph::Memgraph;
+    ///
+    /// let memgraph = Memgraph::default().with_init_cypher(
+    ///     "CREATE (:Person {name: 'Alice'});\n"
+    ///         .to_string()
+    ///         .into_bytes(),
+    /// );
+    /// ```
+    pub fn with_init_cypher(mut self, init_cypher: impl Into<CopyDataSource>) -> Self {
+        self.init_file = Some(CopyToContainer::new(init_cypher.into(), INIT_FILE_PATH));
+        self
+    }
+}
+
+impl Image for Memgraph {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn tag(&self) -> &str {
+        TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        // the log output depends on the log level, thus the container is considered
+        // ready once the Bolt port accepts connections, see `exec_after_start`
+        vec![]
+    }
+
+    fn env_vars(
+        &self,
+    ) -> impl IntoIterator<Item = (impl Into<Cow<'_, str>>, impl Into<Cow<'_, str>>)> {
+        &self.env_vars
+    }
+
+    fn cmd(&self) -> impl IntoIterator<Item = impl Into<Cow<'_, str>>> {
+        let mut cmd = vec![];
+        if let Some(log_level) = &self.log_level {
+            cmd.push(format!("--log-level={log_level}"));
+        }
+        if self.init_file.is_some() {
+            cmd.push(format!("--init-data-file={INIT_FILE_PATH}"));
+        }
+        cmd
+    }
+
+    fn copy_to_sources(&self) -> impl IntoIterator<Item = &CopyToContainer> {
+        &self.init_file
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[MEMGRAPH_BOLT_PORT]
+    }
+
+    fn exec_after_start(
+        &self,
+        _cs: ContainerState,
+    ) -> Result<Vec<ExecCommand>, TestcontainersError> {
+        Ok(vec![ExecCommand::new([
+            "timeout".to_owned(),
+            READY_TIMEOUT_SECS.to_string(),
+            "bash".to_owned(),
+            "-c".to_owned(),
+            format!(
+                "until (echo > /dev/tcp/127.0.0.1/{}) 2>/dev/null; do sleep 0.1; done",
+                MEMGRAPH_BOLT_PORT.as_u16()
+            ),
+        ])
+        .with_cmd_ready_condition(CmdWaitFor::exit_code(0))])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use neo4rs::Graph;
+
+    use super::*;
+    use crate::testcontainers::runners::AsyncRunner;
+
+    #[tokio::test]
+    async fn memgraph_with_auth_and_init_cypher() -> Result<(), Box<dyn std::error::Error + 'static>>
+    {
+        let _ = pretty_env_logger::try_init();
+        let node = Memgraph::default()
+            .with_log_level("INFO")
+            .with_user("memgraph")
+            .with_password("secret")
+            .with_init_cypher(
+                "CREATE (:Person {name: 'Alice'});\nCREATE (:Person {name: 'Bob'});\n"
+                    .to_string()
+                    .into_bytes(),
+            )
+            .start()
+            .await?;
+        let uri = format!(
+            "bolt://{}:{}",
+            node.get_host().await?,
+            node.get_host_port_ipv4(MEMGRAPH_BOLT_PORT).await?
+        );
+
+        let graph = Graph::new(uri, "memgraph", "secret").await?;
+        let mut result = graph
+            .execute(neo4rs::query("MATCH (p:Person) RETURN count(p) AS persons"))
+            .await?;
+        let row = result.next().await?.unwrap();
+        let persons: i64 = row.get("persons").unwrap();
+        assert_eq!(2, persons);
+        Ok(())
+    }
+}
@@ -0,0 +1,152 @@
+use std::{borrow::Cow, collections::BTreeMap};
+
+use testcontainers::{
+    core::{wait::HttpWaitStrategy, ContainerPort, WaitFor},
+    CopyToContainer, Image,
+};
+
+const NAME: &str = "milvusdb/milvus";
+const TAG: &str = "v2.4.17";
+const EMBED_ETCD_CONFIG_PATH: &str = "/milvus/configs/embedEtcd.yaml";
+const EMBED_ETCD_CONFIG: &str = "listen-client-urls: http://0.0.0.0:2379
+advertise-client-urls: http://0.0.0.0:2379
+quota-backend-bytes: 4294967296
+auto-compaction-mode: revision
+auto-compaction-retention: '1000'
+";
+
+/// Port of the gRPC and REST API that the [`Milvus`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Milvus`]: https://milvus.io/
+pub const MILVUS_PORT: ContainerPort = ContainerPort::Tcp(19530);
+/// Port of the health and metrics endpoints that the [`Milvus`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Milvus`]: https://milvus.io/
+pub const MILVUS_MANAGEMENT_PORT: ContainerPort = ContainerPort::Tcp(9091);
+
+/// Module to work with [`Milvus`] inside of tests.
+///
+/// Starts a standalone instance of Milvus based on the official [`Milvus docker image`],
+/// using an embedded etcd and the local filesystem as storage, thus no further containers are needed.
+///
+/// Clients connect on port 19530 ([`MILVUS_PORT`]), the health and metrics endpoints are
+/// served on port 9091 ([`MILVUS_MANAGEMENT_PORT`]).
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{
+///     milvus::{Milvus, MILVUS_PORT},
+///     testcontainers::runners::SyncRunner,
+/// };
+///
+/// let milvus_instance = Milvus::default().start().unwrap();
+/// let url = format!(
+///     "http://{}:{}",
+///     milvus_instance.get_host().unwrap(),
+///     milvus_instance.get_host_port_ipv4(MILVUS_PORT).unwrap()
+/// );
+/// ```
+///
+/// [`Milvus`]: https://milvus.io/
+/// [`Milvus docker image`]: https://hub.docker.com/r/milvusdb/milvus
+#[derive(Debug, Clone)]
+pub struct Milvus {
+    env_vars: BTreeMap<String, String>,
+    embed_etcd_config: CopyToContainer,
+}
+
+impl Default for Milvus {
+    fn default() -> Self {
+        let mut env_vars = BTreeMap::new();
+        env_vars.insert("ETCD_USE_EMBED".to_owned(), "true".to_owned());
+        env_vars.insert(
+            "ETCD_DATA_DIR".to_owned(),
+            "/var/lib/milvus/etcd".to_owned(),
+        );
+        env_vars.insert(
+            "ETCD_CONFIG_PATH".to_owned(),
+            EMBED_ETCD_CONFIG_PATH.to_owned(),
+        );
+        env_vars.insert("COMMON_STORAGETYPE".to_owned(), "local".to_owned());
+        Self {
+            env_vars,
+            embed_etcd_config: CopyToContainer::new(
+                EMBED_ETCD_CONFIG.to_string().into_bytes(),
+                EMBED_ETCD_CONFIG_PATH,
+            ),
+        }
+    }
+}
+
+impl Image for Milvus {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn tag(&self) -> &str {
+        TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![WaitFor::http(
+            HttpWaitStrategy::new("/healthz")
+                .with_port(MILVUS_MANAGEMENT_PORT)
+                .with_expected_status_code(200_u16),
+        )]
+    }
+
+    fn env_vars(
+        &self,
+    ) -> impl IntoIterator<Item = (impl Into<Cow<'_, str>>, impl Into<Cow<'_, str>>)> {
+        &self.env_vars
+    }
+
+    fn cmd(&self) -> impl IntoIterator<Item = impl Into<Cow<'_, str>>> {
+        ["milvus", "run", "standalone"]
+    }
+
+    fn copy_to_sources(&self) -> impl IntoIterator<Item = &CopyToContainer> {
+        [&self.embed_etcd_config]
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[MILVUS_PORT, MILVUS_MANAGEMENT_PORT]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testcontainers::runners::SyncRunner;
+
+    #[test]
+    fn milvus_create_collection() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = Milvus::default().start()?;
+        let url = format!(
+            "http://{}:{}/v2/vectordb/collections",
+            node.get_host()?,
+            node.get_host_port_ipv4(MILVUS_PORT)?
+        );
+
+        let client = reqwest::blocking::Client::new();
+        let created: serde_json::Value = client
+            .post(format!("{url}/create"))
+            .json(&serde_json::json!({"collectionName": "test", "dimension": 4}))
+            .send()?
+            .error_for_status()?
+            .json()?;
+        assert_eq!(created["code"], 0);
+
+        let collections: serde_json::Value = client
+            .post(format!("{url}/list"))
+            .json(&serde_json::json!({}))
+            .send()?
+            .error_for_status()?
+            .json()?;
+        assert_eq!(collections["data"], serde_json::json!(["test"]));
+        Ok(())
+    }
+}
@@ -1,6 +1,9 @@
 use std::{borrow::Cow, collections::HashMap};
 
-use testcontainers::{core::WaitFor, Image};
+use testcontainers::{
+    core::{CmdWaitFor, ContainerPort, ContainerState, ExecCommand, WaitFor},
+    Image, TestcontainersError,
+};
 
 const NAME: &str = "minio/minio";
 const TAG: &str = "RELEASE.2022-02-07T08-17-33Z";
@@ -8,12 +11,77 @@ const TAG: &str = "RELEASE.2022-02-07T08-17-33Z";
 const DIR: &str = "/data";
 const CONSOLE_ADDRESS: &str = ":9001";
 
-#[allow(missing_docs)]
-// not having docs here is currently allowed to address the missing docs problem one place at a time. Helping us by documenting just one of these places helps other devs tremendously
+/// Port of the S3 API that the [`MinIO`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`MinIO`]: https://min.io/
+pub const MINIO_PORT: ContainerPort = ContainerPort::Tcp(9000);
+
+/// Port of the web console that the [`MinIO`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`MinIO`]: https://min.io/
+pub const MINIO_CONSOLE_PORT: ContainerPort = ContainerPort::Tcp(9001);
+
+/// Module to work with [`MinIO`] inside of tests.
+///
+/// Starts a single node instance of MinIO based on the official [`MinIO docker image`],
+/// with `minioadmin` as username and password of the root user by default.
+///
+/// The S3 API is exposed on port 9000 ([`MINIO_PORT`]) and the web console on port 9001 ([`MINIO_CONSOLE_PORT`]).
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{
+///     minio::{MinIO, MINIO_PORT},
+///     testcontainers::runners::SyncRunner,
+/// };
+///
+/// let minio_instance = MinIO::default()
+///     .with_buckets(&["test-bucket"])
+///     .start()
+///     .unwrap();
+/// let endpoint = format!(
+///     "http://{}:{}",
+///     minio_instance.get_host().unwrap(),
+///     minio_instance.get_host_port_ipv4(MINIO_PORT).unwrap()
+/// );
+/// ```
+///
+/// [`MinIO`]: https://min.io/
+/// [`MinIO docker image`]: https://hub.docker.com/r/minio/minio
 #[derive(Debug, Clone)]
 pub struct MinIO {
     env_vars: HashMap<String, String>,
     cmd: MinIOServerCmd,
+    buckets: Vec<String>,
+}
+
+impl MinIO {
+    /// Sets the username of the root user (`MINIO_ROOT_USER`), defaults to `minioadmin`.
+    pub fn with_root_user(mut self, user: &str) -> Self {
+        self.env_vars
+            .insert("MINIO_ROOT_USER".to_owned(), user.to_owned());
+        self
+    }
+
+    /// Sets the password of the root user (`MINIO_ROOT_PASSWORD`), defaults to `minioadmin`.
+    /// Has to be at least 8 characters long.
+    pub fn with_root_password(mut self, password: &str) -> Self {
+        self.env_vars
+            .insert("MINIO_ROOT_PASSWORD".to_owned(), password.to_owned());
+        self
+    }
+
+    /// Creates the given buckets once the server is started, thus they can be used by tests right away.
+    /// Can be called multiple times to add (not override) buckets.
+    ///
+    /// The buckets are created as directories in the data directory of the server.
+    pub fn with_buckets(mut self, buckets: &[&str]) -> Self {
+        self.buckets
+            .extend(buckets.iter().map(|bucket| (*bucket).to_owned()));
+        self
+    }
 }
 
 impl Default for MinIO {
@@ -27,6 +95,7 @@ impl Default for MinIO {
         Self {
             env_vars,
             cmd: MinIOServerCmd::default(),
+            buckets: Vec::new(),
         }
     }
 }
@@ -98,6 +167,29 @@ impl Image for MinIO {
     fn cmd(&self) -> impl IntoIterator<Item = impl Into<Cow<'_, str>>> {
         &self.cmd
     }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[MINIO_PORT, MINIO_CONSOLE_PORT]
+    }
+
+    fn exec_after_start(
+        &self,
+        _cs: ContainerState,
+    ) -> Result<Vec<ExecCommand>, TestcontainersError> {
+        if self.buckets.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let mut cmd = vec!["mkdir".to_owned(), "-p".to_owned()];
+        cmd.extend(
+            self.buckets
+                .iter()
+                .map(|bucket| format!("{}/{bucket}", self.cmd.dir)),
+        );
+        Ok(vec![
+            ExecCommand::new(cmd).with_cmd_ready_condition(CmdWaitFor::exit_code(0))
+        ])
+    }
 }
 
 #[cfg(test)]
@@ -114,7 +206,7 @@ mod tests {
         let node = minio.start().await?;
 
         let host_port = node.get_host_port_ipv4(9000).await?;
-        let client = build_s3_client(host_port).await;
+        let client = build_s3_client(host_port, "minioadmin", "minioadmin").await;
 
         let bucket_name = "test-bucket";
 
@@ -137,10 +229,38 @@ mod tests {
         Ok(())
     }
 
-    async fn build_s3_client(host_port: u16) -> Client {
+    #[tokio::test]
+    async fn minio_with_root_credentials_and_buckets(
+    ) -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let minio = minio::MinIO::default()
+            .with_root_user("custom-user")
+            .with_root_password("custom-password")
+            .with_buckets(&["first-bucket", "second-bucket"]);
+        let node = minio.start().await?;
+
+        let host_port = node.get_host_port_ipv4(minio::MINIO_PORT).await?;
+        let client = build_s3_client(host_port, "custom-user", "custom-password").await;
+
+        let buckets = client
+            .list_buckets()
+            .send()
+            .await
+            .expect("Failed to get list of buckets")
+            .buckets
+            .unwrap();
+        let mut bucket_names: Vec<_> = buckets
+            .iter()
+            .map(|bucket| bucket.name.as_deref().unwrap())
+            .collect();
+        bucket_names.sort();
+        assert_eq!(vec!["first-bucket", "second-bucket"], bucket_names);
+        Ok(())
+    }
+
+    async fn build_s3_client(host_port: u16, access_key: &str, secret_key: &str) -> Client {
         let endpoint_uri = format!("http://127.0.0.1:{host_port}");
         let region_provider = RegionProviderChain::default_provider().or_else("us-east-1");
-        let creds = Credentials::new("minioadmin", "minioadmin", None, None, "test");
+        let creds = Credentials::new(access_key, secret_key, None, None, "test");
 
         // Default MinIO credentials (Can be overridden by ENV container variables)
         let shared_config = aws_config::defaults(BehaviorVersion::latest())
@@ -1,14 +1,37 @@
 use std::borrow::Cow;
 
-use testcontainers::{core::WaitFor, Image};
+use testcontainers::{
+    core::{ContainerPort, WaitFor},
+    CopyDataSource, CopyToContainer, Image,
+};
 
 const NAME: &str = "eclipse-mosquitto";
 const TAG: &str = "2.0.18";
 
+const CONFIG_DIR: &str = "/mosquitto/config";
+
+/// Port of the MQTT listener that the [`Mosquitto`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Mosquitto`]: https://mosquitto.org/
+pub const MOSQUITTO_PORT: ContainerPort = ContainerPort::Tcp(1883);
+/// Port of the MQTT over TLS listener (see [`Mosquitto::with_tls`]) that the [`Mosquitto`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Mosquitto`]: https://mosquitto.org/
+pub const MOSQUITTO_TLS_PORT: ContainerPort = ContainerPort::Tcp(8883);
+/// Port of the MQTT over WebSockets listener (see [`Mosquitto::with_websockets`]) that the [`Mosquitto`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Mosquitto`]: https://mosquitto.org/
+pub const MOSQUITTO_WEBSOCKETS_PORT: ContainerPort = ContainerPort::Tcp(9001);
+
 /// Module to work with [`Mosquitto`] inside of tests.
 ///
-/// Starts a MQTT broker without authentication.
+/// Starts a MQTT broker without authentication, listening on port 1883 ([`MOSQUITTO_PORT`]).
 ///
+/// Access control, a TLS listener on port 8883 ([`MOSQUITTO_TLS_PORT`]) and a WebSockets listener
+/// on port 9001 ([`MOSQUITTO_WEBSOCKETS_PORT`]) can be configured with the builder methods.
 ///
 /// # Example
 /// ```
@@ -19,18 +42,121 @@ const TAG: &str = "2.0.18";
 /// let broker_url = format!(
 ///     "{}:{}",
 ///     mosquitto_instance.get_host().unwrap(),
-///     mosquitto_instance.get_host_port_ipv4(1883).unwrap()
+///     mosquitto_instance
+///         .get_host_port_ipv4(mosquitto::MOSQUITTO_PORT)
+///         .unwrap()
 /// );
 /// ```
 ///
 /// [`Mosquitto`]: https://mosquitto.org/
 /// [`Mosquitto docker image`]: https://hub.docker.com/_/eclipse-mosquitto
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Clone)]
 pub struct Mosquitto {
-    /// (remove if there is another variable)
-    /// Field is included to prevent this struct to be a unit struct.
-    /// This allows extending functionality (and thus further variables) without breaking changes
-    _priv: (),
+    allow_anonymous: bool,
+    websockets: bool,
+    password_file: Option<CopyToContainer>,
+    tls_files: Vec<CopyToContainer>,
+}
+
+impl Default for Mosquitto {
+    fn default() -> Self {
+        Self {
+            allow_anonymous: true,
+            websockets: false,
+            password_file: None,
+            tls_files: Vec::new(),
+        }
+    }
+}
+
+impl Mosquitto {
+    /// Allows or denies clients connecting without credentials, allowed by default.
+    pub fn with_anonymous_access(mut self, allowed: bool) -> Self {
+        self.allow_anonymous = allowed;
+        self
+    }
+
+    /// Registers a [password file](https://mosquitto.org/man/mosquitto_passwd-1.html)
+    /// used to authenticate clients. The passwords have to be hashed, e.g. by `mosquitto_passwd`.
+    ///
+    /// Usually combined with [`Mosquitto::with_anonymous_access`] to deny unauthenticated clients.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// # use testcontainers_modules::mosquitto::Mosquitto;
+    /// let mosquitto = Mosquitto::default()
+    ///     .with_anonymous_access(false)
+    ///     .with_password_file(include_str!("path_to_passwd").to_string().into_bytes());
+    /// ```
+    pub fn with_password_file(mut self, password_file: impl Into<CopyDataSource>) -> Self {
+        self.password_file = Some(CopyToContainer::new(
+            password_file.into(),
+            format!("{CONFIG_DIR}/passwd"),
+        ));
+        self
+    }
+
+    /// Enables a MQTT over WebSockets listener on port 9001 ([`MOSQUITTO_WEBSOCKETS_PORT`]).
+    pub fn with_websockets(mut self) -> Self {
+        self.websockets = true;
+        self
+    }
+
+    /// Enables a MQTT over TLS listener on port 8883 ([`MOSQUITTO_TLS_PORT`]),
+    /// using the server certificate of the given [`TlsCertificates`].
+    /// Clients can verify the broker with [`TlsCertificates::ca_cert_pem`].
+    ///
+    /// # Example
+    /// ```
+    /// use testcontainers_modules::{mosquitto::Mosquitto, tls::TlsCertificates};
+    ///
+    /// let certificates = TlsCertificates::default();
+    /// let mosquitto = Mosquitto::default().with_tls(&certificates);
+    /// ```
+    ///
+    /// [`TlsCertificates`]: crate::tls::TlsCertificates
+    /// [`TlsCertificates::ca_cert_pem`]: crate::tls::TlsCertificates::ca_cert_pem
+    #[cfg(feature = "tls")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "tls")))]
+    pub fn with_tls(mut self, certificates: &crate::tls::TlsCertificates) -> Self {
+        self.tls_files = vec![
+            certificates.copy_ca_cert_to(format!("{CONFIG_DIR}/ca.crt")),
+            certificates.copy_cert_to(format!("{CONFIG_DIR}/server.crt")),
+            certificates.copy_key_to(format!("{CONFIG_DIR}/server.key")),
+        ];
+        self
+    }
+
+    fn is_customized(&self) -> bool {
+        !self.allow_anonymous
+            || self.websockets
+            || self.password_file.is_some()
+            || !self.tls_files.is_empty()
+    }
+
+    fn config(&self) -> String {
+        let mut config = format!(
+            "per_listener_settings false\nallow_anonymous {}\nlistener {}\n",
+            self.allow_anonymous,
+            MOSQUITTO_PORT.as_u16()
+        );
+        if self.password_file.is_some() {
+            config.push_str(&format!("password_file {CONFIG_DIR}/passwd\n"));
+        }
+        if self.websockets {
+            config.push_str(&format!(
+                "listener {}\nprotocol websockets\n",
+                MOSQUITTO_WEBSOCKETS_PORT.as_u16()
+            ));
+        }
+        if !self.tls_files.is_empty() {
+            config.push_str(&format!(
+                "listener {}\ncafile {CONFIG_DIR}/ca.crt\ncertfile {CONFIG_DIR}/server.crt\nkeyfile {CONFIG_DIR}/server.key\n",
+                MOSQUITTO_TLS_PORT.as_u16()
+            ));
+        }
+        config
+    }
 }
 
 impl Image for Mosquitto {
@@ -49,7 +175,142 @@ impl Image for Mosquitto {
         ))]
     }
 
+    fn copy_to_sources(&self) -> impl IntoIterator<Item = &CopyToContainer> {
+        self.password_file.iter().chain(&self.tls_files)
+    }
+
     fn cmd(&self) -> impl IntoIterator<Item = impl Into<Cow<'_, str>>> {
-        ["mosquitto", "-c", "/mosquitto-no-auth.conf"]
+        if !self.is_customized() {
+            return vec![
+                "mosquitto".to_owned(),
+                "-c".to_owned(),
+                "/mosquitto-no-auth.conf".to_owned(),
+            ];
+        }
+
+        let config_file = format!("{CONFIG_DIR}/testcontainers.conf");
+        vec![
+            "sh".to_owned(),
+            "-c".to_owned(),
+            format!(
+                "cat > {config_file} <<'EOF'\n{}EOF\nexec mosquitto -c {config_file}",
+                self.config()
+            ),
+        ]
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        match (!self.tls_files.is_empty(), self.websockets) {
+            (false, false) => &[MOSQUITTO_PORT],
+            (true, false) => &[MOSQUITTO_PORT, MOSQUITTO_TLS_PORT],
+            (false, true) => &[MOSQUITTO_PORT, MOSQUITTO_WEBSOCKETS_PORT],
+            (true, true) => &[
+                MOSQUITTO_PORT,
+                MOSQUITTO_TLS_PORT,
+                MOSQUITTO_WEBSOCKETS_PORT,
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use testcontainers::core::{CmdWaitFor, ExecCommand};
+
+    use super::*;
+    use crate::testcontainers::runners::AsyncRunner;
+
+    /// Password file entry of `user` with password `secret`, as generated by `mosquitto_passwd`.
+    const PASSWD: &str = "user:$7$101$dGVzdGNvbnRhaW5y$hGkc6TUF/0WPyS4zJ+reLdDPlasomEsD5FBenQaZxnT96XfwVyBzDsrrypCV3gTXEnaDedwLr5xTlJkkq6PBOg==\n";
+
+    fn publish_command(args: &[&str]) -> ExecCommand {
+        let mut cmd = vec![
+            "mosquitto_pub",
+            "-h",
+            "localhost",
+            "-t",
+            "test",
+            "-m",
+            "hello",
+        ];
+        cmd.extend_from_slice(args);
+        ExecCommand::new(cmd)
+    }
+
+    #[tokio::test]
+    async fn mosquitto_default_exposes_mqtt_port_only(
+    ) -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = Mosquitto::default().start().await?;
+
+        node.exec(publish_command(&[]).with_cmd_ready_condition(CmdWaitFor::exit_code(0)))
+            .await?;
+        assert!(node.get_host_port_ipv4(MOSQUITTO_PORT).await.is_ok());
+        assert!(node.get_host_port_ipv4(MOSQUITTO_TLS_PORT).await.is_err());
+        assert!(node
+            .get_host_port_ipv4(MOSQUITTO_WEBSOCKETS_PORT)
+            .await
+            .is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn mosquitto_with_password_file() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = Mosquitto::default()
+            .with_anonymous_access(false)
+            .with_password_file(PASSWD.to_string().into_bytes())
+            .start()
+            .await?;
+
+        let mut anonymous = node.exec(publish_command(&[])).await?;
+        let stderr = String::from_utf8(anonymous.stderr_to_vec().await?)?;
+        assert!(stderr.contains("not authorised"), "{stderr}");
+
+        node.exec(
+            publish_command(&["-u", "user", "-P", "secret"])
+                .with_cmd_ready_condition(CmdWaitFor::exit_code(0)),
+        )
+        .await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn mosquitto_with_websockets() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = Mosquitto::default().with_websockets().start().await?;
+        let url = format!(
+            "http://{}:{}/",
+            node.get_host().await?,
+            node.get_host_port_ipv4(MOSQUITTO_WEBSOCKETS_PORT).await?
+        );
+
+        let response = reqwest::Client::new()
+            .get(url)
+            .header("Connection", "Upgrade")
+            .header("Upgrade", "websocket")
+            .header("Sec-WebSocket-Version", "13")
+            .header("Sec-WebSocket-Key", "dGhlIHNhbXBsZSBub25jZQ==")
+            .header("Sec-WebSocket-Protocol", "mqtt")
+            .send()
+            .await?;
+        assert_eq!(response.status(), reqwest::StatusCode::SWITCHING_PROTOCOLS);
+        Ok(())
+    }
+
+    #[cfg(feature = "tls")]
+    #[tokio::test]
+    async fn mosquitto_with_tls() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let certificates = crate::tls::TlsCertificates::default();
+        let node = Mosquitto::default().with_tls(&certificates).start().await?;
+
+        node.exec(
+            publish_command(&["-p", "8883", "--cafile", "/mosquitto/config/ca.crt"])
+                .with_cmd_ready_condition(CmdWaitFor::exit_code(0)),
+        )
+        .await?;
+        assert!(node.get_host_port_ipv4(MOSQUITTO_TLS_PORT).await.is_ok());
+        Ok(())
     }
 }
@@ -1,6 +1,16 @@
 use std::{borrow::Cow, collections::HashMap};
 
-use testcontainers::{core::WaitFor, Image};
+use testcontainers::{
+    core::{CmdWaitFor, ContainerPort, ContainerState, ExecCommand, WaitFor},
+    Image, TestcontainersError,
+};
+
+/// Port of the TDS endpoint that the [`MssqlServer`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+pub const MSSQL_SERVER_PORT: ContainerPort = ContainerPort::Tcp(1433);
+
+/// Maximum time to wait for the server to accept logins, before the start is considered failed.
+const READY_TIMEOUT_SECS: u64 = 120;
 
 /// [Microsoft SQL Server](https://www.microsoft.com/en-us/sql-server) module
 /// for [testcontainers](https://crates.io/crates/testcontainers).
@@ -48,8 +58,13 @@ use testcontainers::{core::WaitFor, Image};
 ///
 /// ## `MSSQL_PID`
 ///
-/// The edition of SQL Server.
+/// The edition of SQL Server, see [`MssqlServer::with_edition`].
 /// The default value is `Developer`, which will run the container using the Developer Edition.
+///
+/// # Readiness
+///
+/// SQL Server logs that it is ready for client connections before it finished the TLS setup and the recovery
+/// of all databases, thus the container is only considered ready once the SA user can log in with `sqlcmd`.
 #[derive(Debug, Clone)]
 pub struct MssqlServer {
     env_vars: HashMap<String, String>,
@@ -63,9 +78,27 @@ impl MssqlServer {
     pub const DEFAULT_SA_PASSWORD: &'static str = "yourStrong(!)Password";
 
     /// Sets the password as `MSSQL_SA_PASSWORD`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the password does not conform to the
+    /// [strong password policy](https://learn.microsoft.com/en-us/sql/relational-databases/security/password-policy?view=sql-server-ver15#password-complexity),
+    /// as SQL Server would refuse to start with it.
     pub fn with_sa_password(mut self, password: impl Into<String>) -> Self {
+        let password = password.into();
+        assert!(
+            is_strong_password(&password),
+            "the SA password has to be at least 8 characters long and contain characters of three of the \
+             following sets: uppercase letters, lowercase letters, digits and symbols"
+        );
+        self.env_vars.insert("MSSQL_SA_PASSWORD".into(), password);
+        self
+    }
+
+    /// Sets the edition of SQL Server as `MSSQL_PID`, defaults to [`MssqlEdition::Developer`].
+    pub fn with_edition(mut self, edition: MssqlEdition) -> Self {
         self.env_vars
-            .insert("MSSQL_SA_PASSWORD".into(), password.into());
+            .insert("MSSQL_PID".into(), edition.as_str().into());
         self
     }
 
@@ -79,6 +112,34 @@ impl MssqlServer {
     }
 }
 
+/// Edition of SQL Server, see [`MssqlServer::with_edition`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MssqlEdition {
+    /// Developer Edition, with all features of the Enterprise Edition, licensed for development and test systems only.
+    Developer,
+    /// Express Edition, the free edition with limited resources.
+    Express,
+}
+
+impl MssqlEdition {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Developer => "Developer",
+            Self::Express => "Express",
+        }
+    }
+}
+
+fn is_strong_password(password: &str) -> bool {
+    let character_sets = [
+        password.chars().any(|c| c.is_uppercase()),
+        password.chars().any(|c| c.is_lowercase()),
+        password.chars().any(|c| c.is_ascii_digit()),
+        password.chars().any(|c| !c.is_alphanumeric()),
+    ];
+    password.chars().count() >= 8 && character_sets.iter().filter(|&&set| set).count() >= 3
+}
+
 impl Default for MssqlServer {
     fn default() -> Self {
         let mut env_vars = HashMap::new();
@@ -114,6 +175,25 @@ impl Image for MssqlServer {
     ) -> impl IntoIterator<Item = (impl Into<Cow<'_, str>>, impl Into<Cow<'_, str>>)> {
         &self.env_vars
     }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[MSSQL_SERVER_PORT]
+    }
+
+    fn exec_after_start(
+        &self,
+        _cs: ContainerState,
+    ) -> Result<Vec<ExecCommand>, TestcontainersError> {
+        Ok(vec![ExecCommand::new([
+            "timeout",
+            &READY_TIMEOUT_SECS.to_string(),
+            "bash",
+            "-c",
+            "until /opt/mssql-tools18/bin/sqlcmd -C -S localhost -U sa -P \"$MSSQL_SA_PASSWORD\" \
+             -Q 'SELECT 1' > /dev/null 2>&1; do sleep 1; done",
+        ])
+        .with_cmd_ready_condition(CmdWaitFor::exit_code(0))])
+    }
 }
 
 #[cfg(test)]
@@ -166,6 +246,50 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn express_edition() -> Result<(), Box<dyn error::Error>> {
+        let container = MssqlServer::default()
+            .with_accept_eula()
+            .with_edition(MssqlEdition::Express)
+            .start()
+            .await?;
+        let config = new_config(
+            container.get_host().await?,
+            container.get_host_port_ipv4(MSSQL_SERVER_PORT).await?,
+            MssqlServer::DEFAULT_SA_PASSWORD,
+        );
+        let mut client = get_mssql_client(config).await?;
+
+        let stream = client
+            .query(
+                "SELECT CAST(SERVERPROPERTY('Edition') AS nvarchar(128))",
+                &[],
+            )
+            .await?;
+        let row = stream.into_row().await?.unwrap();
+
+        assert!(row
+            .get::<&str, _>(0)
+            .unwrap()
+            .starts_with("Express Edition"));
+
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "the SA password has to be at least 8 characters long")]
+    fn weak_sa_password() {
+        let _ = MssqlServer::default().with_sa_password("password");
+    }
+
+    #[test]
+    fn strong_password() {
+        assert!(is_strong_password("yourStrong(!)Password"));
+        assert!(is_strong_password("Passw0rd"));
+        assert!(!is_strong_password("Pa55!"));
+        assert!(!is_strong_password("password123"));
+    }
+
     async fn get_mssql_client(
         config: Config,
     ) -> Result<Client<Compat<TcpStream>>, Box<dyn error::Error>> {
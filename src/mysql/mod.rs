@@ -1,9 +1,10 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, collections::BTreeMap};
 
 use testcontainers::{core::WaitFor, CopyDataSource, CopyToContainer, Image};
 
 const NAME: &str = "mysql";
 const TAG: &str = "8.1";
+const CONFIG_FILE_PATH: &str = "/etc/mysql/conf.d/testcontainers.cnf";
 
 /// Module to work with [`MySQL`] inside of tests.
 ///
@@ -25,9 +26,24 @@ const TAG: &str = "8.1";
 ///
 /// [`MySQL`]: https://www.mysql.com/
 /// [`MySQL docker image`]: https://hub.docker.com/_/mysql
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Clone)]
 pub struct Mysql {
+    env_vars: BTreeMap<String, String>,
     copy_to_sources: Vec<CopyToContainer>,
+    config_file: Option<CopyToContainer>,
+}
+
+impl Default for Mysql {
+    fn default() -> Self {
+        let mut env_vars = BTreeMap::new();
+        env_vars.insert("MYSQL_DATABASE".to_owned(), "test".to_owned());
+        env_vars.insert("MYSQL_ALLOW_EMPTY_PASSWORD".to_owned(), "yes".to_owned());
+        Self {
+            env_vars,
+            copy_to_sources: Vec::new(),
+            config_file: None,
+        }
+    }
 }
 impl Mysql {
     /// Registers sql to be executed automatically when the container starts.
@@ -58,6 +74,40 @@ impl Mysql {
             .push(CopyToContainer::new(init_sql.into(), target));
         self
     }
+
+    /// Sets the password of the root user (`MYSQL_ROOT_PASSWORD`), instead of allowing an empty password.
+    pub fn with_root_password(mut self, password: &str) -> Self {
+        self.env_vars.remove("MYSQL_ALLOW_EMPTY_PASSWORD");
+        self.env_vars
+            .insert("MYSQL_ROOT_PASSWORD".to_owned(), password.to_owned());
+        self
+    }
+
+    /// Sets the name of the database created on startup (`MYSQL_DATABASE`), defaults to `test`.
+    pub fn with_database(mut self, database: &str) -> Self {
+        self.env_vars
+            .insert("MYSQL_DATABASE".to_owned(), database.to_owned());
+        self
+    }
+
+    /// Registers a custom `my.cnf` to be used by the MySQL instance.
+    ///
+    /// The file is copied to `/etc/mysql/conf.d/`, thus its options take precedence over the defaults of the image.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use testcontainers_modules::mysql::Mysql;
+    /// let mysql_image = Mysql::default().with_config_file(
+    ///     "[mysqld]\nmax_connections = 42\n"
+    ///         .to_string()
+    ///         .into_bytes(),
+    /// );
+    /// ```
+    pub fn with_config_file(mut self, config_file: impl Into<CopyDataSource>) -> Self {
+        self.config_file = Some(CopyToContainer::new(config_file.into(), CONFIG_FILE_PATH));
+        self
+    }
 }
 
 impl Image for Mysql {
@@ -79,13 +129,10 @@ impl Image for Mysql {
     fn env_vars(
         &self,
     ) -> impl IntoIterator<Item = (impl Into<Cow<'_, str>>, impl Into<Cow<'_, str>>)> {
-        [
-            ("MYSQL_DATABASE", "test"),
-            ("MYSQL_ALLOW_EMPTY_PASSWORD", "yes"),
-        ]
+        &self.env_vars
     }
     fn copy_to_sources(&self) -> impl IntoIterator<Item = &CopyToContainer> {
-        &self.copy_to_sources
+        self.copy_to_sources.iter().chain(&self.config_file)
     }
 }
 
@@ -160,4 +207,25 @@ mod tests {
         assert_eq!(first_row, Some(String::from("8.0.34")));
         Ok(())
     }
+
+    #[test]
+    fn mysql_with_root_password_database_and_config_file(
+    ) -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let node = MysqlImage::default()
+            .with_root_password("secret")
+            .with_database("custom_db")
+            .with_config_file("[mysqld]\nmax_connections = 42\n".to_string().into_bytes())
+            .start()?;
+
+        let connection_string = &format!(
+            "mysql://root:secret@{}:{}/custom_db",
+            node.get_host()?,
+            node.get_host_port_ipv4(3306)?
+        );
+        let mut conn = mysql::Conn::new(mysql::Opts::from_url(connection_string).unwrap()).unwrap();
+
+        let max_connections: Option<u32> = conn.query_first("SELECT @@max_connections").unwrap();
+        assert_eq!(max_connections, Some(42));
+        Ok(())
+    }
 }
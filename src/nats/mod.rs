@@ -1,18 +1,70 @@
 use std::borrow::Cow;
 
-use testcontainers::{core::WaitFor, Image};
+use testcontainers::{
+    core::{ContainerPort, WaitFor},
+    Image,
+};
 
 const NAME: &str = "nats";
 const TAG: &str = "2.10.14";
+const CONFIG_FILE: &str = "nats-server.conf";
+
+/// Port for client connections that the [`Nats`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+pub const NATS_CLIENT_PORT: ContainerPort = ContainerPort::Tcp(4222);
+/// Port of the HTTP monitoring endpoint that the [`Nats`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+pub const NATS_MONITORING_PORT: ContainerPort = ContainerPort::Tcp(8222);
+/// Port for cluster routing that the [`Nats`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+pub const NATS_ROUTING_PORT: ContainerPort = ContainerPort::Tcp(6222);
 
 /// Nats image for [testcontainers](https://crates.io/crates/testcontainers).
 ///
 /// This image is based on the official [Nats](https://hub.docker.com/_/nats) image.
+///
+/// Clients connect on port 4222 ([`NATS_CLIENT_PORT`]), monitoring is available on port 8222
+/// ([`NATS_MONITORING_PORT`]) and cluster routing on port 6222 ([`NATS_ROUTING_PORT`]).
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{
+///     nats::{Nats, NATS_CLIENT_PORT},
+///     testcontainers::runners::SyncRunner,
+/// };
+///
+/// let nats_instance = Nats::default()
+///     .with_jetstream()
+///     .with_user_password("user", "secret")
+///     .start()
+///     .unwrap();
+/// let url = format!(
+///     "{}:{}",
+///     nats_instance.get_host().unwrap(),
+///     nats_instance.get_host_port_ipv4(NATS_CLIENT_PORT).unwrap()
+/// );
+/// ```
 #[derive(Debug, Default, Clone)]
 pub struct Nats {
     cmd: NatsServerCmd,
 }
 
+impl Nats {
+    /// Enables JetStream (`--jetstream`) to use the built-in persistence features of NATS.
+    ///
+    /// See: https://docs.nats.io/nats-concepts/jetstream
+    pub fn with_jetstream(mut self) -> Self {
+        self.cmd = self.cmd.with_jetstream();
+        self
+    }
+
+    /// Requires clients to authenticate with the given user and password.
+    pub fn with_user_password(mut self, user: &str, password: &str) -> Self {
+        self.cmd = self.cmd.with_user(user).with_password(password);
+        self
+    }
+}
+
 #[allow(missing_docs)]
 // not having docs here is currently allowed to address the missing docs problem one place at a time. Helping us by documenting just one of these places helps other devs tremendously
 #[derive(Default, Debug, Clone)]
@@ -98,7 +150,20 @@ impl Image for Nats {
     }
 
     fn cmd(&self) -> impl IntoIterator<Item = impl Into<Cow<'_, str>>> {
-        &self.cmd
+        let args: Vec<String> = self.cmd.into_iter().collect();
+        if args.is_empty() {
+            return args;
+        }
+        // keep the monitoring and routing listeners of the image's default config,
+        // which would be dropped by overriding its command
+        ["--config".to_owned(), CONFIG_FILE.to_owned()]
+            .into_iter()
+            .chain(args)
+            .collect()
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[NATS_CLIENT_PORT, NATS_MONITORING_PORT, NATS_ROUTING_PORT]
     }
 }
 
@@ -110,7 +175,7 @@ mod tests {
     use futures::StreamExt;
     use testcontainers::{runners::AsyncRunner, ImageExt};
 
-    use crate::nats::{Nats, NatsServerCmd};
+    use crate::nats::{Nats, NatsServerCmd, NATS_CLIENT_PORT, NATS_MONITORING_PORT};
 
     #[test]
     fn set_user() {
@@ -229,4 +294,42 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn it_works_with_user_password_and_jetstream(
+    ) -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let container = Nats::default()
+            .with_jetstream()
+            .with_user_password("custom_user", "custom_password")
+            .start()
+            .await?;
+
+        let host = container.get_host().await?;
+        let host_port = container.get_host_port_ipv4(NATS_CLIENT_PORT).await?;
+        let url = format!("{host}:{host_port}");
+
+        let unauthenticated = async_nats::ConnectOptions::default().connect(&url).await;
+        assert!(unauthenticated.is_err());
+
+        let nats_client = async_nats::ConnectOptions::with_user_and_password(
+            "custom_user".into(),
+            "custom_password".into(),
+        )
+        .connect(&url)
+        .await
+        .expect("failed to connect to nats server");
+        let jetstream = jetstream::new(nats_client);
+        jetstream
+            .create_stream(jetstream::stream::Config {
+                name: "EVENTS".to_string(),
+                subjects: vec!["events.>".to_string()],
+                ..Default::default()
+            })
+            .await?;
+
+        let monitoring_port = container.get_host_port_ipv4(NATS_MONITORING_PORT).await?;
+        let response = reqwest::get(format!("http://{host}:{monitoring_port}/healthz")).await?;
+        assert!(response.status().is_success());
+        Ok(())
+    }
 }
@@ -63,6 +63,9 @@ pub enum Neo4jLabsPlugin {
     Custom(String),
 }
 
+/// Alias of [`Neo4jLabsPlugin`], as the plugins are configured by `NEO4J_PLUGINS` as of Neo4j `5`.
+pub type Neo4jPlugin = Neo4jLabsPlugin;
+
 impl std::fmt::Display for Neo4jLabsPlugin {
     fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -124,15 +127,21 @@ impl std::fmt::Display for Neo4jLabsPlugin {
 /// # Neo4j Labs Plugins
 ///
 /// Neo4j offers built-in support for Neo4j Labs plugins.
-/// The method `with_neo4j_labs_plugin` can be used to define them.
+/// The method `with_plugins` can be used to define them.
 ///
 /// Supported plugins are APOC, APOC Core, Bloom, Streams, Graph Data Science, and Neo Semantics.
+///
+/// # Memory
+///
+/// The heap and page cache sizes can be limited with `with_memory_limits`,
+/// which is useful when running plugins like Graph Data Science.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Neo4j {
     version: Value,
     user: Option<Value>,
     pass: Option<Value>,
     plugins: BTreeSet<Neo4jLabsPlugin>,
+    memory_limits: Option<(Value, Value)>,
 }
 
 impl Neo4j {
@@ -148,6 +157,7 @@ impl Neo4j {
             user: Some(Cow::Borrowed(Self::DEFAULT_USER)),
             pass: Some(Cow::Borrowed(Self::DEFAULT_PASS)),
             plugins: BTreeSet::new(),
+            memory_limits: None,
         }
     }
 
@@ -182,12 +192,34 @@ impl Neo4j {
         self
     }
 
-    /// Add Neo4j lab plugins to get started with the database.
+    /// Add plugins to get started with the database, which are installed on startup.
+    /// Can be called multiple times to add (not override) plugins.
+    ///
+    /// The plugins are configured by `NEO4J_PLUGINS`, or `NEO4JLABS_PLUGINS` for Neo4j versions before `5`.
     #[must_use]
-    pub fn with_neo4j_labs_plugin(mut self, plugins: &[Neo4jLabsPlugin]) -> Self {
+    pub fn with_plugins(mut self, plugins: &[Neo4jPlugin]) -> Self {
         self.plugins.extend(plugins.iter().cloned());
         self
     }
+
+    /// Add Neo4j lab plugins to get started with the database.
+    ///
+    /// Same as [`Self::with_plugins`].
+    #[must_use]
+    pub fn with_neo4j_labs_plugin(self, plugins: &[Neo4jLabsPlugin]) -> Self {
+        self.with_plugins(plugins)
+    }
+
+    /// Set the size of the heap (initial and maximum) and of the page cache, e.g. `512m`.
+    #[must_use]
+    pub fn with_memory_limits(
+        mut self,
+        heap: impl Into<Value>,
+        pagecache: impl Into<Value>,
+    ) -> Self {
+        self.memory_limits = Some((heap.into(), pagecache.into()));
+        self
+    }
 }
 
 type Value = Cow<'static, str>;
@@ -343,7 +375,37 @@ impl Neo4j {
 
         let plugin_definition = format!("[{}]", plugin_names);
 
-        Some(("NEO4JLABS_PLUGINS".to_owned(), plugin_definition))
+        let key = if self.is_before_v5() {
+            "NEO4JLABS_PLUGINS"
+        } else {
+            "NEO4J_PLUGINS"
+        };
+        Some((key.to_owned(), plugin_definition))
+    }
+
+    fn memory_env(&self) -> impl IntoIterator<Item = (String, String)> {
+        let Some((heap, pagecache)) = &self.memory_limits else {
+            return Vec::new();
+        };
+
+        let prefix = if self.is_before_v5() {
+            "NEO4J_dbms_memory"
+        } else {
+            "NEO4J_server_memory"
+        };
+        vec![
+            (format!("{prefix}_heap_initial__size"), heap.to_string()),
+            (format!("{prefix}_heap_max__size"), heap.to_string()),
+            (format!("{prefix}_pagecache_size"), pagecache.to_string()),
+        ]
+    }
+
+    fn is_before_v5(&self) -> bool {
+        self.version
+            .split('.')
+            .next()
+            .and_then(|major| major.parse::<u32>().ok())
+            .is_some_and(|major| major < 5)
     }
 
     fn conf_env(&self) -> impl IntoIterator<Item = (String, String)> {
@@ -374,6 +436,10 @@ impl Neo4j {
             env_vars.insert(key, value);
         }
 
+        for (key, value) in self.memory_env() {
+            env_vars.insert(key, value);
+        }
+
         let auth = self
             .user
             .and_then(|user| self.pass.map(|pass| (user.into_owned(), pass.into_owned())));
@@ -480,10 +546,7 @@ mod tests {
         let neo4j = Neo4j::new()
             .with_neo4j_labs_plugin(&[Neo4jLabsPlugin::Apoc])
             .build();
-        assert_eq!(
-            neo4j.env_vars.get("NEO4JLABS_PLUGINS").unwrap(),
-            "[\"apoc\"]"
-        );
+        assert_eq!(neo4j.env_vars.get("NEO4J_PLUGINS").unwrap(), "[\"apoc\"]");
     }
 
     #[test]
@@ -492,7 +555,7 @@ mod tests {
             .with_neo4j_labs_plugin(&[Neo4jLabsPlugin::Apoc, Neo4jLabsPlugin::Bloom])
             .build();
         assert_eq!(
-            neo4j.env_vars.get("NEO4JLABS_PLUGINS").unwrap(),
+            neo4j.env_vars.get("NEO4J_PLUGINS").unwrap(),
             "[\"apoc\",\"bloom\"]"
         );
     }
@@ -505,11 +568,84 @@ mod tests {
             .with_neo4j_labs_plugin(&[Neo4jLabsPlugin::Apoc])
             .build();
         assert_eq!(
-            neo4j.env_vars.get("NEO4JLABS_PLUGINS").unwrap(),
+            neo4j.env_vars.get("NEO4J_PLUGINS").unwrap(),
             "[\"apoc\",\"bloom\"]"
         );
     }
 
+    #[test]
+    fn plugin_definition_before_v5() {
+        let neo4j = Neo4j::new()
+            .with_version("4.4")
+            .with_plugins(&[Neo4jPlugin::Apoc, Neo4jPlugin::GraphDataScience])
+            .build();
+        assert_eq!(
+            neo4j.env_vars.get("NEO4JLABS_PLUGINS").unwrap(),
+            "[\"apoc\",\"graph-data-science\"]"
+        );
+        assert!(!neo4j.env_vars.contains_key("NEO4J_PLUGINS"));
+    }
+
+    #[test]
+    fn memory_limits() {
+        let neo4j = Neo4j::new().with_memory_limits("512m", "256m").build();
+        assert_eq!(
+            neo4j
+                .env_vars
+                .get("NEO4J_server_memory_heap_max__size")
+                .unwrap(),
+            "512m"
+        );
+        assert_eq!(
+            neo4j
+                .env_vars
+                .get("NEO4J_server_memory_pagecache_size")
+                .unwrap(),
+            "256m"
+        );
+
+        let neo4j = Neo4j::new()
+            .with_version("4.4")
+            .with_memory_limits("512m", "256m")
+            .build();
+        assert_eq!(
+            neo4j
+                .env_vars
+                .get("NEO4J_dbms_memory_heap_initial__size")
+                .unwrap(),
+            "512m"
+        );
+    }
+
+    #[tokio::test]
+    async fn with_apoc_plugin() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let container = Neo4j::default()
+            .with_plugins(&[Neo4jPlugin::Apoc])
+            .with_memory_limits("512m", "128m")
+            .start()
+            .await?;
+
+        let uri = format!(
+            "bolt://{}:{}",
+            container.get_host().await?,
+            container.image().bolt_port_ipv4()?
+        );
+        let auth_user = container.image().user().expect("default user");
+        let auth_pass = container.image().password().expect("default password");
+
+        let graph = Graph::new(uri, auth_user, auth_pass).await.unwrap();
+        let mut result = graph
+            .execute(neo4rs::query(
+                "RETURN apoc.text.capitalize('neo4j') AS value",
+            ))
+            .await
+            .unwrap();
+        let row = result.next().await.unwrap().unwrap();
+        let value: String = row.get("value").unwrap();
+        assert_eq!("Neo4j", value);
+        Ok(())
+    }
+
     #[tokio::test]
     async fn it_works() -> Result<(), Box<dyn std::error::Error + 'static>> {
         let container = Neo4j::default().start().await?;
@@ -0,0 +1,154 @@
+use std::{borrow::Cow, collections::BTreeMap};
+
+use testcontainers::{
+    core::{wait::HttpWaitStrategy, ContainerPort, WaitFor},
+    Image,
+};
+
+const NAME: &str = "ghcr.io/projectnessie/nessie";
+const TAG: &str = "0.100.0";
+
+/// Port of the Nessie and Iceberg REST APIs that the [`Nessie`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Nessie`]: https://projectnessie.org/
+pub const NESSIE_PORT: ContainerPort = ContainerPort::Tcp(19120);
+
+/// Port of the management API (health checks, metrics) that the [`Nessie`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Nessie`]: https://projectnessie.org/
+pub const NESSIE_MANAGEMENT_PORT: ContainerPort = ContainerPort::Tcp(9000);
+
+/// Module to work with [`Nessie`] inside of tests.
+///
+/// Starts an instance of Nessie based on the official [`Nessie docker image`], serving the Nessie API
+/// (`/api/v2`) and the [`Iceberg REST catalog`] API (`/iceberg`) on port 19120 ([`NESSIE_PORT`]).
+///
+/// The catalog is stored in memory and authentication is disabled by default, see
+/// [`Nessie::with_oidc_authentication`]. For the Iceberg REST API a default warehouse has to be configured
+/// with [`Nessie::with_warehouse`], e.g. an S3 bucket of another container in the same network.
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{
+///     nessie::{Nessie, NESSIE_PORT},
+///     testcontainers::runners::SyncRunner,
+/// };
+///
+/// let nessie_instance = Nessie::default().start().unwrap();
+/// let iceberg_catalog_uri = format!(
+///     "http://{}:{}/iceberg",
+///     nessie_instance.get_host().unwrap(),
+///     nessie_instance.get_host_port_ipv4(NESSIE_PORT).unwrap()
+/// );
+/// ```
+///
+/// [`Nessie`]: https://projectnessie.org/
+/// [`Nessie docker image`]: https://github.com/projectnessie/nessie/pkgs/container/nessie
+/// [`Iceberg REST catalog`]: https://iceberg.apache.org/concepts/catalog/
+#[derive(Debug, Clone)]
+pub struct Nessie {
+    env_vars: BTreeMap<String, String>,
+}
+
+impl Default for Nessie {
+    fn default() -> Self {
+        let mut env_vars = BTreeMap::new();
+        env_vars.insert(
+            "NESSIE_VERSION_STORE_TYPE".to_owned(),
+            "IN_MEMORY".to_owned(),
+        );
+        Self { env_vars }
+    }
+}
+
+impl Nessie {
+    /// Registers a warehouse with the given location (e.g. `s3://bucket/warehouse`) and uses it as default
+    /// warehouse of the Iceberg REST API.
+    pub fn with_warehouse(mut self, name: &str, location: &str) -> Self {
+        self.env_vars.insert(
+            "NESSIE_CATALOG_DEFAULT_WAREHOUSE".to_owned(),
+            name.to_owned(),
+        );
+        self.env_vars.insert(
+            format!("NESSIE_CATALOG_WAREHOUSES_{}_LOCATION", name.to_uppercase()),
+            location.to_owned(),
+        );
+        self
+    }
+
+    /// Enables authentication (`NESSIE_SERVER_AUTHENTICATION_ENABLED`), clients have to send a bearer token
+    /// issued by the OpenID Connect server at `auth_server_url` (e.g. a Keycloak realm) for the given client id.
+    ///
+    /// The url has to be reachable from inside of the Nessie container, e.g. by starting
+    /// both containers in the same network (see [`testcontainers::core::ImageExt::with_network`]).
+    pub fn with_oidc_authentication(mut self, auth_server_url: &str, client_id: &str) -> Self {
+        self.env_vars.insert(
+            "NESSIE_SERVER_AUTHENTICATION_ENABLED".to_owned(),
+            "true".to_owned(),
+        );
+        self.env_vars.insert(
+            "QUARKUS_OIDC_AUTH_SERVER_URL".to_owned(),
+            auth_server_url.to_owned(),
+        );
+        self.env_vars
+            .insert("QUARKUS_OIDC_CLIENT_ID".to_owned(), client_id.to_owned());
+        self
+    }
+}
+
+impl Image for Nessie {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn tag(&self) -> &str {
+        TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![WaitFor::http(
+            HttpWaitStrategy::new("/q/health/ready")
+                .with_port(NESSIE_MANAGEMENT_PORT)
+                .with_expected_status_code(200_u16),
+        )]
+    }
+
+    fn env_vars(
+        &self,
+    ) -> impl IntoIterator<Item = (impl Into<Cow<'_, str>>, impl Into<Cow<'_, str>>)> {
+        &self.env_vars
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[NESSIE_PORT, NESSIE_MANAGEMENT_PORT]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testcontainers::runners::SyncRunner;
+
+    #[test]
+    fn nessie_config() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = Nessie::default()
+            .with_warehouse("warehouse", "file:///tmp/warehouse")
+            .start()?;
+        let url = format!(
+            "http://{}:{}",
+            node.get_host()?,
+            node.get_host_port_ipv4(NESSIE_PORT)?
+        );
+
+        let config: serde_json::Value =
+            reqwest::blocking::get(format!("{url}/api/v2/config"))?.json()?;
+        assert_eq!(config["defaultBranch"], "main");
+
+        let response = reqwest::blocking::get(format!("{url}/iceberg/v1/config"))?;
+        assert!(response.status().is_success());
+        Ok(())
+    }
+}
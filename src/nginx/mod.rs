@@ -0,0 +1,214 @@
+use testcontainers::{
+    core::{wait::HttpWaitStrategy, ContainerPort, WaitFor},
+    CopyDataSource, CopyToContainer, Image,
+};
+
+const NAME: &str = "nginx";
+const TAG: &str = "1.27-alpine";
+const CONFIG_FILE_PATH: &str = "/etc/nginx/nginx.conf";
+const HTML_DIR: &str = "/usr/share/nginx/html";
+#[cfg(feature = "tls")]
+const TLS_DIR: &str = "/etc/nginx/certs";
+#[cfg(feature = "tls")]
+const TLS_CONFIG_PATH: &str = "/etc/nginx/conf.d/testcontainers-tls.conf";
+/// Serves the default site on port 443 as well, included by the default `nginx.conf` of the image.
+#[cfg(feature = "tls")]
+const TLS_CONFIG: &str = r#"server {
+    listen 443 ssl;
+    server_name _;
+    ssl_certificate /etc/nginx/certs/server.crt;
+    ssl_certificate_key /etc/nginx/certs/server.key;
+    location / {
+        root /usr/share/nginx/html;
+        index index.html;
+    }
+}
+"#;
+
+/// Port of the HTTP server that the [`Nginx`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Nginx`]: https://nginx.org/
+pub const NGINX_HTTP_PORT: ContainerPort = ContainerPort::Tcp(80);
+
+/// Port for HTTPS, if configured with a custom configuration, that the [`Nginx`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Nginx`]: https://nginx.org/
+pub const NGINX_HTTPS_PORT: ContainerPort = ContainerPort::Tcp(443);
+
+/// Module to work with [`Nginx`] inside of tests.
+///
+/// Starts an instance of Nginx based on the official [`Nginx docker image`], serving
+/// `/usr/share/nginx/html` on port 80 ([`NGINX_HTTP_PORT`]) with the default configuration.
+///
+/// Use [`Nginx::with_config`] to set up e.g. a reverse proxy or TLS termination on port 443 ([`NGINX_HTTPS_PORT`]),
+/// certificates and other files can be copied with [`testcontainers::core::ImageExt::with_copy_to`].
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{
+///     nginx::{Nginx, NGINX_HTTP_PORT},
+///     testcontainers::runners::SyncRunner,
+/// };
+///
+/// let nginx_instance = Nginx::default()
+///     .with_html("<h1>Hello</h1>".to_string().into_bytes())
+///     .start()
+///     .unwrap();
+/// let url = format!(
+///     "http://{}:{}/",
+///     nginx_instance.get_host().unwrap(),
+///     nginx_instance.get_host_port_ipv4(NGINX_HTTP_PORT).unwrap()
+/// );
+/// ```
+///
+/// [`Nginx`]: https://nginx.org/
+/// [`Nginx docker image`]: https://hub.docker.com/_/nginx
+#[derive(Debug, Clone, Default)]
+pub struct Nginx {
+    config_file: Option<CopyToContainer>,
+    html: Option<CopyToContainer>,
+    tls: Vec<CopyToContainer>,
+}
+
+impl Nginx {
+    /// Registers a custom `nginx.conf`, replacing `/etc/nginx/nginx.conf`.
+    ///
+    /// The configuration should keep listening on port 80, as the container is considered ready
+    /// once it responds to HTTP requests on that port (regardless of the status code).
+    pub fn with_config(mut self, config: impl Into<CopyDataSource>) -> Self {
+        self.config_file = Some(CopyToContainer::new(config.into(), CONFIG_FILE_PATH));
+        self
+    }
+
+    /// Sets the content of the default site, copied to `/usr/share/nginx/html/index.html`.
+    pub fn with_html(mut self, html: impl Into<CopyDataSource>) -> Self {
+        self.html = Some(CopyToContainer::new(
+            html.into(),
+            format!("{HTML_DIR}/index.html"),
+        ));
+        self
+    }
+
+    /// Serves the default site via HTTPS on port 443 ([`NGINX_HTTPS_PORT`]), using the server certificate
+    /// and key of the given [`TlsCertificates`]. Clients can verify the server with [`TlsCertificates::ca_cert_pem`].
+    ///
+    /// The certificate and key are copied to `/etc/nginx/certs/server.crt` and `/etc/nginx/certs/server.key`.
+    /// The server block is placed in `/etc/nginx/conf.d/`, thus a custom configuration
+    /// (see [`Nginx::with_config`]) has to include that directory to keep using it.
+    ///
+    /// [`TlsCertificates`]: crate::tls::TlsCertificates
+    /// [`TlsCertificates::ca_cert_pem`]: crate::tls::TlsCertificates::ca_cert_pem
+    #[cfg(feature = "tls")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "tls")))]
+    pub fn with_tls(mut self, certificates: &crate::tls::TlsCertificates) -> Self {
+        self.tls = vec![
+            certificates.copy_cert_to(format!("{TLS_DIR}/server.crt")),
+            certificates.copy_key_to(format!("{TLS_DIR}/server.key")),
+            CopyToContainer::new(TLS_CONFIG.to_string().into_bytes(), TLS_CONFIG_PATH),
+        ];
+        self
+    }
+}
+
+impl Image for Nginx {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn tag(&self) -> &str {
+        TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![WaitFor::http(
+            HttpWaitStrategy::new("/")
+                .with_port(NGINX_HTTP_PORT)
+                .with_response_matcher(|_| true),
+        )]
+    }
+
+    fn copy_to_sources(&self) -> impl IntoIterator<Item = &CopyToContainer> {
+        self.config_file.iter().chain(&self.html).chain(&self.tls)
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[NGINX_HTTP_PORT, NGINX_HTTPS_PORT]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testcontainers::runners::SyncRunner;
+
+    #[test]
+    fn nginx_serve_html() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = Nginx::default()
+            .with_html("<h1>testcontainers</h1>".to_string().into_bytes())
+            .start()?;
+        let url = format!(
+            "http://{}:{}/",
+            node.get_host()?,
+            node.get_host_port_ipv4(NGINX_HTTP_PORT)?
+        );
+
+        let body = reqwest::blocking::get(url)?.error_for_status()?.text()?;
+        assert_eq!(body, "<h1>testcontainers</h1>");
+        Ok(())
+    }
+
+    #[test]
+    fn nginx_with_config() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = Nginx::default()
+            .with_config(
+                "events {}\n\
+                 http {\n\
+                 \x20 server {\n\
+                 \x20   listen 80;\n\
+                 \x20   location /ping { return 200 'pong'; }\n\
+                 \x20 }\n\
+                 }\n"
+                .to_string()
+                .into_bytes(),
+            )
+            .start()?;
+        let url = format!(
+            "http://{}:{}/ping",
+            node.get_host()?,
+            node.get_host_port_ipv4(NGINX_HTTP_PORT)?
+        );
+
+        let body = reqwest::blocking::get(url)?.error_for_status()?.text()?;
+        assert_eq!(body, "pong");
+        Ok(())
+    }
+
+    #[cfg(feature = "tls")]
+    #[test]
+    fn nginx_with_tls() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let certificates = crate::tls::TlsCertificates::default();
+        let node = Nginx::default()
+            .with_html("<h1>secure</h1>".to_string().into_bytes())
+            .with_tls(&certificates)
+            .start()?;
+        let url = format!(
+            "https://localhost:{}/",
+            node.get_host_port_ipv4(NGINX_HTTPS_PORT)?
+        );
+
+        let client = reqwest::blocking::Client::builder()
+            .use_rustls_tls()
+            .add_root_certificate(reqwest::Certificate::from_pem(
+                certificates.ca_cert_pem().as_bytes(),
+            )?)
+            .build()?;
+        let body = client.get(url).send()?.error_for_status()?.text()?;
+        assert_eq!(body, "<h1>secure</h1>");
+        Ok(())
+    }
+}
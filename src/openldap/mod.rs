@@ -8,8 +8,22 @@ use testcontainers::{
 
 const NAME: &str = "bitnami/openldap";
 const TAG: &str = "2.6.8";
-const OPENLDAP_PORT: ContainerPort = ContainerPort::Tcp(1389);
-const OPENLDAPS_PORT: ContainerPort = ContainerPort::Tcp(1636);
+
+/// Port of the LDAP listener that the [`OpenLDAP`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// The image runs as a non-root user, thus it uses `1389` instead of the well-known port `389`.
+///
+/// [`OpenLDAP`]: https://www.openldap.org/
+pub const OPENLDAP_PORT: ContainerPort = ContainerPort::Tcp(1389);
+
+/// Port of the LDAPS listener that the [`OpenLDAP`] container has internally, see [`OpenLDAP::with_tls`]
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// The image runs as a non-root user, thus it uses `1636` instead of the well-known port `636`.
+///
+/// [`OpenLDAP`]: https://www.openldap.org/
+pub const OPENLDAPS_PORT: ContainerPort = ContainerPort::Tcp(1636);
 
 /// Module to work with [`OpenLDAP`] inside of tests.
 ///
@@ -17,15 +31,24 @@ const OPENLDAPS_PORT: ContainerPort = ContainerPort::Tcp(1636);
 /// This module is based on the [`bitnami/openldap docker image`].
 /// See the [`OpenLDAP configuration guide`] for further configuration options.
 ///
+/// The tree is rooted at the base DN (see [`OpenLDAP::with_base_dn`]) and administrated by the root DN
+/// `cn=<admin username>,<base DN>` (see [`OpenLDAP::with_admin`]).
+/// LDAP clients connect on port 1389 ([`OPENLDAP_PORT`]), or with TLS on port 1636 ([`OPENLDAPS_PORT`]).
+/// The tree can be seeded with users (see [`OpenLDAP::with_user`]), or with LDIF files (see [`OpenLDAP::with_ldif_file`]),
+/// which replace the default tree.
+///
 /// # Example
 /// ```
-/// use testcontainers_modules::{openldap, testcontainers::runners::SyncRunner};
+/// use testcontainers_modules::{
+///     openldap::{self, OPENLDAP_PORT},
+///     testcontainers::runners::SyncRunner,
+/// };
 ///
 /// let openldap_instance = openldap::OpenLDAP::default().start().unwrap();
 /// let connection_string = format!(
 ///     "ldap://{}:{}",
 ///     openldap_instance.get_host().unwrap(),
-///     openldap_instance.get_host_port_ipv4(1389).unwrap(),
+///     openldap_instance.get_host_port_ipv4(OPENLDAP_PORT).unwrap(),
 /// );
 /// let mut conn = ldap3::LdapConn::new(&connection_string).unwrap();
 /// let ldap3::SearchResult(rs, _) = conn
@@ -0,0 +1,206 @@
+use std::{borrow::Cow, collections::BTreeMap};
+
+use testcontainers::{
+    core::{wait::HttpWaitStrategy, ContainerPort, WaitFor},
+    Image,
+};
+
+const NAME: &str = "opensearchproject/opensearch";
+const TAG: &str = "2.18.0";
+const NODE_NAME: &str = "opensearch-node";
+
+/// Port of the REST API that the [`OpenSearch`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`OpenSearch`]: https://opensearch.org/
+pub const OPENSEARCH_PORT: ContainerPort = ContainerPort::Tcp(9200);
+/// Port of the Performance Analyzer that the [`OpenSearch`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`OpenSearch`]: https://opensearch.org/
+pub const OPENSEARCH_PERFORMANCE_ANALYZER_PORT: ContainerPort = ContainerPort::Tcp(9600);
+
+/// Module to work with [`OpenSearch`] inside of tests.
+///
+/// Starts a single node cluster based on the official [`OpenSearch docker image`],
+/// exposing the REST API on port 9200 ([`OPENSEARCH_PORT`]) and the Performance Analyzer
+/// on port 9600 ([`OPENSEARCH_PERFORMANCE_ANALYZER_PORT`]).
+///
+/// The security plugin is disabled by default, thus the REST API is served via plain http without authentication,
+/// and the container is considered ready once the cluster health is at least yellow.
+/// Use [`OpenSearch::with_initial_admin_password`] to enable the security plugin with its demo configuration.
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{
+///     opensearch::{OpenSearch, OPENSEARCH_PORT},
+///     testcontainers::runners::SyncRunner,
+/// };
+///
+/// let opensearch_instance = OpenSearch::default().start().unwrap();
+/// let url = format!(
+///     "http://{}:{}",
+///     opensearch_instance.get_host().unwrap(),
+///     opensearch_instance
+///         .get_host_port_ipv4(OPENSEARCH_PORT)
+///         .unwrap()
+/// );
+/// ```
+///
+/// [`OpenSearch`]: https://opensearch.org/
+/// [`OpenSearch docker image`]: https://hub.docker.com/r/opensearchproject/opensearch
+#[derive(Debug, Clone)]
+pub struct OpenSearch {
+    env_vars: BTreeMap<String, String>,
+    security: bool,
+}
+
+impl Default for OpenSearch {
+    fn default() -> Self {
+        let mut env_vars = BTreeMap::new();
+        env_vars.insert("discovery.type".to_owned(), "single-node".to_owned());
+        env_vars.insert("node.name".to_owned(), NODE_NAME.to_owned());
+        env_vars.insert(
+            "OPENSEARCH_JAVA_OPTS".to_owned(),
+            "-Xms512m -Xmx512m".to_owned(),
+        );
+        Self {
+            env_vars,
+            security: false,
+        }
+    }
+}
+
+impl OpenSearch {
+    /// Enables the security plugin and sets the password of the `admin` user (`OPENSEARCH_INITIAL_ADMIN_PASSWORD`).
+    ///
+    /// The REST API is served via https with the self-signed demo certificates then,
+    /// and the container is considered ready once the cluster health is at least yellow as well.
+    /// The password has to be strong, e.g. `Testcontainers-1`.
+    pub fn with_initial_admin_password(mut self, password: &str) -> Self {
+        self.env_vars.insert(
+            "OPENSEARCH_INITIAL_ADMIN_PASSWORD".to_owned(),
+            password.to_owned(),
+        );
+        self.security = true;
+        self
+    }
+
+    /// Disables the security plugin (`DISABLE_SECURITY_PLUGIN`), which is the default.
+    pub fn with_disable_security(mut self) -> Self {
+        self.env_vars.remove("OPENSEARCH_INITIAL_ADMIN_PASSWORD");
+        self.security = false;
+        self
+    }
+
+    /// Sets the minimum and maximum heap size of the JVM (`OPENSEARCH_JAVA_OPTS`), defaults to `512m`.
+    pub fn with_heap_size(mut self, heap_size: &str) -> Self {
+        self.env_vars.insert(
+            "OPENSEARCH_JAVA_OPTS".to_owned(),
+            format!("-Xms{heap_size} -Xmx{heap_size}"),
+        );
+        self
+    }
+}
+
+impl Image for OpenSearch {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn tag(&self) -> &str {
+        TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        let strategy = HttpWaitStrategy::new("/_cluster/health?wait_for_status=yellow")
+            .with_port(OPENSEARCH_PORT)
+            .with_expected_status_code(200_u16);
+        let strategy = match self.env_vars.get("OPENSEARCH_INITIAL_ADMIN_PASSWORD") {
+            Some(password) if self.security => {
+                // the demo certificates are self-signed
+                let client = reqwest::Client::builder()
+                    .danger_accept_invalid_certs(true)
+                    .build()
+                    .expect("failed to build http client");
+                strategy
+                    .with_tls()
+                    .with_client(client)
+                    .with_basic_auth("admin", password)
+            }
+            _ => strategy,
+        };
+        vec![WaitFor::http(strategy)]
+    }
+
+    fn env_vars(
+        &self,
+    ) -> impl IntoIterator<Item = (impl Into<Cow<'_, str>>, impl Into<Cow<'_, str>>)> {
+        let security_vars = if self.security {
+            vec![]
+        } else {
+            vec![
+                ("DISABLE_SECURITY_PLUGIN", "true"),
+                ("DISABLE_INSTALL_DEMO_CONFIG", "true"),
+            ]
+        };
+        self.env_vars
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+            .chain(security_vars)
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[OPENSEARCH_PORT, OPENSEARCH_PERFORMANCE_ANALYZER_PORT]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testcontainers::runners::SyncRunner;
+
+    #[test]
+    fn opensearch_without_security() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = OpenSearch::default().start()?;
+        let url = format!(
+            "http://{}:{}",
+            node.get_host()?,
+            node.get_host_port_ipv4(OPENSEARCH_PORT)?
+        );
+
+        let info: serde_json::Value = reqwest::blocking::get(url)?.error_for_status()?.json()?;
+        assert_eq!(info["version"]["distribution"], "opensearch");
+        Ok(())
+    }
+
+    #[test]
+    fn opensearch_with_initial_admin_password() -> Result<(), Box<dyn std::error::Error + 'static>>
+    {
+        let _ = pretty_env_logger::try_init();
+        let node = OpenSearch::default()
+            .with_initial_admin_password("Testcontainers-1")
+            .start()?;
+        let url = format!(
+            "https://{}:{}/_cluster/health",
+            node.get_host()?,
+            node.get_host_port_ipv4(OPENSEARCH_PORT)?
+        );
+
+        let client = reqwest::blocking::Client::builder()
+            .danger_accept_invalid_certs(true)
+            .build()?;
+        let response = client.get(&url).send()?;
+        assert_eq!(response.status(), 401);
+
+        let health: serde_json::Value = client
+            .get(&url)
+            .basic_auth("admin", Some("Testcontainers-1"))
+            .send()?
+            .error_for_status()?
+            .json()?;
+        assert_eq!(health["cluster_name"], "docker-cluster");
+        Ok(())
+    }
+}
@@ -1,8 +1,8 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, collections::BTreeMap};
 
 use testcontainers::{
     core::{ContainerPort, WaitFor},
-    Image,
+    CopyDataSource, CopyToContainer, Image,
 };
 
 const DEFAULT_IMAGE_NAME: &str = "gvenzl/oracle-free";
@@ -17,7 +17,9 @@ pub const FREE_PORT: ContainerPort = ContainerPort::Tcp(1521);
 /// The default image is [`gvenzl/oracle-free:23-slim-faststart`] (unofficial).
 /// Official dockerfiles can be found [here][Oracle official dockerfiles].
 ///
-/// The default schema is `test`, with a password `test`.
+/// The default schema is `test`, with a password `test` (see [`Oracle::with_app_user`]).
+/// The password of the `SYS` and `SYSTEM` users is `testsys` (see [`Oracle::with_password`]).
+/// SQL scripts to set up the database are registered with [`Oracle::with_init_sql`].
 ///
 /// NOTE: Currently, there is no Oracle Database Free port for ARM chips,
 /// hence Oracle Database Free images cannot run on the new Apple M chips via Docker Desktop.
@@ -40,12 +42,67 @@ pub const FREE_PORT: ContainerPort = ContainerPort::Tcp(1521);
 /// [`Oracle Database Free`]: https://www.oracle.com/database/free/
 /// [Oracle official dockerfiles]: https://github.com/oracle/docker-images/tree/main/OracleDatabase
 /// [`gvenzl/oracle-free:23-slim-faststart`]: https://hub.docker.com/r/gvenzl/oracle-free
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Clone)]
 pub struct Oracle {
-    /// (remove if there is another variable)
-    /// Field is included to prevent this struct to be a unit struct.
-    /// This allows extending functionality (and thus further variables) without breaking changes
-    _priv: (),
+    env_vars: BTreeMap<String, String>,
+    init_scripts: Vec<CopyToContainer>,
+}
+
+impl Default for Oracle {
+    fn default() -> Self {
+        let mut env_vars = BTreeMap::new();
+        env_vars.insert("ORACLE_PASSWORD".to_owned(), "testsys".to_owned());
+        env_vars.insert("APP_USER".to_owned(), "test".to_owned());
+        env_vars.insert("APP_USER_PASSWORD".to_owned(), "test".to_owned());
+        Self {
+            env_vars,
+            init_scripts: Vec::new(),
+        }
+    }
+}
+
+impl Oracle {
+    /// Sets the password of the `SYS` and `SYSTEM` users (`ORACLE_PASSWORD`), defaults to `testsys`.
+    pub fn with_password(mut self, password: &str) -> Self {
+        self.env_vars
+            .insert("ORACLE_PASSWORD".to_owned(), password.to_owned());
+        self
+    }
+
+    /// Sets the user (and thus schema) created in the pluggable database `FREEPDB1` on startup
+    /// (`APP_USER`, `APP_USER_PASSWORD`), defaults to `test` with the password `test`.
+    pub fn with_app_user(mut self, user: &str, password: &str) -> Self {
+        self.env_vars.insert("APP_USER".to_owned(), user.to_owned());
+        self.env_vars
+            .insert("APP_USER_PASSWORD".to_owned(), password.to_owned());
+        self
+    }
+
+    /// Registers a SQL script, copied to `/container-entrypoint-initdb.d`, which is executed by the `SYS` user
+    /// in the root container on the first startup, after the app user was created.
+    /// Use `ALTER SESSION SET CONTAINER=FREEPDB1;` to work within the pluggable database.
+    /// Can be called multiple times to add (not override) scripts, which are executed in registration order.
+    ///
+    /// # Example
+    /// ```
+    /// use testcontainers_modules::oracle::free::Oracle;
+    ///
+    /// let oracle = Oracle::default().with_init_sql(
+    ///     "ALTER SESSION SET CONTAINER=FREEPDB1;\n\
+    ///      CREATE TABLE test.foo (bar varchar2(255));\n"
+    ///         .to_string()
+    ///         .into_bytes(),
+    /// );
+    /// ```
+    pub fn with_init_sql(mut self, init_sql: impl Into<CopyDataSource>) -> Self {
+        let target = format!(
+            "/container-entrypoint-initdb.d/init_{i}.sql",
+            i = self.init_scripts.len()
+        );
+        self.init_scripts
+            .push(CopyToContainer::new(init_sql.into(), target));
+        self
+    }
 }
 
 impl Image for Oracle {
@@ -64,11 +121,11 @@ impl Image for Oracle {
     fn env_vars(
         &self,
     ) -> impl IntoIterator<Item = (impl Into<Cow<'_, str>>, impl Into<Cow<'_, str>>)> {
-        [
-            ("ORACLE_PASSWORD", "testsys"),
-            ("APP_USER", "test"),
-            ("APP_USER_PASSWORD", "test"),
-        ]
+        &self.env_vars
+    }
+
+    fn copy_to_sources(&self) -> impl IntoIterator<Item = &CopyToContainer> {
+        &self.init_scripts
     }
 
     fn expose_ports(&self) -> &[ContainerPort] {
@@ -106,4 +163,41 @@ mod tests {
         assert_eq!(col, 2);
         Ok(())
     }
+
+    #[test]
+    fn oracle_with_app_user_and_init_sql() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let oracle = Oracle::default()
+            .with_password("secret")
+            .with_app_user("app", "app_password")
+            .with_init_sql(
+                "ALTER SESSION SET CONTAINER=FREEPDB1;\n\
+                 CREATE TABLE app.foo (bar varchar2(255));\n\
+                 INSERT INTO app.foo (bar) VALUES ('blub');\n\
+                 COMMIT;\n"
+                    .to_string()
+                    .into_bytes(),
+            )
+            .pull_image()?
+            .with_startup_timeout(Duration::from_secs(75));
+
+        let node = oracle.start()?;
+
+        let connection_string = format!(
+            "//{}:{}/FREEPDB1",
+            node.get_host()?,
+            node.get_host_port_ipv4(FREE_PORT)?
+        );
+        let conn = oracle::Connection::connect("app", "app_password", &connection_string)?;
+        let mut rows = conn.query("SELECT bar FROM foo", &[])?;
+        let row = rows.next().unwrap()?;
+        let col: String = row.get(0)?;
+        assert_eq!(col, "blub");
+
+        let conn = oracle::Connection::connect("system", "secret", &connection_string)?;
+        let mut rows = conn.query("SELECT 1 + 1 FROM dual", &[])?;
+        let row = rows.next().unwrap()?;
+        let col: i32 = row.get(0)?;
+        assert_eq!(col, 2);
+        Ok(())
+    }
 }
@@ -0,0 +1,220 @@
+use testcontainers::{
+    core::{ContainerPort, WaitFor},
+    CopyDataSource, CopyToContainer, Image,
+};
+
+const NAME: &str = "otel/opentelemetry-collector-contrib";
+const TAG: &str = "0.114.0";
+const CONFIG_FILE_PATH: &str = "/etc/otelcol-contrib/config.yaml";
+
+/// Port of the OTLP gRPC receiver that the [`OpenTelemetry Collector`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`OpenTelemetry Collector`]: https://opentelemetry.io/docs/collector/
+pub const OTLP_GRPC_PORT: ContainerPort = ContainerPort::Tcp(4317);
+
+/// Port of the OTLP HTTP receiver that the [`OpenTelemetry Collector`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`OpenTelemetry Collector`]: https://opentelemetry.io/docs/collector/
+pub const OTLP_HTTP_PORT: ContainerPort = ContainerPort::Tcp(4318);
+
+/// Module to work with the [`OpenTelemetry Collector`] inside of tests.
+///
+/// Starts an instance of the collector based on the official [`OpenTelemetry Collector Contrib docker image`].
+///
+/// The pipelines are defined by a configuration file, which is set with [`OtelCollector::with_config_yaml`].
+/// For simple tests the presets [`OtelCollector::with_debug_export`] and [`OtelCollector::with_file_export`]
+/// generate a configuration, which receives traces, metrics and logs via OTLP on ports 4317 ([`OTLP_GRPC_PORT`])
+/// and 4318 ([`OTLP_HTTP_PORT`]) and exports them to the container log or a file.
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{
+///     otel_collector::{OtelCollector, OTLP_HTTP_PORT},
+///     testcontainers::runners::SyncRunner,
+/// };
+///
+/// let collector_instance = OtelCollector::default()
+///     .with_debug_export()
+///     .start()
+///     .unwrap();
+/// let otlp_endpoint = format!(
+///     "http://{}:{}",
+///     collector_instance.get_host().unwrap(),
+///     collector_instance.get_host_port_ipv4(OTLP_HTTP_PORT).unwrap()
+/// );
+/// ```
+///
+/// [`OpenTelemetry Collector`]: https://opentelemetry.io/docs/collector/
+/// [`OpenTelemetry Collector Contrib docker image`]: https://hub.docker.com/r/otel/opentelemetry-collector-contrib
+#[derive(Debug, Clone, Default)]
+pub struct OtelCollector {
+    config_file: Option<CopyToContainer>,
+    preset_file: Option<CopyToContainer>,
+    debug_export: bool,
+    file_export: Option<String>,
+}
+
+impl OtelCollector {
+    /// Registers the collector configuration, copied to `/etc/otelcol-contrib/config.yaml`.
+    /// Takes precedence over the presets [`OtelCollector::with_debug_export`] and [`OtelCollector::with_file_export`].
+    ///
+    /// # Example
+    /// ```
+    /// use testcontainers_modules::otel_collector::OtelCollector;
+    ///
+    /// let collector = OtelCollector::default().with_config_yaml(
+    ///     "receivers:\n  otlp:\n    protocols:\n      grpc:\n        endpoint: 0.0.0.0:4317\n\
+    ///      exporters:\n  debug: {}\n\
+    ///      service:\n  pipelines:\n    traces:\n      receivers: [otlp]\n      exporters: [debug]\n"
+    ///         .to_string()
+    ///         .into_bytes(),
+    /// );
+    /// ```
+    pub fn with_config_yaml(mut self, config: impl Into<CopyDataSource>) -> Self {
+        self.config_file = Some(CopyToContainer::new(config.into(), CONFIG_FILE_PATH));
+        self
+    }
+
+    /// Preset exporting all received telemetry with the `debug` exporter in detailed verbosity,
+    /// so it can be asserted on in the container log (stderr).
+    pub fn with_debug_export(mut self) -> Self {
+        self.debug_export = true;
+        self.preset_file = self.preset_config();
+        self
+    }
+
+    /// Preset exporting all received telemetry with the `file` exporter as JSON lines to the given path
+    /// inside of the container. As the image does not contain a shell, the directory of the file should be
+    /// mounted, see [`testcontainers::core::ImageExt::with_mount`].
+    pub fn with_file_export(mut self, path: &str) -> Self {
+        self.file_export = Some(path.to_owned());
+        self.preset_file = self.preset_config();
+        self
+    }
+
+    fn preset_config(&self) -> Option<CopyToContainer> {
+        let mut exporters = Vec::new();
+        let mut exporters_config = String::new();
+        if self.debug_export {
+            exporters.push("debug");
+            exporters_config.push_str("  debug:\n    verbosity: detailed\n");
+        }
+        if let Some(path) = &self.file_export {
+            exporters.push("file");
+            exporters_config.push_str(&format!("  file:\n    path: {path}\n"));
+        }
+        if exporters.is_empty() {
+            return None;
+        }
+
+        let exporters = exporters.join(", ");
+        let pipelines: String = ["traces", "metrics", "logs"]
+            .iter()
+            .map(|signal| {
+                format!("    {signal}:\n      receivers: [otlp]\n      exporters: [{exporters}]\n")
+            })
+            .collect();
+        let config = format!(
+            "receivers:\n\
+             \x20 otlp:\n\
+             \x20   protocols:\n\
+             \x20     grpc:\n\
+             \x20       endpoint: 0.0.0.0:{grpc_port}\n\
+             \x20     http:\n\
+             \x20       endpoint: 0.0.0.0:{http_port}\n\
+             exporters:\n\
+             {exporters_config}\
+             service:\n\
+             \x20 pipelines:\n\
+             {pipelines}",
+            grpc_port = OTLP_GRPC_PORT.as_u16(),
+            http_port = OTLP_HTTP_PORT.as_u16(),
+        );
+        Some(CopyToContainer::new(config.into_bytes(), CONFIG_FILE_PATH))
+    }
+}
+
+impl Image for OtelCollector {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn tag(&self) -> &str {
+        TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![WaitFor::message_on_stderr(
+            "Everything is ready. Begin running and processing data.",
+        )]
+    }
+
+    fn copy_to_sources(&self) -> impl IntoIterator<Item = &CopyToContainer> {
+        self.config_file.as_ref().or(self.preset_file.as_ref())
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[OTLP_GRPC_PORT, OTLP_HTTP_PORT]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use retry::{delay::Fixed, retry};
+
+    use super::*;
+    use crate::testcontainers::runners::SyncRunner;
+
+    #[test]
+    fn otel_collector_debug_export() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = OtelCollector::default().with_debug_export().start()?;
+        let url = format!(
+            "http://{}:{}/v1/traces",
+            node.get_host()?,
+            node.get_host_port_ipv4(OTLP_HTTP_PORT)?
+        );
+
+        let trace = serde_json::json!({
+            "resourceSpans": [{
+                "resource": {
+                    "attributes": [{
+                        "key": "service.name",
+                        "value": { "stringValue": "testcontainers" }
+                    }]
+                },
+                "scopeSpans": [{
+                    "spans": [{
+                        "traceId": "5b8efff798038103d269b633813fc60c",
+                        "spanId": "eee19b7ec3c1b174",
+                        "name": "testcontainers-span",
+                        "kind": 1,
+                        "startTimeUnixNano": "1700000000000000000",
+                        "endTimeUnixNano": "1700000001000000000"
+                    }]
+                }]
+            }]
+        });
+        reqwest::blocking::Client::new()
+            .post(url)
+            .json(&trace)
+            .send()?
+            .error_for_status()?;
+
+        retry(Fixed::from(Duration::from_millis(500)).take(20), || {
+            let stderr = String::from_utf8(node.stderr_to_vec().map_err(|err| err.to_string())?)
+                .map_err(|err| err.to_string())?;
+            if stderr.contains("testcontainers-span") {
+                Ok(())
+            } else {
+                Err("span not yet exported".to_owned())
+            }
+        })
+        .map_err(|err| err.to_string())?;
+        Ok(())
+    }
+}
@@ -0,0 +1,172 @@
+use std::{borrow::Cow, collections::BTreeMap};
+
+use testcontainers::{core::WaitFor, CopyDataSource, CopyToContainer, Image};
+
+const NAME: &str = "percona/percona-server";
+const TAG: &str = "8.0";
+const CONFIG_FILE_PATH: &str = "/etc/my.cnf.d/testcontainers.cnf";
+
+/// Module to work with [`Percona Server for MySQL`] inside of tests.
+///
+/// Starts an instance of Percona Server with no password set for the root user and a default database named `test` created.
+///
+/// This module is based on the official [`Percona Server docker image`].
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{percona, testcontainers::runners::SyncRunner};
+///
+/// let percona_instance = percona::Percona::default().start().unwrap();
+/// let mysql_url = format!(
+///     "mysql://root@{}:{}/test",
+///     percona_instance.get_host().unwrap(),
+///     percona_instance.get_host_port_ipv4(3306).unwrap()
+/// );
+/// ```
+///
+/// [`Percona Server for MySQL`]: https://www.percona.com/mysql/software/percona-server-for-mysql
+/// [`Percona Server docker image`]: https://hub.docker.com/r/percona/percona-server
+#[derive(Debug, Clone)]
+pub struct Percona {
+    env_vars: BTreeMap<String, String>,
+    copy_to_sources: Vec<CopyToContainer>,
+    config_file: Option<CopyToContainer>,
+}
+
+impl Default for Percona {
+    fn default() -> Self {
+        let mut env_vars = BTreeMap::new();
+        env_vars.insert("MYSQL_DATABASE".to_owned(), "test".to_owned());
+        env_vars.insert("MYSQL_ALLOW_EMPTY_PASSWORD".to_owned(), "yes".to_owned());
+        Self {
+            env_vars,
+            copy_to_sources: Vec::new(),
+            config_file: None,
+        }
+    }
+}
+
+impl Percona {
+    /// Registers sql to be executed automatically when the container starts.
+    /// Can be called multiple times to add (not override) scripts.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use testcontainers_modules::percona::Percona;
+    /// let percona_image = Percona::default().with_init_sql(
+    ///     "CREATE TABLE foo (bar varchar(255));"
+    ///         .to_string()
+    ///         .into_bytes(),
+    /// );
+    /// ```
+    pub fn with_init_sql(mut self, init_sql: impl Into<CopyDataSource>) -> Self {
+        let target = format!(
+            "/docker-entrypoint-initdb.d/init_{i}.sql",
+            i = self.copy_to_sources.len()
+        );
+        self.copy_to_sources
+            .push(CopyToContainer::new(init_sql.into(), target));
+        self
+    }
+
+    /// Sets the password of the root user (`MYSQL_ROOT_PASSWORD`), instead of allowing an empty password.
+    pub fn with_root_password(mut self, password: &str) -> Self {
+        self.env_vars.remove("MYSQL_ALLOW_EMPTY_PASSWORD");
+        self.env_vars
+            .insert("MYSQL_ROOT_PASSWORD".to_owned(), password.to_owned());
+        self
+    }
+
+    /// Sets the name of the database created on startup (`MYSQL_DATABASE`), defaults to `test`.
+    pub fn with_database(mut self, database: &str) -> Self {
+        self.env_vars
+            .insert("MYSQL_DATABASE".to_owned(), database.to_owned());
+        self
+    }
+
+    /// Sets the name of an additional user (`MYSQL_USER`) created on startup,
+    /// which is granted all privileges on the database. Has to be combined with [`Percona::with_password`].
+    pub fn with_user(mut self, user: &str) -> Self {
+        self.env_vars
+            .insert("MYSQL_USER".to_owned(), user.to_owned());
+        self
+    }
+
+    /// Sets the password of the additional user (`MYSQL_PASSWORD`), see [`Percona::with_user`].
+    pub fn with_password(mut self, password: &str) -> Self {
+        self.env_vars
+            .insert("MYSQL_PASSWORD".to_owned(), password.to_owned());
+        self
+    }
+
+    /// Registers a custom `my.cnf` to be used by the Percona Server instance.
+    ///
+    /// The file is copied to `/etc/my.cnf.d/`, thus its options take precedence over the defaults of the image.
+    pub fn with_config_file(mut self, config_file: impl Into<CopyDataSource>) -> Self {
+        self.config_file = Some(CopyToContainer::new(config_file.into(), CONFIG_FILE_PATH));
+        self
+    }
+}
+
+impl Image for Percona {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn tag(&self) -> &str {
+        TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![
+            WaitFor::message_on_stderr("X Plugin ready for connections. Bind-address"),
+            WaitFor::message_on_stderr("/usr/sbin/mysqld: ready for connections."),
+        ]
+    }
+
+    fn env_vars(
+        &self,
+    ) -> impl IntoIterator<Item = (impl Into<Cow<'_, str>>, impl Into<Cow<'_, str>>)> {
+        &self.env_vars
+    }
+
+    fn copy_to_sources(&self) -> impl IntoIterator<Item = &CopyToContainer> {
+        self.copy_to_sources.iter().chain(&self.config_file)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mysql::prelude::Queryable;
+
+    use crate::{percona::Percona, testcontainers::runners::SyncRunner};
+
+    #[test]
+    fn percona_with_user_and_init_sql() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let node = Percona::default()
+            .with_database("custom_db")
+            .with_user("custom_user")
+            .with_password("custom_password")
+            .with_init_sql(
+                "CREATE TABLE foo (bar varchar(255));"
+                    .to_string()
+                    .into_bytes(),
+            )
+            .start()?;
+
+        let connection_string = &format!(
+            "mysql://custom_user:custom_password@{}:{}/custom_db",
+            node.get_host()?,
+            node.get_host_port_ipv4(3306)?
+        );
+        let mut conn = mysql::Conn::new(mysql::Opts::from_url(connection_string).unwrap()).unwrap();
+
+        let rows: Vec<String> = conn.query("SELECT bar FROM foo").unwrap();
+        assert_eq!(rows.len(), 0);
+
+        let version_comment: Option<String> = conn.query_first("SELECT @@version_comment").unwrap();
+        assert!(version_comment.unwrap().contains("Percona"));
+        Ok(())
+    }
+}
@@ -0,0 +1,199 @@
+use std::{borrow::Cow, fmt::Display};
+
+use testcontainers::{core::WaitFor, CopyDataSource, CopyToContainer, Image};
+
+use super::postgres::Postgres;
+
+const NAME: &str = "pgvector/pgvector";
+const TAG: &str = "0.8.0-pg17";
+
+/// Module to work with [`pgvector`] inside of tests.
+///
+/// Starts an instance of Postgres with the pgvector extension installed and created
+/// in the configured database.
+/// This module is based on the official [`pgvector docker image`] and shares the
+/// configuration of the [`Postgres`] module.
+///
+/// Default db name, user and password is `postgres`.
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{pgvector, testcontainers::runners::SyncRunner};
+///
+/// let pgvector_instance = pgvector::Pgvector::default()
+///     .with_init_sql(
+///         "CREATE TABLE items (id bigserial PRIMARY KEY, embedding vector(3));"
+///             .to_string()
+///             .into_bytes(),
+///     )
+///     .start()
+///     .unwrap();
+///
+/// let connection_string = pgvector_instance.image().connection_string(
+///     pgvector_instance.get_host().unwrap(),
+///     pgvector_instance.get_host_port_ipv4(5432).unwrap(),
+/// );
+/// ```
+///
+/// [`pgvector`]: https://github.com/pgvector/pgvector
+/// [`pgvector docker image`]: https://hub.docker.com/r/pgvector/pgvector
+#[derive(Debug, Clone)]
+pub struct Pgvector(Postgres);
+
+impl Pgvector {
+    /// Enables the Postgres instance to be used without authentication on host.
+    /// For more information see the description of `POSTGRES_HOST_AUTH_METHOD` in official [docker image](https://hub.docker.com/_/postgres)
+    pub fn with_host_auth(self) -> Self {
+        Self(self.0.with_host_auth())
+    }
+
+    /// Sets the db name for the Postgres instance.
+    pub fn with_db_name(self, db_name: &str) -> Self {
+        Self(self.0.with_db_name(db_name))
+    }
+
+    /// Sets the user for the Postgres instance.
+    pub fn with_user(self, user: &str) -> Self {
+        Self(self.0.with_user(user))
+    }
+
+    /// Sets the password for the Postgres instance.
+    pub fn with_password(self, password: &str) -> Self {
+        Self(self.0.with_password(password))
+    }
+
+    /// Registers sql to be executed automatically when the container starts.
+    /// Can be called multiple times to add (not override) scripts.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use testcontainers_modules::pgvector::Pgvector;
+    /// let pgvector_image = Pgvector::default().with_init_sql(
+    ///     "CREATE TABLE items (id bigserial PRIMARY KEY, embedding vector(3));"
+    ///         .to_string()
+    ///         .into_bytes(),
+    /// );
+    /// ```
+    ///
+    /// ```rust,ignore
+    /// # use testcontainers_modules::pgvector::Pgvector;
+    /// let pgvector_image = Pgvector::default()
+    ///                                .with_init_sql(include_str!("path_to_init.sql").to_string().into_bytes());
+    /// ```
+    pub fn with_init_sql(self, init_sql: impl Into<CopyDataSource>) -> Self {
+        Self(self.0.with_init_sql(init_sql))
+    }
+
+    /// Enables [the fsync-setting](https://www.postgresql.org/docs/current/runtime-config-wal.html#GUC-FSYNC) for the Postgres instance.
+    pub fn with_fsync_enabled(self) -> Self {
+        Self(self.0.with_fsync_enabled())
+    }
+
+    /// Sets a [configuration parameter](https://www.postgresql.org/docs/current/runtime-config.html)
+    /// for the Postgres instance, by passing `-c key=value` to the server command.
+    ///
+    /// See [`Postgres::with_config_param`] for details.
+    pub fn with_config_param(self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        Self(self.0.with_config_param(key, value))
+    }
+
+    /// Registers a custom `postgresql.conf` to be used by the Postgres instance.
+    ///
+    /// See [`Postgres::with_config_file`] for details.
+    pub fn with_config_file(self, config_file: impl Into<CopyDataSource>) -> Self {
+        Self(self.0.with_config_file(config_file))
+    }
+
+    /// Configures the Postgres instance for logical replication.
+    ///
+    /// See [`Postgres::with_logical_replication`] for details.
+    pub fn with_logical_replication(self) -> Self {
+        Self(self.0.with_logical_replication())
+    }
+
+    /// Returns a `postgres://` connection url for the configured user, password and db name,
+    /// pointing to the given host and (host-mapped) port of the started container.
+    ///
+    /// See [`Postgres::connection_string`] for details.
+    pub fn connection_string(&self, host: impl Display, port: u16) -> String {
+        self.0.connection_string(host, port)
+    }
+}
+
+impl Default for Pgvector {
+    fn default() -> Self {
+        Self(
+            Postgres::default().with_init_sql(
+                "CREATE EXTENSION IF NOT EXISTS vector;"
+                    .to_string()
+                    .into_bytes(),
+            ),
+        )
+    }
+}
+
+impl Image for Pgvector {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn tag(&self) -> &str {
+        TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        self.0.ready_conditions()
+    }
+
+    fn env_vars(
+        &self,
+    ) -> impl IntoIterator<Item = (impl Into<Cow<'_, str>>, impl Into<Cow<'_, str>>)> {
+        self.0.env_vars()
+    }
+
+    fn copy_to_sources(&self) -> impl IntoIterator<Item = &CopyToContainer> {
+        self.0.copy_to_sources()
+    }
+
+    fn cmd(&self) -> impl IntoIterator<Item = impl Into<std::borrow::Cow<'_, str>>> {
+        self.0.cmd()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use testcontainers::runners::SyncRunner;
+
+    use super::*;
+
+    #[test]
+    fn pgvector_nearest_neighbor() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = Pgvector::default()
+            .with_init_sql(
+                "CREATE TABLE items (id bigserial PRIMARY KEY, embedding vector(3));\n\
+                 INSERT INTO items (embedding) VALUES ('[1,2,3]'), ('[4,5,6]');"
+                    .to_string()
+                    .into_bytes(),
+            )
+            .start()?;
+
+        let connection_string = node
+            .image()
+            .connection_string(node.get_host()?, node.get_host_port_ipv4(5432)?);
+        let mut conn = postgres::Client::connect(&connection_string, postgres::NoTls).unwrap();
+
+        let rows = conn
+            .query(
+                "SELECT id FROM items ORDER BY embedding <-> '[3,1,2]' LIMIT 1",
+                &[],
+            )
+            .unwrap();
+        assert_eq!(rows.len(), 1);
+
+        let id: i64 = rows[0].get(0);
+        assert_eq!(id, 1);
+        Ok(())
+    }
+}
@@ -1,33 +1,59 @@
-use std::{borrow::Cow, collections::HashMap};
+use std::{borrow::Cow, fmt::Display};
 
 use testcontainers::{core::WaitFor, CopyDataSource, CopyToContainer, Image};
+
 use super::postgres::Postgres;
 
 const NAME: &str = "postgis/postgis";
 const TAG: &str = "17-3.5";
 
+/// Module to work with [`PostGIS`] inside of tests.
+///
+/// Starts an instance of Postgres with the PostGIS extensions installed.
+/// This module is based on the official [`PostGIS docker image`] and shares the
+/// configuration of the [`Postgres`] module.
+///
+/// Default db name, user and password is `postgres`.
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{postgis, testcontainers::runners::SyncRunner};
+///
+/// let postgis_instance = postgis::Postgis::default()
+///     .with_extensions(&["postgis_topology", "postgis_raster"])
+///     .start()
+///     .unwrap();
+///
+/// let connection_string = postgis_instance.image().connection_string(
+///     postgis_instance.get_host().unwrap(),
+///     postgis_instance.get_host_port_ipv4(5432).unwrap(),
+/// );
+/// ```
+///
+/// [`PostGIS`]: https://postgis.net/
+/// [`PostGIS docker image`]: https://hub.docker.com/r/postgis/postgis
 #[derive(Debug, Clone, Default)]
 pub struct Postgis(Postgres);
 
 impl Postgis {
     /// Enables the Postgres instance to be used without authentication on host.
     /// For more information see the description of `POSTGRES_HOST_AUTH_METHOD` in official [docker image](https://hub.docker.com/_/postgres)
-    pub fn with_host_auth(mut self) -> Self {
+    pub fn with_host_auth(self) -> Self {
         Self(self.0.with_host_auth())
     }
 
     /// Sets the db name for the Postgres instance.
-    pub fn with_db_name(mut self, db_name: &str) -> Self {
+    pub fn with_db_name(self, db_name: &str) -> Self {
         Self(self.0.with_db_name(db_name))
     }
 
     /// Sets the user for the Postgres instance.
-    pub fn with_user(mut self, user: &str) -> Self {
+    pub fn with_user(self, user: &str) -> Self {
         Self(self.0.with_user(user))
     }
 
     /// Sets the password for the Postgres instance.
-    pub fn with_password(mut self, password: &str) -> Self {
+    pub fn with_password(self, password: &str) -> Self {
         Self(self.0.with_password(password))
     }
 
@@ -37,27 +63,78 @@ impl Postgis {
     /// # Example
     ///
     /// ```
-    /// # use testcontainers_modules::postgres::Postgres;
-    /// let postgres_image = Postgres::default().with_init_sql(
-    ///     "CREATE EXTENSION IF NOT EXISTS hstore;"
+    /// # use testcontainers_modules::postgis::Postgis;
+    /// let postgis_image = Postgis::default().with_init_sql(
+    ///     "CREATE TABLE places (id serial primary key, geom geometry(Point, 4326));"
     ///         .to_string()
     ///         .into_bytes(),
     /// );
     /// ```
     ///
     /// ```rust,ignore
-    /// # use testcontainers_modules::postgres::Postgres;
-    /// let postgres_image = Postgres::default()
+    /// # use testcontainers_modules::postgis::Postgis;
+    /// let postgis_image = Postgis::default()
     ///                                .with_init_sql(include_str!("path_to_init.sql").to_string().into_bytes());
     /// ```
-    pub fn with_init_sql(mut self, init_sql: impl Into<CopyDataSource>) -> Self {
+    pub fn with_init_sql(self, init_sql: impl Into<CopyDataSource>) -> Self {
         Self(self.0.with_init_sql(init_sql))
     }
 
+    /// Creates the given PostGIS related extensions (e.g. `postgis_topology`, `postgis_raster`
+    /// or `pgrouting`) when the container starts.
+    ///
+    /// The `CREATE EXTENSION` statements are registered as init sql (see [`Postgis::with_init_sql`]),
+    /// so they are executed against the configured database. Dependencies of an extension are
+    /// created as well. Note that the extensions have to be available in the used image.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use testcontainers_modules::postgis::Postgis;
+    /// let postgis_image = Postgis::default().with_extensions(&["postgis_topology", "postgis_raster"]);
+    /// ```
+    pub fn with_extensions(self, extensions: &[&str]) -> Self {
+        let init_sql = extensions
+            .iter()
+            .map(|extension| format!("CREATE EXTENSION IF NOT EXISTS \"{extension}\" CASCADE;\n"))
+            .collect::<String>();
+        self.with_init_sql(init_sql.into_bytes())
+    }
+
     /// Enables [the fsync-setting](https://www.postgresql.org/docs/current/runtime-config-wal.html#GUC-FSYNC) for the Postgres instance.
-    pub fn with_fsync_enabled(mut self) -> Self {
+    pub fn with_fsync_enabled(self) -> Self {
         Self(self.0.with_fsync_enabled())
     }
+
+    /// Sets a [configuration parameter](https://www.postgresql.org/docs/current/runtime-config.html)
+    /// for the Postgres instance, by passing `-c key=value` to the server command.
+    ///
+    /// See [`Postgres::with_config_param`] for details.
+    pub fn with_config_param(self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        Self(self.0.with_config_param(key, value))
+    }
+
+    /// Registers a custom `postgresql.conf` to be used by the Postgres instance.
+    ///
+    /// See [`Postgres::with_config_file`] for details.
+    pub fn with_config_file(self, config_file: impl Into<CopyDataSource>) -> Self {
+        Self(self.0.with_config_file(config_file))
+    }
+
+    /// Configures the Postgres instance for logical replication.
+    ///
+    /// See [`Postgres::with_logical_replication`] for details.
+    pub fn with_logical_replication(self) -> Self {
+        Self(self.0.with_logical_replication())
+    }
+
+    /// Returns a `postgres://` connection url for the configured user, password and db name,
+    /// pointing to the given host and (host-mapped) port of the started container.
+    ///
+    /// See [`Postgres::connection_string`] for details.
+    pub fn connection_string(&self, host: impl Display, port: u16) -> String {
+        self.0.connection_string(host, port)
+    }
 }
 
 impl Image for Postgis {
@@ -87,3 +164,32 @@ impl Image for Postgis {
         self.0.cmd()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use testcontainers::runners::SyncRunner;
+
+    use super::*;
+
+    #[test]
+    fn postgis_with_extensions() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = Postgis::default()
+            .with_extensions(&["postgis_topology", "postgis_raster"])
+            .start()?;
+
+        let connection_string = node
+            .image()
+            .connection_string(node.get_host()?, node.get_host_port_ipv4(5432)?);
+        let mut conn = postgres::Client::connect(&connection_string, postgres::NoTls).unwrap();
+
+        let rows = conn
+            .query(
+                "SELECT extname FROM pg_extension WHERE extname IN ('postgis_topology', 'postgis_raster')",
+                &[],
+            )
+            .unwrap();
+        assert_eq!(rows.len(), 2);
+        Ok(())
+    }
+}
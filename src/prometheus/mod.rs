@@ -0,0 +1,151 @@
+use std::borrow::Cow;
+
+use testcontainers::{
+    core::{ContainerPort, WaitFor},
+    CopyDataSource, CopyToContainer, Image,
+};
+
+const NAME: &str = "prom/prometheus";
+const TAG: &str = "v3.0.1";
+const CONFIG_FILE_PATH: &str = "/etc/prometheus/prometheus.yml";
+
+/// Port of the HTTP API and web UI that the [`Prometheus`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Prometheus`]: https://prometheus.io/
+pub const PROMETHEUS_PORT: ContainerPort = ContainerPort::Tcp(9090);
+
+/// Module to work with [`Prometheus`] inside of tests.
+///
+/// Starts an instance of Prometheus based on the official [`Prometheus docker image`],
+/// serving the HTTP API and web UI on port 9090 ([`PROMETHEUS_PORT`]).
+///
+/// Without a custom configuration (see [`Prometheus::with_config_yaml`]),
+/// the default configuration of the image is used, which scrapes Prometheus itself.
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{
+///     prometheus::{Prometheus, PROMETHEUS_PORT},
+///     testcontainers::runners::SyncRunner,
+/// };
+///
+/// let prometheus_instance = Prometheus::default()
+///     .with_feature("exemplar-storage")
+///     .start()
+///     .unwrap();
+/// let url = format!(
+///     "http://{}:{}",
+///     prometheus_instance.get_host().unwrap(),
+///     prometheus_instance
+///         .get_host_port_ipv4(PROMETHEUS_PORT)
+///         .unwrap()
+/// );
+/// ```
+///
+/// [`Prometheus`]: https://prometheus.io/
+/// [`Prometheus docker image`]: https://hub.docker.com/r/prom/prometheus
+#[derive(Debug, Clone, Default)]
+pub struct Prometheus {
+    config_file: Option<CopyToContainer>,
+    features: Vec<String>,
+}
+
+impl Prometheus {
+    /// Registers a custom `prometheus.yml`, e.g. to configure scrape targets.
+    ///
+    /// # Example
+    /// ```
+    /// use testcontainers_modules::prometheus::Prometheus;
+    ///
+    /// let prometheus = Prometheus::default().with_config_yaml(
+    ///     "scrape_configs:\n  - job_name: app\n    static_configs:\n      - targets: ['app:8080']\n"
+    ///         .to_string()
+    ///         .into_bytes(),
+    /// );
+    /// ```
+    pub fn with_config_yaml(mut self, config: impl Into<CopyDataSource>) -> Self {
+        self.config_file = Some(CopyToContainer::new(config.into(), CONFIG_FILE_PATH));
+        self
+    }
+
+    /// Enables a [feature flag](https://prometheus.io/docs/prometheus/latest/feature_flags/) (`--enable-feature`),
+    /// e.g. `exemplar-storage`. Can be called multiple times to enable several features.
+    pub fn with_feature(mut self, feature: &str) -> Self {
+        self.features.push(feature.to_owned());
+        self
+    }
+}
+
+impl Image for Prometheus {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn tag(&self) -> &str {
+        TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![WaitFor::message_on_stderr(
+            "Server is ready to receive web requests.",
+        )]
+    }
+
+    fn cmd(&self) -> impl IntoIterator<Item = impl Into<Cow<'_, str>>> {
+        let mut cmd = vec![
+            format!("--config.file={CONFIG_FILE_PATH}"),
+            "--storage.tsdb.path=/prometheus".to_owned(),
+        ];
+        if !self.features.is_empty() {
+            cmd.push(format!("--enable-feature={}", self.features.join(",")));
+        }
+        cmd
+    }
+
+    fn copy_to_sources(&self) -> impl IntoIterator<Item = &CopyToContainer> {
+        &self.config_file
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[PROMETHEUS_PORT]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testcontainers::runners::SyncRunner;
+
+    #[test]
+    fn prometheus_with_config_and_feature() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = Prometheus::default()
+            .with_config_yaml(
+                "scrape_configs:\n  - job_name: testcontainers\n    static_configs:\n      - targets: ['localhost:9090']\n"
+                    .to_string()
+                    .into_bytes(),
+            )
+            .with_feature("exemplar-storage")
+            .start()?;
+        let url = format!(
+            "http://{}:{}/api/v1/status",
+            node.get_host()?,
+            node.get_host_port_ipv4(PROMETHEUS_PORT)?
+        );
+
+        let config: serde_json::Value = reqwest::blocking::get(format!("{url}/config"))?
+            .error_for_status()?
+            .json()?;
+        assert!(config["data"]["yaml"]
+            .as_str()
+            .unwrap()
+            .contains("job_name: testcontainers"));
+
+        let flags: serde_json::Value = reqwest::blocking::get(format!("{url}/flags"))?
+            .error_for_status()?
+            .json()?;
+        assert_eq!(flags["data"]["enable-feature"], "exemplar-storage");
+        Ok(())
+    }
+}
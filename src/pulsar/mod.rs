@@ -8,8 +8,16 @@ use testcontainers::{
 const NAME: &str = "apachepulsar/pulsar";
 const TAG: &str = "2.10.6";
 
-const PULSAR_PORT: ContainerPort = ContainerPort::Tcp(6650);
-const ADMIN_PORT: ContainerPort = ContainerPort::Tcp(8080);
+/// Port of the binary protocol that the [`Apache Pulsar`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Apache Pulsar`]: https://github.com/apache/pulsar
+pub const PULSAR_PORT: ContainerPort = ContainerPort::Tcp(6650);
+/// Port of the HTTP admin API that the [`Apache Pulsar`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Apache Pulsar`]: https://github.com/apache/pulsar
+pub const ADMIN_PORT: ContainerPort = ContainerPort::Tcp(8080);
 
 /// Module to work with [`Apache Pulsar`] inside of tests.
 /// **Requires protoc to be installed, otherwise will not build.**
@@ -21,7 +29,7 @@ const ADMIN_PORT: ContainerPort = ContainerPort::Tcp(8080);
 /// use testcontainers_modules::{pulsar, testcontainers::runners::SyncRunner};
 ///
 /// let pulsar = pulsar::Pulsar::default().start().unwrap();
-/// let http_port = pulsar.get_host_port_ipv4(6650).unwrap();
+/// let port = pulsar.get_host_port_ipv4(pulsar::PULSAR_PORT).unwrap();
 ///
 /// // do something with the running pulsar instance..
 /// ```
@@ -187,7 +195,7 @@ mod tests {
 
         let endpoint = format!(
             "pulsar://0.0.0.0:{}",
-            pulsar.get_host_port_ipv4(6650).await?
+            pulsar.get_host_port_ipv4(PULSAR_PORT).await?
         );
         let client = pulsar::Pulsar::builder(endpoint, TokioExecutor)
             .build()
@@ -225,7 +233,7 @@ mod tests {
 
         let endpoint = format!(
             "pulsar://0.0.0.0:{}",
-            pulsar.get_host_port_ipv4(6650).await?
+            pulsar.get_host_port_ipv4(PULSAR_PORT).await?
         );
         let client = pulsar::Pulsar::builder(endpoint, TokioExecutor)
             .build()
@@ -0,0 +1,150 @@
+use std::{borrow::Cow, collections::BTreeMap};
+
+use testcontainers::{
+    core::{wait::HttpWaitStrategy, ContainerPort, WaitFor},
+    CopyDataSource, CopyToContainer, Image,
+};
+
+const NAME: &str = "qdrant/qdrant";
+const TAG: &str = "v1.12.4";
+const CONFIG_FILE_PATH: &str = "/qdrant/config/local.yaml";
+
+/// Port of the REST API that the [`Qdrant`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Qdrant`]: https://qdrant.tech/
+pub const QDRANT_REST_PORT: ContainerPort = ContainerPort::Tcp(6333);
+/// Port of the gRPC API that the [`Qdrant`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Qdrant`]: https://qdrant.tech/
+pub const QDRANT_GRPC_PORT: ContainerPort = ContainerPort::Tcp(6334);
+
+/// Module to work with [`Qdrant`] inside of tests.
+///
+/// Starts an instance of Qdrant based on the official [`Qdrant docker image`],
+/// serving the REST API on port 6333 ([`QDRANT_REST_PORT`]) and the gRPC API on port 6334 ([`QDRANT_GRPC_PORT`]).
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{
+///     qdrant::{Qdrant, QDRANT_GRPC_PORT},
+///     testcontainers::runners::SyncRunner,
+/// };
+///
+/// let qdrant_instance = Qdrant::default().start().unwrap();
+/// // e.g. to be used with `qdrant_client::Qdrant::from_url`
+/// let grpc_url = format!(
+///     "http://{}:{}",
+///     qdrant_instance.get_host().unwrap(),
+///     qdrant_instance.get_host_port_ipv4(QDRANT_GRPC_PORT).unwrap()
+/// );
+/// ```
+///
+/// [`Qdrant`]: https://qdrant.tech/
+/// [`Qdrant docker image`]: https://hub.docker.com/r/qdrant/qdrant
+#[derive(Debug, Clone, Default)]
+pub struct Qdrant {
+    env_vars: BTreeMap<String, String>,
+    config_file: Option<CopyToContainer>,
+}
+
+impl Qdrant {
+    /// Sets the API key (`QDRANT__SERVICE__API_KEY`), which has to be sent by clients in the `api-key` header.
+    pub fn with_api_key(mut self, api_key: &str) -> Self {
+        self.env_vars
+            .insert("QDRANT__SERVICE__API_KEY".to_owned(), api_key.to_owned());
+        self
+    }
+
+    /// Registers a custom configuration file, which overrides the defaults of the image.
+    ///
+    /// # Example
+    /// ```
+    /// use testcontainers_modules::qdrant::Qdrant;
+    ///
+    /// let qdrant = Qdrant::default().with_config_yaml(
+    ///     "storage:\n  on_disk_payload: false\n"
+    ///         .to_string()
+    ///         .into_bytes(),
+    /// );
+    /// ```
+    pub fn with_config_yaml(mut self, config: impl Into<CopyDataSource>) -> Self {
+        self.config_file = Some(CopyToContainer::new(config.into(), CONFIG_FILE_PATH));
+        self
+    }
+}
+
+impl Image for Qdrant {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn tag(&self) -> &str {
+        TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![WaitFor::http(
+            HttpWaitStrategy::new("/readyz")
+                .with_port(QDRANT_REST_PORT)
+                .with_expected_status_code(200_u16),
+        )]
+    }
+
+    fn env_vars(
+        &self,
+    ) -> impl IntoIterator<Item = (impl Into<Cow<'_, str>>, impl Into<Cow<'_, str>>)> {
+        &self.env_vars
+    }
+
+    fn copy_to_sources(&self) -> impl IntoIterator<Item = &CopyToContainer> {
+        &self.config_file
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[QDRANT_REST_PORT, QDRANT_GRPC_PORT]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testcontainers::runners::SyncRunner;
+
+    #[test]
+    fn qdrant_with_api_key_and_config() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = Qdrant::default()
+            .with_api_key("secret")
+            .with_config_yaml("log_level: DEBUG\n".to_string().into_bytes())
+            .start()?;
+        let url = format!(
+            "http://{}:{}/collections/test",
+            node.get_host()?,
+            node.get_host_port_ipv4(QDRANT_REST_PORT)?
+        );
+
+        let client = reqwest::blocking::Client::new();
+        let response = client.get(&url).send()?;
+        assert_eq!(response.status(), 401);
+
+        client
+            .put(&url)
+            .header("api-key", "secret")
+            .json(&serde_json::json!({"vectors": {"size": 4, "distance": "Cosine"}}))
+            .send()?
+            .error_for_status()?;
+        let collection: serde_json::Value = client
+            .get(&url)
+            .header("api-key", "secret")
+            .send()?
+            .error_for_status()?
+            .json()?;
+        assert_eq!(
+            collection["result"]["config"]["params"]["vectors"]["size"],
+            4
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,160 @@
+use std::{borrow::Cow, collections::BTreeMap};
+
+use testcontainers::{
+    core::{wait::HttpWaitStrategy, ContainerPort, WaitFor},
+    Image,
+};
+
+const NAME: &str = "questdb/questdb";
+const TAG: &str = "8.2.1";
+
+/// Port of the PostgreSQL wire protocol that the [`QuestDB`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`QuestDB`]: https://questdb.io/
+pub const QUESTDB_PG_PORT: ContainerPort = ContainerPort::Tcp(8812);
+/// Port of the InfluxDB Line Protocol (ILP) over TCP that the [`QuestDB`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`QuestDB`]: https://questdb.io/
+pub const QUESTDB_ILP_PORT: ContainerPort = ContainerPort::Tcp(9009);
+/// Port of the REST API and web console that the [`QuestDB`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`QuestDB`]: https://questdb.io/
+pub const QUESTDB_HTTP_PORT: ContainerPort = ContainerPort::Tcp(9000);
+/// Port of the health endpoint that the [`QuestDB`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`QuestDB`]: https://questdb.io/
+pub const QUESTDB_HEALTH_PORT: ContainerPort = ContainerPort::Tcp(9003);
+
+/// Module to work with [`QuestDB`] inside of tests.
+///
+/// Starts an instance of QuestDB based on the official [`QuestDB docker image`], exposing
+/// - the PostgreSQL wire protocol on port 8812 ([`QUESTDB_PG_PORT`]),
+/// - the InfluxDB Line Protocol on port 9009 ([`QUESTDB_ILP_PORT`]),
+/// - the REST API (including ILP over HTTP) and web console on port 9000 ([`QUESTDB_HTTP_PORT`]),
+/// - the health endpoint on port 9003 ([`QUESTDB_HEALTH_PORT`]).
+///
+/// The credentials of the PostgreSQL wire protocol default to `admin` with password `quest`.
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{
+///     questdb::{QuestDb, QUESTDB_PG_PORT},
+///     testcontainers::runners::SyncRunner,
+/// };
+///
+/// let questdb_instance = QuestDb::default().start().unwrap();
+/// let connection_string = format!(
+///     "postgres://admin:quest@{}:{}/qdb",
+///     questdb_instance.get_host().unwrap(),
+///     questdb_instance.get_host_port_ipv4(QUESTDB_PG_PORT).unwrap()
+/// );
+/// ```
+///
+/// [`QuestDB`]: https://questdb.io/
+/// [`QuestDB docker image`]: https://hub.docker.com/r/questdb/questdb
+#[derive(Debug, Clone, Default)]
+pub struct QuestDb {
+    env_vars: BTreeMap<String, String>,
+}
+
+impl QuestDb {
+    /// Sets the user of the PostgreSQL wire protocol (`QDB_PG_USER`), defaults to `admin`.
+    pub fn with_pg_user(mut self, user: &str) -> Self {
+        self.env_vars
+            .insert("QDB_PG_USER".to_owned(), user.to_owned());
+        self
+    }
+
+    /// Sets the password of the PostgreSQL wire protocol (`QDB_PG_PASSWORD`), defaults to `quest`.
+    pub fn with_pg_password(mut self, password: &str) -> Self {
+        self.env_vars
+            .insert("QDB_PG_PASSWORD".to_owned(), password.to_owned());
+        self
+    }
+}
+
+impl Image for QuestDb {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn tag(&self) -> &str {
+        TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![WaitFor::http(
+            HttpWaitStrategy::new("/status")
+                .with_port(QUESTDB_HEALTH_PORT)
+                .with_expected_status_code(200_u16),
+        )]
+    }
+
+    fn env_vars(
+        &self,
+    ) -> impl IntoIterator<Item = (impl Into<Cow<'_, str>>, impl Into<Cow<'_, str>>)> {
+        &self.env_vars
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[
+            QUESTDB_PG_PORT,
+            QUESTDB_ILP_PORT,
+            QUESTDB_HTTP_PORT,
+            QUESTDB_HEALTH_PORT,
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{io::Write, net::TcpStream};
+
+    use retry::{delay::Fixed, retry};
+
+    use super::*;
+    use crate::testcontainers::runners::SyncRunner;
+
+    #[test]
+    fn questdb_ilp_ingestion() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = QuestDb::default()
+            .with_pg_user("quest_user")
+            .with_pg_password("quest_password")
+            .start()?;
+        let host = node.get_host()?;
+
+        let mut ilp = TcpStream::connect(format!(
+            "{host}:{}",
+            node.get_host_port_ipv4(QUESTDB_ILP_PORT)?
+        ))?;
+        ilp.write_all(b"sensors,location=kitchen temperature=21.5\n")?;
+        ilp.flush()?;
+        drop(ilp);
+
+        let mut conn = postgres::Client::connect(
+            &format!(
+                "postgres://quest_user:quest_password@{host}:{}/qdb",
+                node.get_host_port_ipv4(QUESTDB_PG_PORT)?
+            ),
+            postgres::NoTls,
+        )?;
+        // ingested rows are committed asynchronously
+        let rows = retry(Fixed::from_millis(500).take(20), || {
+            conn.query("SELECT location, temperature FROM sensors", &[])
+                .ok()
+                .filter(|rows| !rows.is_empty())
+                .ok_or("no rows ingested yet")
+        })
+        .map_err(|err| err.to_string())?;
+        let location: String = rows[0].get(0);
+        let temperature: f64 = rows[0].get(1);
+        assert_eq!(location, "kitchen");
+        assert_eq!(temperature, 21.5);
+        Ok(())
+    }
+}
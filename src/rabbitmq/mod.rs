@@ -1,7 +1,25 @@
-use testcontainers::{core::WaitFor, Image};
+use std::{borrow::Cow, collections::BTreeMap};
+
+use testcontainers::{
+    core::{ContainerPort, WaitFor},
+    CopyDataSource, CopyToContainer, Image,
+};
 
 const NAME: &str = "rabbitmq";
 const TAG: &str = "3.8.22-management";
+const DEFINITIONS_FILE_PATH: &str = "/etc/rabbitmq/definitions.json";
+
+/// Port of the AMQP endpoint that the [`RabbitMQ`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`RabbitMQ`]: https://www.rabbitmq.com/
+pub const RABBITMQ_AMQP_PORT: ContainerPort = ContainerPort::Tcp(5672);
+
+/// Port of the management HTTP API that the [`RabbitMQ`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`RabbitMQ`]: https://www.rabbitmq.com/
+pub const RABBITMQ_MANAGEMENT_PORT: ContainerPort = ContainerPort::Tcp(15672);
 
 /// Module to work with [`RabbitMQ`] inside of tests.
 ///
@@ -19,7 +37,9 @@ const TAG: &str = "3.8.22-management";
 /// let amqp_url = format!(
 ///     "amqp://{}:{}",
 ///     rabbitmq_instance.get_host().unwrap(),
-///     rabbitmq_instance.get_host_port_ipv4(5672).unwrap()
+///     rabbitmq_instance
+///         .get_host_port_ipv4(rabbitmq::RABBITMQ_AMQP_PORT)
+///         .unwrap()
 /// );
 ///
 /// // do something with the started rabbitmq instance..
@@ -31,10 +51,47 @@ const TAG: &str = "3.8.22-management";
 /// [`RabbitMQ docker image`]: https://hub.docker.com/_/rabbitmq
 #[derive(Debug, Default, Clone)]
 pub struct RabbitMq {
-    /// (remove if there is another variable)
-    /// Field is included to prevent this struct to be a unit struct.
-    /// This allows extending functionality (and thus further variables) without breaking changes
-    _priv: (),
+    env_vars: BTreeMap<String, String>,
+    definitions: Option<CopyToContainer>,
+}
+
+impl RabbitMq {
+    /// Sets the name of the default user (`RABBITMQ_DEFAULT_USER`), defaults to `guest`.
+    pub fn with_default_user(mut self, user: &str) -> Self {
+        self.env_vars
+            .insert("RABBITMQ_DEFAULT_USER".to_owned(), user.to_owned());
+        self
+    }
+
+    /// Sets the password of the default user (`RABBITMQ_DEFAULT_PASS`), defaults to `guest`.
+    pub fn with_default_pass(mut self, pass: &str) -> Self {
+        self.env_vars
+            .insert("RABBITMQ_DEFAULT_PASS".to_owned(), pass.to_owned());
+        self
+    }
+
+    /// Registers a [definitions file](https://www.rabbitmq.com/docs/definitions) to pre-declare
+    /// exchanges, queues, bindings, users, etc.
+    ///
+    /// The file is copied into the container and loaded by the management plugin on startup.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// # use testcontainers_modules::rabbitmq::RabbitMq;
+    /// let rabbitmq = RabbitMq::default()
+    ///     .with_definitions_json(include_str!("path_to_definitions.json").to_string().into_bytes());
+    /// ```
+    pub fn with_definitions_json(mut self, definitions: impl Into<CopyDataSource>) -> Self {
+        self.definitions = Some(CopyToContainer::new(
+            definitions.into(),
+            DEFINITIONS_FILE_PATH,
+        ));
+        self.env_vars.insert(
+            "RABBITMQ_SERVER_ADDITIONAL_ERL_ARGS".to_owned(),
+            format!("-rabbitmq_management load_definitions \"{DEFINITIONS_FILE_PATH}\""),
+        );
+        self
+    }
 }
 
 impl Image for RabbitMq {
@@ -51,6 +108,20 @@ impl Image for RabbitMq {
             "Server startup complete; 4 plugins started.",
         )]
     }
+
+    fn env_vars(
+        &self,
+    ) -> impl IntoIterator<Item = (impl Into<Cow<'_, str>>, impl Into<Cow<'_, str>>)> {
+        &self.env_vars
+    }
+
+    fn copy_to_sources(&self) -> impl IntoIterator<Item = &CopyToContainer> {
+        &self.definitions
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[RABBITMQ_AMQP_PORT, RABBITMQ_MANAGEMENT_PORT]
+    }
 }
 
 #[cfg(test)]
@@ -67,7 +138,10 @@ mod tests {
         BasicProperties, Connection, ConnectionProperties, ExchangeKind,
     };
 
-    use crate::{rabbitmq, testcontainers::runners::AsyncRunner};
+    use crate::{
+        rabbitmq::{self, RABBITMQ_AMQP_PORT},
+        testcontainers::runners::AsyncRunner,
+    };
 
     #[tokio::test]
     async fn rabbitmq_produce_and_consume_messages(
@@ -155,4 +229,50 @@ mod tests {
         assert_eq!(delivery.routing_key.as_str(), "routing-key");
         Ok(())
     }
+
+    #[tokio::test]
+    async fn rabbitmq_with_user_and_definitions() -> Result<(), Box<dyn std::error::Error + 'static>>
+    {
+        let _ = pretty_env_logger::try_init();
+        let definitions = r#"{
+            "vhosts": [{ "name": "/" }],
+            "queues": [{
+                "name": "predeclared_queue",
+                "vhost": "/",
+                "durable": true,
+                "auto_delete": false,
+                "arguments": {}
+            }]
+        }"#;
+        let rabbit_node = rabbitmq::RabbitMq::default()
+            .with_default_user("custom_user")
+            .with_default_pass("custom_pass")
+            .with_definitions_json(definitions.to_string().into_bytes())
+            .start()
+            .await?;
+
+        let amqp_url = format!(
+            "amqp://custom_user:custom_pass@{}:{}",
+            rabbit_node.get_host().await?,
+            rabbit_node.get_host_port_ipv4(RABBITMQ_AMQP_PORT).await?
+        );
+        let connection = Connection::connect(amqp_url.as_str(), ConnectionProperties::default())
+            .await
+            .unwrap();
+        let channel = connection.create_channel().await.unwrap();
+
+        let queue = channel
+            .queue_declare(
+                "predeclared_queue",
+                QueueDeclareOptions {
+                    passive: true,
+                    ..QueueDeclareOptions::default()
+                },
+                FieldTable::default(),
+            )
+            .await
+            .expect("queue from definitions should exist");
+        assert_eq!(queue.name().as_str(), "predeclared_queue");
+        Ok(())
+    }
 }
@@ -0,0 +1,375 @@
+use std::{borrow::Cow, fmt::Display};
+
+use testcontainers::{
+    core::{ContainerPort, ContainerState, ExecCommand, WaitFor},
+    Image,
+};
+
+const NAME: &str = "redis";
+const TAG: &str = "7.2.4";
+
+const START_SCRIPT: &str = "/usr/local/bin/testcontainers_start.sh";
+const FIRST_NODE_PORT: u16 = 7000;
+const CLUSTER_BUS_PORT_OFFSET: u16 = 10000;
+const DEFAULT_MASTERS: u16 = 3;
+const MAX_MASTERS: u16 = 100;
+const DEFAULT_REPLICAS_PER_MASTER: u16 = 1;
+const MAX_REPLICAS_PER_MASTER: u16 = 9;
+const READY_MESSAGE: &str = "Redis Cluster ready";
+const DEFAULT_ANNOUNCED_HOST: &str = "127.0.0.1";
+
+/// Module to work with a [`Redis Cluster`] inside of tests.
+///
+/// Starts all nodes of a Redis Cluster inside a single container based on the official [`Redis docker image`].
+/// By default the cluster consists of 3 masters with 1 replica each, listening on ports 7000 to 7005
+/// inside of the container (see [`RedisCluster::node_ports`]).
+///
+/// Nodes announce their host-mapped ports together with the hostname `127.0.0.1`
+/// (see [`RedisCluster::with_announced_host`]), so cluster-aware clients
+/// (e.g. `redis::cluster::ClusterClient`) are redirected to reachable addresses.
+/// Use [`RedisCluster::nodes`] to get the `host:port` pairs of all nodes.
+///
+/// Replicas and the cluster bus use the host-mapped ports as well (thus the bus ports starting at 17000
+/// are exposed too), reached through the gateway of the container network, which forwards them
+/// back to the container on local and remote docker hosts.
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{redis::RedisCluster, testcontainers::runners::SyncRunner};
+///
+/// let cluster = RedisCluster::default().start().unwrap();
+/// let host = cluster.get_host().unwrap();
+/// let nodes = cluster
+///     .image()
+///     .nodes(host, |port| cluster.get_host_port_ipv4(port))
+///     .unwrap();
+///
+/// let urls: Vec<String> = nodes.iter().map(|node| format!("redis://{node}")).collect();
+/// // e.g. `redis::cluster::ClusterClient::new(urls)`
+/// ```
+///
+/// [`Redis Cluster`]: https://redis.io/docs/management/scaling/
+/// [`Redis docker image`]: https://hub.docker.com/_/redis
+#[derive(Debug, Clone)]
+pub struct RedisCluster {
+    announced_host: String,
+    masters: u16,
+    replicas_per_master: u16,
+    node_ports: Vec<ContainerPort>,
+    exposed_ports: Vec<ContainerPort>,
+}
+
+impl Default for RedisCluster {
+    fn default() -> Self {
+        Self::new(
+            DEFAULT_ANNOUNCED_HOST.to_string(),
+            DEFAULT_MASTERS,
+            DEFAULT_REPLICAS_PER_MASTER,
+        )
+    }
+}
+
+impl RedisCluster {
+    fn new(announced_host: String, masters: u16, replicas_per_master: u16) -> Self {
+        // at most 1000 nodes, thus the node ports (7000-7999) and bus ports (17000-17999) stay in range
+        let masters = masters.clamp(DEFAULT_MASTERS, MAX_MASTERS);
+        let replicas_per_master = replicas_per_master.min(MAX_REPLICAS_PER_MASTER);
+        let node_count = masters * (replicas_per_master + 1);
+        let node_ports: Vec<ContainerPort> = (FIRST_NODE_PORT..FIRST_NODE_PORT + node_count)
+            .map(ContainerPort::Tcp)
+            .collect();
+        let exposed_ports = node_ports
+            .iter()
+            .copied()
+            .chain(node_ports.iter().map(|port| bus_port(*port)))
+            .collect();
+        Self {
+            announced_host,
+            masters,
+            replicas_per_master,
+            node_ports,
+            exposed_ports,
+        }
+    }
+
+    /// Sets the number of master nodes of the cluster.
+    ///
+    /// Redis Cluster requires at least 3 masters, thus smaller values are raised to 3.
+    /// At most 100 masters are supported, larger values are lowered to 100.
+    pub fn with_masters(self, masters: u16) -> Self {
+        Self::new(self.announced_host, masters, self.replicas_per_master)
+    }
+
+    /// Sets the number of replicas started for each master node.
+    ///
+    /// At most 9 replicas per master are supported, larger values are lowered to 9.
+    pub fn with_replicas_per_master(self, replicas_per_master: u16) -> Self {
+        Self::new(self.announced_host, self.masters, replicas_per_master)
+    }
+
+    /// Sets the hostname announced to clients (`cluster-announce-hostname`), defaults to `127.0.0.1`.
+    ///
+    /// Has to be set to the host of the docker daemon, when the cluster is started on a remote docker host
+    /// (e.g. configured by `DOCKER_HOST`), for clients to follow the redirects of the cluster.
+    pub fn with_announced_host(mut self, host: impl Into<String>) -> Self {
+        self.announced_host = host.into();
+        self
+    }
+
+    /// Returns the ports the cluster nodes are listening on inside of the container.
+    pub fn node_ports(&self) -> &[ContainerPort] {
+        &self.node_ports
+    }
+
+    /// Returns the `host:port` pairs of all cluster nodes, resolving the host-mapped port
+    /// of each node with the given function (e.g. `Container::get_host_port_ipv4`).
+    pub fn nodes<E>(
+        &self,
+        host: impl Display,
+        mut host_port: impl FnMut(ContainerPort) -> Result<u16, E>,
+    ) -> Result<Vec<String>, E> {
+        self.node_ports
+            .iter()
+            .map(|port| Ok(format!("{host}:{}", host_port(*port)?)))
+            .collect()
+    }
+
+    fn start_script(&self, host_ports: &[u16], host_bus_ports: &[u16]) -> String {
+        let mut script = String::from("#!/usr/bin/env bash\nset -e\n");
+        for port in self.node_ports.iter().map(|port| port.as_u16()) {
+            script.push_str(&format!(
+                "redis-server --port {port} --cluster-enabled yes --cluster-config-file nodes-{port}.conf --protected-mode no --appendonly no --daemonize yes --logfile /tmp/redis-{port}.log\n\
+                 until redis-cli -p {port} ping; do sleep 0.1; done\n"
+            ));
+        }
+
+        let addresses: Vec<String> = self
+            .node_ports
+            .iter()
+            .map(|port| format!("127.0.0.1:{}", port.as_u16()))
+            .collect();
+        script.push_str(&format!(
+            "redis-cli --cluster create {} --cluster-replicas {} --cluster-yes\n",
+            addresses.join(" "),
+            self.replicas_per_master
+        ));
+
+        // announce host-mapped ports only once the cluster is formed, as
+        // `redis-cli --cluster create` has to reach the nodes from inside the container.
+        // Replicas follow the announced ip, thus the gateway of the container network is used,
+        // which forwards the host-mapped ports back to the container, while clients are
+        // redirected to the announced hostname.
+        script.push_str(
+            "gateway=$(awk '$2 == \"00000000\" { print $3; exit }' /proc/net/route)\n\
+             announce_ip=$(printf '%d.%d.%d.%d' 0x${gateway:6:2} 0x${gateway:4:2} 0x${gateway:2:2} 0x${gateway:0:2})\n",
+        );
+        for ((port, host_port), host_bus_port) in self
+            .node_ports
+            .iter()
+            .map(|port| port.as_u16())
+            .zip(host_ports)
+            .zip(host_bus_ports)
+        {
+            script.push_str(&format!(
+                "redis-cli -p {port} config set cluster-announce-ip \"$announce_ip\" cluster-announce-hostname {} cluster-preferred-endpoint-type hostname cluster-announce-port {host_port} cluster-announce-bus-port {host_bus_port}\n",
+                self.announced_host,
+            ));
+        }
+
+        let host_ports: Vec<String> = host_ports.iter().map(u16::to_string).collect();
+        script.push_str(&format!(
+            "until [ \"$(redis-cli -p {FIRST_NODE_PORT} cluster nodes | grep -cE \":({})@\")\" -eq {} ]; do sleep 0.1; done\n\
+             echo {READY_MESSAGE}\n\
+             exec tail -f /dev/null\n",
+            host_ports.join("|"),
+            host_ports.len()
+        ));
+        script
+    }
+}
+
+fn bus_port(port: ContainerPort) -> ContainerPort {
+    ContainerPort::Tcp(port.as_u16() + CLUSTER_BUS_PORT_OFFSET)
+}
+
+impl Image for RedisCluster {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn tag(&self) -> &str {
+        TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        // nodes can only announce their host-mapped ports, once those are known,
+        // thus the actual wait is done in `exec_after_start`
+        vec![]
+    }
+
+    fn entrypoint(&self) -> Option<&str> {
+        Some("bash")
+    }
+
+    fn cmd(&self) -> impl IntoIterator<Item = impl Into<Cow<'_, str>>> {
+        // waits until the start script is created in `exec_after_start`
+        vec![
+            "-c".to_string(),
+            format!("while [ ! -f {START_SCRIPT} ]; do sleep 0.1; done; chmod 755 {START_SCRIPT} && {START_SCRIPT}"),
+        ]
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &self.exposed_ports
+    }
+
+    fn exec_after_start(
+        &self,
+        cs: ContainerState,
+    ) -> Result<Vec<ExecCommand>, testcontainers::TestcontainersError> {
+        let host_ports = self
+            .node_ports
+            .iter()
+            .map(|port| cs.host_port_ipv4(*port))
+            .collect::<Result<Vec<_>, _>>()?;
+        let host_bus_ports = self
+            .node_ports
+            .iter()
+            .map(|port| cs.host_port_ipv4(bus_port(*port)))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let cmd = vec![
+            "sh".to_string(),
+            "-c".to_string(),
+            format!(
+                "cat > {START_SCRIPT}.tmp <<'EOF'\n{}EOF\nmv {START_SCRIPT}.tmp {START_SCRIPT}\n",
+                self.start_script(&host_ports, &host_bus_ports)
+            ),
+        ];
+        // the start script is executed by the `cmd` process,
+        // so the message has to be looked up in the container log
+        let ready_conditions = vec![WaitFor::message_on_stdout(READY_MESSAGE)];
+        Ok(vec![
+            ExecCommand::new(cmd).with_container_ready_conditions(ready_conditions)
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use retry::{delay::Fixed, retry};
+
+    use crate::{redis::RedisCluster, testcontainers::runners::SyncRunner};
+
+    #[test]
+    fn redis_cluster_announces_host_ports() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = RedisCluster::default().start()?;
+        let nodes = node
+            .image()
+            .nodes(node.get_host()?, |port| node.get_host_port_ipv4(port))?;
+        assert_eq!(6, nodes.len());
+
+        for address in &nodes {
+            let client = redis::Client::open(format!("redis://{address}")).unwrap();
+            let mut con = client.get_connection().unwrap();
+
+            let info: String = redis::cmd("CLUSTER").arg("INFO").query(&mut con).unwrap();
+            assert!(info.contains("cluster_state:ok"));
+            assert!(info.contains("cluster_known_nodes:6"));
+        }
+
+        let client = redis::Client::open(format!("redis://{}", nodes[0])).unwrap();
+        let mut con = client.get_connection().unwrap();
+        let cluster_nodes: String = redis::cmd("CLUSTER").arg("NODES").query(&mut con).unwrap();
+        for port in &nodes {
+            let port = port.rsplit(':').next().unwrap();
+            assert!(cluster_nodes.contains(&format!(":{port}@")));
+        }
+        assert_eq!(6, cluster_nodes.matches(",127.0.0.1 ").count());
+        Ok(())
+    }
+
+    #[test]
+    fn redis_cluster_replicas_sync_from_announced_ports(
+    ) -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = RedisCluster::default().start()?;
+        let nodes = node
+            .image()
+            .nodes(node.get_host()?, |port| node.get_host_port_ipv4(port))?;
+        let host_ports: Vec<&str> = nodes
+            .iter()
+            .map(|address| address.rsplit(':').next().unwrap())
+            .collect();
+
+        let mut replicas = 0;
+        for address in &nodes {
+            let client = redis::Client::open(format!("redis://{address}")).unwrap();
+            let mut con = client.get_connection().unwrap();
+            let info: String = redis::cmd("INFO")
+                .arg("replication")
+                .query(&mut con)
+                .unwrap();
+            if !info.contains("role:slave") {
+                continue;
+            }
+            replicas += 1;
+
+            retry(Fixed::from_millis(500).take(40), || {
+                let info: String = redis::cmd("INFO")
+                    .arg("replication")
+                    .query(&mut con)
+                    .map_err(|err| err.to_string())?;
+                let synced = info.contains("master_link_status:up")
+                    && host_ports
+                        .iter()
+                        .any(|port| info.contains(&format!("master_port:{port}\r\n")));
+                if synced {
+                    Ok(())
+                } else {
+                    Err(format!("replica {address} not synced: {info}"))
+                }
+            })
+            .map_err(|err| err.to_string())?;
+        }
+        assert_eq!(3, replicas);
+        Ok(())
+    }
+
+    #[test]
+    fn redis_cluster_raises_masters_to_minimum() {
+        let cluster = RedisCluster::default().with_masters(1);
+        assert_eq!(6, cluster.node_ports().len());
+    }
+
+    #[test]
+    fn redis_cluster_caps_node_counts() {
+        let cluster = RedisCluster::default()
+            .with_masters(u16::MAX)
+            .with_replicas_per_master(u16::MAX);
+        assert_eq!(1000, cluster.node_ports().len());
+        assert_eq!(
+            Some(7999),
+            cluster.node_ports().last().map(|port| port.as_u16())
+        );
+    }
+
+    #[test]
+    fn redis_cluster_without_replicas() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = RedisCluster::default()
+            .with_replicas_per_master(0)
+            .start()?;
+        let nodes = node
+            .image()
+            .nodes(node.get_host()?, |port| node.get_host_port_ipv4(port))?;
+        assert_eq!(3, nodes.len());
+
+        let client = redis::Client::open(format!("redis://{}", nodes[0])).unwrap();
+        let mut con = client.get_connection().unwrap();
+        let info: String = redis::cmd("CLUSTER").arg("INFO").query(&mut con).unwrap();
+        assert!(info.contains("cluster_size:3"));
+        Ok(())
+    }
+}
@@ -1,3 +1,4 @@
+mod cluster;
 mod stack;
 mod standalone;
 
@@ -7,5 +8,6 @@ mod standalone;
 /// [`Redis`]: https://redis.io/
 pub const REDIS_PORT: u16 = 6379;
 
-pub use stack::RedisStack;
+pub use cluster::RedisCluster;
+pub use stack::{RedisModule, RedisStack};
 pub use standalone::Redis;
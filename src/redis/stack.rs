@@ -1,7 +1,50 @@
-use testcontainers::{core::WaitFor, Image};
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, BTreeSet},
+};
+
+use testcontainers::{core::WaitFor, CopyToContainer, Image};
 
 const NAME: &str = "redis/redis-stack-server";
 const TAG: &str = "7.2.0-v8";
+const MODULES_DIR: &str = "/opt/redis-stack/lib";
+/// Config file picked up by the entrypoint of the image
+const CONFIG_FILE_PATH: &str = "/redis-stack.conf";
+
+/// Modules shipped with the [`RedisStack`] image.
+/// See the [Redis Stack documentation](https://redis.io/docs/about/about-stack/) for more information.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum RedisModule {
+    /// [RediSearch](https://redis.io/docs/interact/search-and-query/): querying, secondary indexing and full-text search
+    Search,
+    /// [RedisJSON](https://redis.io/docs/data-types/json/): JSON documents as native data type
+    Json,
+    /// [RedisTimeSeries](https://redis.io/docs/data-types/timeseries/): time series data structure
+    TimeSeries,
+    /// [RedisBloom](https://redis.io/docs/data-types/probabilistic/): probabilistic data structures
+    Bloom,
+}
+
+impl RedisModule {
+    fn library(&self) -> &'static str {
+        match self {
+            Self::Search => "redisearch.so",
+            Self::Json => "rejson.so",
+            Self::TimeSeries => "redistimeseries.so",
+            Self::Bloom => "redisbloom.so",
+        }
+    }
+
+    fn args_env_var(&self) -> &'static str {
+        match self {
+            Self::Search => "REDISEARCH_ARGS",
+            Self::Json => "REDISJSON_ARGS",
+            Self::TimeSeries => "REDISTIMESERIES_ARGS",
+            Self::Bloom => "REDISBLOOM_ARGS",
+        }
+    }
+}
 
 /// Module to work with [`Redis Stack`] inside of tests.
 ///
@@ -27,16 +70,75 @@ const TAG: &str = "7.2.0-v8";
 /// let result: String = con.json_get("my_key", "$..number").unwrap();
 /// ```
 ///
+/// By default all modules of the stack are loaded, use [`RedisStack::with_modules`] to only load a selection of them.
+///
 /// [`Redis Stack`]: https://redis.io/docs/about/about-stack/
 /// [`Redis Stack docker image`]: https://hub.docker.com/r/redis/redis-stack-server
 /// [`Redis reference guide`]: https://redis.io/docs/interact/
 /// [`REDIS_PORT`]: super::REDIS_PORT
 #[derive(Debug, Default, Clone)]
 pub struct RedisStack {
-    /// (remove if there is another variable)
-    /// Field is included to prevent this struct to be a unit struct.
-    /// This allows extending functionality (and thus further variables) without breaking changes
-    _priv: (),
+    modules: Option<BTreeSet<RedisModule>>,
+    env_vars: BTreeMap<String, String>,
+    config_file: Option<CopyToContainer>,
+}
+
+impl RedisStack {
+    /// Only loads the given modules, instead of all modules of the stack.
+    /// Can be called multiple times to add (not override) modules.
+    ///
+    /// # Example
+    /// ```
+    /// use testcontainers_modules::redis::{RedisModule, RedisStack};
+    ///
+    /// let redis_stack = RedisStack::default().with_modules(&[RedisModule::Json, RedisModule::Search]);
+    /// ```
+    pub fn with_modules(mut self, modules: &[RedisModule]) -> Self {
+        self.modules
+            .get_or_insert_with(BTreeSet::new)
+            .extend(modules.iter().copied());
+        self
+    }
+
+    /// Sets the arguments passed to the given module when it is loaded
+    /// (e.g. `REDISEARCH_ARGS` for [`RedisModule::Search`]).
+    ///
+    /// # Example
+    /// ```
+    /// use testcontainers_modules::redis::{RedisModule, RedisStack};
+    ///
+    /// let redis_stack =
+    ///     RedisStack::default().with_module_args(RedisModule::Search, "MAXSEARCHRESULTS 100");
+    /// ```
+    pub fn with_module_args(mut self, module: RedisModule, args: impl Into<String>) -> Self {
+        self.env_vars
+            .insert(module.args_env_var().to_owned(), args.into());
+        self
+    }
+
+    /// Sets additional arguments for the redis server itself, one argument per item,
+    /// e.g. `["--save", ""]` to disable snapshots.
+    ///
+    /// The arguments are passed to the server as config file, thus values may contain whitespaces or be empty.
+    ///
+    /// # Example
+    /// ```
+    /// use testcontainers_modules::redis::RedisStack;
+    ///
+    /// let redis_stack = RedisStack::default().with_redis_args(["--save", "", "--maxmemory", "2mb"]);
+    /// ```
+    pub fn with_redis_args(mut self, args: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        let config = redis_config(args.into_iter().map(Into::into));
+        self.config_file = Some(CopyToContainer::new(config.into_bytes(), CONFIG_FILE_PATH));
+        self
+    }
+
+    fn env_var_args(&self, key: &str) -> impl Iterator<Item = String> + '_ {
+        self.env_vars
+            .get(key)
+            .into_iter()
+            .flat_map(|args| args.split_whitespace().map(str::to_owned))
+    }
 }
 
 impl Image for RedisStack {
@@ -51,6 +153,69 @@ impl Image for RedisStack {
     fn ready_conditions(&self) -> Vec<WaitFor> {
         vec![WaitFor::message_on_stdout("Ready to accept connections")]
     }
+
+    fn env_vars(
+        &self,
+    ) -> impl IntoIterator<Item = (impl Into<Cow<'_, str>>, impl Into<Cow<'_, str>>)> {
+        &self.env_vars
+    }
+
+    fn copy_to_sources(&self) -> impl IntoIterator<Item = &CopyToContainer> {
+        &self.config_file
+    }
+
+    fn entrypoint(&self) -> Option<&str> {
+        // the default entrypoint of the image always loads all modules
+        self.modules.as_ref().map(|_| "redis-server")
+    }
+
+    fn cmd(&self) -> impl IntoIterator<Item = impl Into<Cow<'_, str>>> {
+        let Some(modules) = &self.modules else {
+            return Vec::new();
+        };
+
+        let mut cmd = Vec::new();
+        if self.config_file.is_some() {
+            // the config file has to be the first argument
+            cmd.push(CONFIG_FILE_PATH.to_owned());
+        }
+        cmd.extend([
+            "--protected-mode".to_owned(),
+            "no".to_owned(),
+            "--daemonize".to_owned(),
+            "no".to_owned(),
+        ]);
+        for module in modules {
+            cmd.push("--loadmodule".to_owned());
+            cmd.push(format!("{MODULES_DIR}/{}", module.library()));
+            cmd.extend(self.env_var_args(module.args_env_var()));
+        }
+        cmd
+    }
+}
+
+/// Converts command line arguments of `redis-server` (e.g. `--save ""`) into the lines of a config file.
+fn redis_config(args: impl IntoIterator<Item = String>) -> String {
+    let mut config = String::new();
+    for arg in args {
+        match arg.strip_prefix("--") {
+            Some(option) => {
+                if !config.is_empty() {
+                    config.push('\n');
+                }
+                config.push_str(option);
+            }
+            None => {
+                let value = arg
+                    .replace('\\', "\\\\")
+                    .replace('"', "\\\"")
+                    .replace('\n', "\\n");
+                config.push_str(&format!(" \"{value}\""));
+            }
+        }
+    }
+    config.push('\n');
+    config
 }
 
 #[cfg(test)]
@@ -58,11 +223,24 @@ mod tests {
     use redis::JsonCommands;
     use serde_json::json;
 
+    use super::redis_config;
     use crate::{
-        redis::{RedisStack, REDIS_PORT},
+        redis::{RedisModule, RedisStack, REDIS_PORT},
         testcontainers::runners::SyncRunner,
     };
 
+    #[test]
+    fn redis_stack_args_to_config() {
+        assert_eq!(
+            redis_config(
+                ["--save", "", "--notify-keyspace-events", "K\"$"]
+                    .into_iter()
+                    .map(str::to_owned)
+            ),
+            "save \"\"\nnotify-keyspace-events \"K\\\"$\"\n"
+        );
+    }
+
     #[test]
     fn redis_fetch_an_integer_in_json() -> Result<(), Box<dyn std::error::Error + 'static>> {
         let _ = pretty_env_logger::try_init();
@@ -82,4 +260,56 @@ mod tests {
         assert_eq!("[42]", result);
         Ok(())
     }
+
+    #[test]
+    fn redis_stack_with_selected_modules() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = RedisStack::default()
+            .with_modules(&[RedisModule::Json])
+            .start()?;
+        let host_ip = node.get_host()?;
+        let host_port = node.get_host_port_ipv4(REDIS_PORT)?;
+        let url = format!("redis://{host_ip}:{host_port}");
+
+        let client = redis::Client::open(url.as_ref()).unwrap();
+        let mut con = client.get_connection().unwrap();
+
+        assert_eq!(
+            con.json_set("my_key", "$", &json!({ "number": 42 })),
+            Ok(true)
+        );
+        let search_indexes: redis::RedisResult<Vec<String>> =
+            redis::cmd("FT._LIST").query(&mut con);
+        assert!(search_indexes.is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn redis_stack_with_redis_args() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        for redis_stack in [
+            RedisStack::default(),
+            RedisStack::default().with_modules(&[RedisModule::Json]),
+        ] {
+            let node = redis_stack
+                .with_redis_args(["--save", "", "--maxmemory", "2mb"])
+                .start()?;
+            let host_ip = node.get_host()?;
+            let host_port = node.get_host_port_ipv4(REDIS_PORT)?;
+            let client = redis::Client::open(format!("redis://{host_ip}:{host_port}"))?;
+            let mut con = client.get_connection()?;
+
+            let save: Vec<String> = redis::cmd("CONFIG")
+                .arg("GET")
+                .arg("save")
+                .query(&mut con)?;
+            assert_eq!(vec!["save", ""], save);
+            let maxmemory: Vec<String> = redis::cmd("CONFIG")
+                .arg("GET")
+                .arg("maxmemory")
+                .query(&mut con)?;
+            assert_eq!(vec!["maxmemory", "2097152"], maxmemory);
+        }
+        Ok(())
+    }
 }
@@ -1,7 +1,12 @@
-use testcontainers::{core::WaitFor, Image};
+use std::borrow::Cow;
+
+use testcontainers::{core::WaitFor, CopyDataSource, CopyToContainer, Image};
 
 const NAME: &str = "redis";
 const TAG: &str = "5.0";
+const CONFIG_FILE_PATH: &str = "/usr/local/etc/redis/redis.conf";
+#[cfg(feature = "tls")]
+const TLS_DIR: &str = "/usr/local/etc/redis/tls";
 
 /// Module to work with [`Redis`] inside of tests.
 ///
@@ -35,10 +40,76 @@ const TAG: &str = "5.0";
 /// [`REDIS_PORT`]: super::REDIS_PORT
 #[derive(Debug, Default, Clone)]
 pub struct Redis {
-    /// (remove if there is another variable)
-    /// Field is included to prevent this struct to be a unit struct.
-    /// This allows extending functionality (and thus further variables) without breaking changes
-    _priv: (),
+    password: Option<String>,
+    config_file: Option<CopyToContainer>,
+    tls: Vec<CopyToContainer>,
+}
+
+impl Redis {
+    /// Enables authentication by passing `--requirepass <password>` to the redis server.
+    /// Clients have to authenticate with the given password (for the `default` user).
+    ///
+    /// # Example
+    /// ```
+    /// use testcontainers_modules::redis::Redis;
+    ///
+    /// let redis = Redis::default().with_password("secret");
+    /// ```
+    pub fn with_password(mut self, password: &str) -> Self {
+        self.password = Some(password.to_owned());
+        self
+    }
+
+    /// Registers a custom `redis.conf` to be used by the redis server.
+    ///
+    /// The file is copied into the container and passed as first argument to `redis-server`.
+    /// Options given via other builders (e.g. [`Redis::with_password`]) take precedence over the file.
+    ///
+    /// # Example
+    /// ```
+    /// use testcontainers_modules::redis::Redis;
+    ///
+    /// let redis = Redis::default()
+    ///     .with_config_file(b"maxmemory 2mb\nmaxmemory-policy allkeys-lru\n".to_vec());
+    /// ```
+    pub fn with_config_file(mut self, config_file: impl Into<CopyDataSource>) -> Self {
+        self.config_file = Some(CopyToContainer::new(config_file.into(), CONFIG_FILE_PATH));
+        self
+    }
+
+    /// Serves TLS instead of plain connections on port 6379 ([`REDIS_PORT`]), using the server certificate
+    /// and key of the given [`TlsCertificates`]. Clients connect via `rediss://` and can verify the server
+    /// with [`TlsCertificates::ca_cert_pem`], client certificates are not required.
+    ///
+    /// TLS is supported as of Redis 6, thus the tag has to be changed as well,
+    /// e.g. with [`ImageExt::with_tag`]. The default tag `5.0` fails to start with this preset.
+    ///
+    /// # Example
+    /// ```
+    /// use testcontainers_modules::{
+    ///     redis::Redis,
+    ///     testcontainers::{ContainerRequest, ImageExt},
+    ///     tls::TlsCertificates,
+    /// };
+    ///
+    /// let certificates = TlsCertificates::default();
+    /// let redis: ContainerRequest<Redis> = Redis::default().with_tls(&certificates).with_tag("7.2");
+    /// ```
+    ///
+    /// [`REDIS_PORT`]: super::REDIS_PORT
+    /// [`TlsCertificates`]: crate::tls::TlsCertificates
+    /// [`TlsCertificates::ca_cert_pem`]: crate::tls::TlsCertificates::ca_cert_pem
+    /// [`ImageExt::with_tag`]: testcontainers::ImageExt::with_tag
+    #[cfg(feature = "tls")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "tls")))]
+    pub fn with_tls(mut self, certificates: &crate::tls::TlsCertificates) -> Self {
+        self.tls = vec![
+            certificates.copy_ca_cert_to(format!("{TLS_DIR}/ca.crt")),
+            certificates.copy_cert_to(format!("{TLS_DIR}/server.crt")),
+            certificates.copy_key_to(format!("{TLS_DIR}/server.key")),
+        ];
+        self
+    }
 }
 
 impl Image for Redis {
@@ -53,6 +124,46 @@ impl Image for Redis {
     fn ready_conditions(&self) -> Vec<WaitFor> {
         vec![WaitFor::message_on_stdout("Ready to accept connections")]
     }
+
+    fn copy_to_sources(&self) -> impl IntoIterator<Item = &CopyToContainer> {
+        self.config_file.iter().chain(&self.tls)
+    }
+
+    fn cmd(&self) -> impl IntoIterator<Item = impl Into<Cow<'_, str>>> {
+        if self.config_file.is_none() && self.password.is_none() && self.tls.is_empty() {
+            return Vec::new();
+        }
+
+        let mut cmd = vec!["redis-server".to_owned()];
+        if self.config_file.is_some() {
+            cmd.push(CONFIG_FILE_PATH.to_owned());
+        }
+        if let Some(password) = &self.password {
+            cmd.push("--requirepass".to_owned());
+            cmd.push(password.clone());
+        }
+        #[cfg(feature = "tls")]
+        if !self.tls.is_empty() {
+            cmd.extend(
+                [
+                    "--port",
+                    "0",
+                    "--tls-port",
+                    &super::REDIS_PORT.to_string(),
+                    "--tls-cert-file",
+                    &format!("{TLS_DIR}/server.crt"),
+                    "--tls-key-file",
+                    &format!("{TLS_DIR}/server.key"),
+                    "--tls-ca-cert-file",
+                    &format!("{TLS_DIR}/ca.crt"),
+                    "--tls-auth-clients",
+                    "no",
+                ]
+                .map(str::to_owned),
+            );
+        }
+        cmd
+    }
 }
 
 #[cfg(test)]
@@ -61,6 +172,46 @@ mod tests {
 
     use crate::{redis::Redis, testcontainers::runners::SyncRunner};
 
+    #[cfg(feature = "tls")]
+    #[test]
+    fn redis_with_tls() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        use testcontainers::{
+            core::{CmdWaitFor, ExecCommand},
+            ImageExt,
+        };
+
+        let _ = pretty_env_logger::try_init();
+        let certificates = crate::tls::TlsCertificates::default();
+        let node = Redis::default()
+            .with_tls(&certificates)
+            .with_tag("7.2")
+            .start()?;
+        let host_ip = node.get_host()?;
+        let host_port = node.get_host_port_ipv4(6379)?;
+
+        // plain connections are refused
+        let plain_connection = redis::Client::open(format!("redis://{host_ip}:{host_port}"))?
+            .get_connection()
+            .and_then(|mut con| con.set::<_, _, ()>("my_key", 42));
+        assert!(plain_connection.is_err());
+
+        // the handshake succeeds when verifying the server with the CA certificate
+        let mut result = node.exec(
+            ExecCommand::new([
+                "redis-cli",
+                "--tls",
+                "--cacert",
+                "/usr/local/etc/redis/tls/ca.crt",
+                "-h",
+                "localhost",
+                "PING",
+            ])
+            .with_cmd_ready_condition(CmdWaitFor::exit_code(0)),
+        )?;
+        assert_eq!(String::from_utf8(result.stdout_to_vec()?)?.trim(), "PONG");
+        Ok(())
+    }
+
     #[test]
     fn redis_fetch_an_integer() -> Result<(), Box<dyn std::error::Error + 'static>> {
         let _ = pretty_env_logger::try_init();
@@ -77,4 +228,45 @@ mod tests {
         assert_eq!(42, result);
         Ok(())
     }
+
+    #[test]
+    fn redis_with_password() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = Redis::default().with_password("secret").start()?;
+        let host_ip = node.get_host()?;
+        let host_port = node.get_host_port_ipv4(6379)?;
+
+        let client = redis::Client::open(format!("redis://{host_ip}:{host_port}")).unwrap();
+        let mut con = client.get_connection().unwrap();
+        assert!(con.set::<_, _, ()>("my_key", 42).is_err());
+
+        let client = redis::Client::open(format!("redis://:secret@{host_ip}:{host_port}")).unwrap();
+        let mut con = client.get_connection().unwrap();
+        con.set::<_, _, ()>("my_key", 42).unwrap();
+        let result: i64 = con.get("my_key").unwrap();
+        assert_eq!(42, result);
+        Ok(())
+    }
+
+    #[test]
+    fn redis_with_config_file() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = Redis::default()
+            .with_config_file(b"maxmemory-policy allkeys-lru\n".to_vec())
+            .start()?;
+        let host_ip = node.get_host()?;
+        let host_port = node.get_host_port_ipv4(6379)?;
+        let url = format!("redis://{host_ip}:{host_port}");
+
+        let client = redis::Client::open(url.as_ref()).unwrap();
+        let mut con = client.get_connection().unwrap();
+
+        let policy: Vec<String> = redis::cmd("CONFIG")
+            .arg("GET")
+            .arg("maxmemory-policy")
+            .query(&mut con)
+            .unwrap();
+        assert_eq!(vec!["maxmemory-policy", "allkeys-lru"], policy);
+        Ok(())
+    }
 }
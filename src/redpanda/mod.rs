@@ -0,0 +1,282 @@
+use std::borrow::Cow;
+
+use testcontainers::{
+    core::{ContainerPort, ContainerState, ExecCommand, WaitFor},
+    Image,
+};
+
+const NAME: &str = "redpandadata/redpanda";
+const TAG: &str = "v24.2.13";
+
+/// Port of the Kafka API that the [`Redpanda`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Redpanda`]: https://redpanda.com/
+pub const REDPANDA_KAFKA_PORT: ContainerPort = ContainerPort::Tcp(9092);
+
+/// Port of the Schema Registry that the [`Redpanda`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Redpanda`]: https://redpanda.com/
+pub const REDPANDA_SCHEMA_REGISTRY_PORT: ContainerPort = ContainerPort::Tcp(8081);
+
+/// Port of the HTTP proxy (PandaProxy) that the [`Redpanda`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Redpanda`]: https://redpanda.com/
+pub const REDPANDA_PANDAPROXY_PORT: ContainerPort = ContainerPort::Tcp(8082);
+
+const START_SCRIPT: &str = "/tmp/testcontainers_start.sh";
+const CONFIG_FILE: &str = "/etc/redpanda/redpanda.yaml";
+const INTERNAL_KAFKA_PORT: u16 = 9093;
+const INTERNAL_PANDAPROXY_PORT: u16 = 8083;
+const DEFAULT_ADVERTISED_HOST: &str = "127.0.0.1";
+
+/// Module to work with [`Redpanda`] inside of tests.
+///
+/// Starts a single node instance of Redpanda in `dev-container` mode, based on the official [`Redpanda docker image`].
+///
+/// The Kafka API is exposed on port 9092 ([`REDPANDA_KAFKA_PORT`]), the Schema Registry on port 8081
+/// ([`REDPANDA_SCHEMA_REGISTRY_PORT`]) and the HTTP proxy on port 8082 ([`REDPANDA_PANDAPROXY_PORT`]).
+/// Schema Registry and HTTP proxy can be disabled with [`Redpanda::with_schema_registry`] and [`Redpanda::with_pandaproxy`].
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{
+///     redpanda::{Redpanda, REDPANDA_KAFKA_PORT},
+///     testcontainers::runners::SyncRunner,
+/// };
+///
+/// let redpanda_instance = Redpanda::default().start().unwrap();
+/// let bootstrap_servers = format!(
+///     "127.0.0.1:{}",
+///     redpanda_instance
+///         .get_host_port_ipv4(REDPANDA_KAFKA_PORT)
+///         .unwrap()
+/// );
+/// // connect to redpanda with any kafka client to send/receive messages
+/// ```
+///
+/// [`Redpanda`]: https://redpanda.com/
+/// [`Redpanda docker image`]: https://hub.docker.com/r/redpandadata/redpanda
+#[derive(Debug, Clone)]
+pub struct Redpanda {
+    schema_registry: bool,
+    pandaproxy: bool,
+    advertised_host: String,
+    internal_network_alias: Option<String>,
+}
+
+impl Default for Redpanda {
+    fn default() -> Self {
+        Self {
+            schema_registry: true,
+            pandaproxy: true,
+            advertised_host: DEFAULT_ADVERTISED_HOST.to_owned(),
+            internal_network_alias: None,
+        }
+    }
+}
+
+impl Redpanda {
+    /// Enables or disables the Schema Registry, enabled by default.
+    pub fn with_schema_registry(mut self, enabled: bool) -> Self {
+        self.schema_registry = enabled;
+        self
+    }
+
+    /// Enables or disables the HTTP proxy (PandaProxy), enabled by default.
+    pub fn with_pandaproxy(mut self, enabled: bool) -> Self {
+        self.pandaproxy = enabled;
+        self
+    }
+
+    /// Sets the host advertised to clients connecting through [`REDPANDA_KAFKA_PORT`]
+    /// and [`REDPANDA_PANDAPROXY_PORT`], defaults to `127.0.0.1`.
+    pub fn with_advertised_host(mut self, host: &str) -> Self {
+        self.advertised_host = host.to_owned();
+        self
+    }
+
+    /// Sets the network alias of the container, which is advertised for the internal Kafka API (port 9093)
+    /// and HTTP proxy (port 8083) listeners, so other containers on the same network can connect via `<alias>:9093`.
+    pub fn with_internal_network_alias(mut self, alias: &str) -> Self {
+        self.internal_network_alias = Some(alias.to_owned());
+        self
+    }
+
+    fn config(&self) -> String {
+        let mut config = String::from(
+            "redpanda:\n  data_directory: /var/lib/redpanda/data\n  seed_servers: []\nrpk: {}\n",
+        );
+        if self.pandaproxy {
+            config.push_str("pandaproxy: {}\n");
+        }
+        if self.schema_registry {
+            config.push_str("schema_registry: {}\n");
+        }
+        config
+    }
+
+    fn start_args(
+        &self,
+        cs: &ContainerState,
+    ) -> Result<Vec<String>, testcontainers::TestcontainersError> {
+        let internal_host = self
+            .internal_network_alias
+            .as_deref()
+            .unwrap_or("localhost");
+        let mut args = vec![
+            "--mode dev-container".to_owned(),
+            "--smp 1".to_owned(),
+            format!(
+                "--kafka-addr internal://0.0.0.0:{INTERNAL_KAFKA_PORT},external://0.0.0.0:{}",
+                REDPANDA_KAFKA_PORT.as_u16()
+            ),
+            format!(
+                "--advertise-kafka-addr internal://{internal_host}:{INTERNAL_KAFKA_PORT},external://{}:{}",
+                self.advertised_host,
+                cs.host_port_ipv4(REDPANDA_KAFKA_PORT)?
+            ),
+        ];
+        if self.pandaproxy {
+            args.push(format!(
+                "--pandaproxy-addr internal://0.0.0.0:{INTERNAL_PANDAPROXY_PORT},external://0.0.0.0:{}",
+                REDPANDA_PANDAPROXY_PORT.as_u16()
+            ));
+            args.push(format!(
+                "--advertise-pandaproxy-addr internal://{internal_host}:{INTERNAL_PANDAPROXY_PORT},external://{}:{}",
+                self.advertised_host,
+                cs.host_port_ipv4(REDPANDA_PANDAPROXY_PORT)?
+            ));
+        }
+        if self.schema_registry {
+            args.push(format!(
+                "--schema-registry-addr 0.0.0.0:{}",
+                REDPANDA_SCHEMA_REGISTRY_PORT.as_u16()
+            ));
+        }
+        Ok(args)
+    }
+}
+
+impl Image for Redpanda {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn tag(&self) -> &str {
+        TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        // advertised addresses depend on the host-mapped ports, thus redpanda is
+        // only started from `exec_after_start`, where the actual wait is done
+        vec![]
+    }
+
+    fn entrypoint(&self) -> Option<&str> {
+        Some("bash")
+    }
+
+    fn cmd(&self) -> impl IntoIterator<Item = impl Into<Cow<'_, str>>> {
+        // waits until the start script is created in `exec_after_start`
+        vec![
+            "-c".to_string(),
+            format!("while [ ! -f {START_SCRIPT} ]; do sleep 0.1; done; chmod 755 {START_SCRIPT} && {START_SCRIPT}"),
+        ]
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        match (self.schema_registry, self.pandaproxy) {
+            (true, true) => &[
+                REDPANDA_KAFKA_PORT,
+                REDPANDA_SCHEMA_REGISTRY_PORT,
+                REDPANDA_PANDAPROXY_PORT,
+            ],
+            (true, false) => &[REDPANDA_KAFKA_PORT, REDPANDA_SCHEMA_REGISTRY_PORT],
+            (false, true) => &[REDPANDA_KAFKA_PORT, REDPANDA_PANDAPROXY_PORT],
+            (false, false) => &[REDPANDA_KAFKA_PORT],
+        }
+    }
+
+    fn exec_after_start(
+        &self,
+        cs: ContainerState,
+    ) -> Result<Vec<ExecCommand>, testcontainers::TestcontainersError> {
+        let script = format!(
+            "#!/usr/bin/env bash\ncat > {CONFIG_FILE} <<'EOF'\n{}EOF\nexec rpk redpanda start {}\n",
+            self.config(),
+            self.start_args(&cs)?.join(" ")
+        );
+        let cmd = vec![
+            "sh".to_string(),
+            "-c".to_string(),
+            format!("cat > {START_SCRIPT}.tmp <<'SCRIPT'\n{script}SCRIPT\nmv {START_SCRIPT}.tmp {START_SCRIPT}\n"),
+        ];
+        // the start script is executed by the `cmd` process,
+        // so the message has to be looked up in the container log
+        let ready_conditions = vec![WaitFor::message_on_stdout("Successfully started Redpanda!")];
+        Ok(vec![
+            ExecCommand::new(cmd).with_container_ready_conditions(ready_conditions)
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use rdkafka::{
+        consumer::{BaseConsumer, Consumer},
+        ClientConfig,
+    };
+
+    use super::*;
+    use crate::testcontainers::runners::SyncRunner;
+
+    #[test]
+    fn redpanda_advertises_host_port() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = Redpanda::default().start()?;
+        let host_port = node.get_host_port_ipv4(REDPANDA_KAFKA_PORT)?;
+
+        let consumer = ClientConfig::new()
+            .set("bootstrap.servers", format!("127.0.0.1:{host_port}"))
+            .create::<BaseConsumer>()
+            .expect("Failed to create Kafka BaseConsumer");
+        let metadata = consumer.fetch_metadata(None, Duration::from_secs(10))?;
+
+        assert_eq!(1, metadata.brokers().len());
+        assert_eq!(i32::from(host_port), metadata.brokers()[0].port());
+
+        let schema_registry_port = node.get_host_port_ipv4(REDPANDA_SCHEMA_REGISTRY_PORT)?;
+        let subjects: Vec<String> =
+            reqwest::blocking::get(format!("http://127.0.0.1:{schema_registry_port}/subjects"))?
+                .json()?;
+        assert!(subjects.is_empty());
+
+        let pandaproxy_port = node.get_host_port_ipv4(REDPANDA_PANDAPROXY_PORT)?;
+        let response =
+            reqwest::blocking::get(format!("http://127.0.0.1:{pandaproxy_port}/topics"))?;
+        assert!(response.status().is_success());
+        Ok(())
+    }
+
+    #[test]
+    fn redpanda_without_schema_registry_and_pandaproxy(
+    ) -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = Redpanda::default()
+            .with_schema_registry(false)
+            .with_pandaproxy(false)
+            .start()?;
+
+        assert!(node
+            .get_host_port_ipv4(REDPANDA_SCHEMA_REGISTRY_PORT)
+            .is_err());
+        assert!(node.get_host_port_ipv4(REDPANDA_PANDAPROXY_PORT).is_err());
+        node.get_host_port_ipv4(REDPANDA_KAFKA_PORT)?;
+        Ok(())
+    }
+}
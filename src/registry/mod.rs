@@ -0,0 +1,75 @@
+use testcontainers::{ContainerRequest, Image, ImageExt};
+
+/// Extension of all modules to pull their image from another registry, e.g. a mirror or a private registry.
+///
+/// The tag and the repository of a module are overridden with [`ImageExt::with_tag`] and
+/// [`ImageExt::with_name`], [`RegistryExt::with_registry`] keeps the repository of the module
+/// and only replaces its registry.
+///
+/// # Example
+/// ```rust,ignore
+/// use testcontainers_modules::{
+///     postgres::Postgres,
+///     registry::RegistryExt,
+///     testcontainers::{ContainerRequest, ImageExt},
+/// };
+///
+/// // pulls `mirror.internal:5000/postgres:16-alpine`
+/// let postgres: ContainerRequest<Postgres> = Postgres::default()
+///     .with_registry("mirror.internal:5000")
+///     .with_tag("16-alpine");
+/// ```
+pub trait RegistryExt<I: Image> {
+    /// Pulls the image of the module from the given registry (`host[:port]`) instead of its default registry.
+    /// The repository path of the module (e.g. `debezium/server` of `quay.io/debezium/server`) is kept as is,
+    /// so mirrors of Docker Hub have to serve official images without the `library/` prefix.
+    ///
+    /// As the name is derived from the module, an image name set with [`ImageExt::with_name`] is replaced.
+    fn with_registry(self, registry: &str) -> ContainerRequest<I>;
+}
+
+impl<RI: Into<ContainerRequest<I>>, I: Image> RegistryExt<I> for RI {
+    fn with_registry(self, registry: &str) -> ContainerRequest<I> {
+        let request = self.into();
+        let name = name_with_registry(request.image().name(), registry);
+        request.with_name(name)
+    }
+}
+
+fn name_with_registry(name: &str, registry: &str) -> String {
+    // the first component is a registry, if it is a host name (`quay.io`, `localhost`) or has a port
+    let repository = match name.split_once('/') {
+        Some((host, repository))
+            if host.contains('.') || host.contains(':') || host == "localhost" =>
+        {
+            repository
+        }
+        _ => name,
+    };
+    format!("{}/{repository}", registry.trim_end_matches('/'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn replaces_registry() {
+        assert_eq!(
+            name_with_registry("postgres", "mirror.internal:5000"),
+            "mirror.internal:5000/postgres"
+        );
+        assert_eq!(
+            name_with_registry("bitnami/spark", "mirror.internal:5000/"),
+            "mirror.internal:5000/bitnami/spark"
+        );
+        assert_eq!(
+            name_with_registry("quay.io/debezium/server", "mirror.internal"),
+            "mirror.internal/debezium/server"
+        );
+        assert_eq!(
+            name_with_registry("localhost/test", "mirror.internal"),
+            "mirror.internal/test"
+        );
+    }
+}
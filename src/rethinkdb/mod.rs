@@ -0,0 +1,128 @@
+use std::borrow::Cow;
+
+use testcontainers::{
+    core::{ContainerPort, WaitFor},
+    Image,
+};
+
+const NAME: &str = "rethinkdb";
+const TAG: &str = "2.4.4-bookworm-slim";
+
+/// Port of the client driver protocol that the [`RethinkDB`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`RethinkDB`]: https://rethinkdb.com/
+pub const RETHINKDB_PORT: ContainerPort = ContainerPort::Tcp(28015);
+
+/// Port of the web UI that the [`RethinkDB`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`RethinkDB`]: https://rethinkdb.com/
+pub const RETHINKDB_WEB_UI_PORT: ContainerPort = ContainerPort::Tcp(8080);
+
+/// Module to work with [`RethinkDB`] inside of tests.
+///
+/// Starts a single server of RethinkDB based on the official [`RethinkDB docker image`].
+/// Drivers connect on port 28015 ([`RETHINKDB_PORT`]) as the `admin` user, which has no password by default
+/// (see [`RethinkDb::with_admin_password`]). The web UI is served on port 8080 ([`RETHINKDB_WEB_UI_PORT`]).
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{
+///     rethinkdb::{RethinkDb, RETHINKDB_PORT},
+///     testcontainers::runners::SyncRunner,
+/// };
+///
+/// let rethinkdb_instance = RethinkDb::default().start().unwrap();
+/// let address = format!(
+///     "{}:{}",
+///     rethinkdb_instance.get_host().unwrap(),
+///     rethinkdb_instance.get_host_port_ipv4(RETHINKDB_PORT).unwrap()
+/// );
+/// ```
+///
+/// [`RethinkDB`]: https://rethinkdb.com/
+/// [`RethinkDB docker image`]: https://hub.docker.com/_/rethinkdb
+#[derive(Debug, Clone, Default)]
+pub struct RethinkDb {
+    admin_password: Option<String>,
+}
+
+impl RethinkDb {
+    /// Sets the password of the `admin` user on the first start (`--initial-password`).
+    pub fn with_admin_password(mut self, password: &str) -> Self {
+        self.admin_password = Some(password.to_owned());
+        self
+    }
+}
+
+impl Image for RethinkDb {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn tag(&self) -> &str {
+        TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![WaitFor::message_on_stdout("Server ready")]
+    }
+
+    fn cmd(&self) -> impl IntoIterator<Item = impl Into<Cow<'_, str>>> {
+        let mut cmd = vec![
+            "rethinkdb".to_owned(),
+            "--bind".to_owned(),
+            "all".to_owned(),
+        ];
+        if let Some(password) = &self.admin_password {
+            cmd.push("--initial-password".to_owned());
+            cmd.push(password.clone());
+        }
+        cmd
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[RETHINKDB_PORT, RETHINKDB_WEB_UI_PORT]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{Read, Write},
+        net::TcpStream,
+    };
+
+    use super::*;
+    use crate::testcontainers::runners::SyncRunner;
+
+    #[test]
+    fn rethinkdb_handshake() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = RethinkDb::default().with_admin_password("secret").start()?;
+
+        // V1_0 handshake: the server answers with a null terminated JSON message
+        let mut stream = TcpStream::connect((
+            node.get_host()?.to_string(),
+            node.get_host_port_ipv4(RETHINKDB_PORT)?,
+        ))?;
+        stream.write_all(&0x34c2bdc3_u32.to_le_bytes())?;
+        let mut response = Vec::new();
+        let mut byte = [0];
+        while stream.read(&mut byte)? == 1 && byte[0] != 0 {
+            response.push(byte[0]);
+        }
+        let response: serde_json::Value = serde_json::from_slice(&response)?;
+        assert_eq!(response["success"], true);
+
+        let url = format!(
+            "http://{}:{}",
+            node.get_host()?,
+            node.get_host_port_ipv4(RETHINKDB_WEB_UI_PORT)?
+        );
+        let response = reqwest::blocking::get(url)?;
+        assert!(response.status().is_success());
+        Ok(())
+    }
+}
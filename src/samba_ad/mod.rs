@@ -0,0 +1,214 @@
+use std::{borrow::Cow, collections::BTreeMap};
+
+use testcontainers::{
+    core::{ContainerPort, WaitFor},
+    Image,
+};
+
+const NAME: &str = "smblds/smblds";
+const TAG: &str = "4.21.4";
+
+/// Port of the LDAP listener that the [`Samba AD`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Samba AD`]: https://wiki.samba.org/index.php/Setting_up_Samba_as_an_Active_Directory_Domain_Controller
+pub const SAMBA_LDAP_PORT: ContainerPort = ContainerPort::Tcp(389);
+
+/// Port of the LDAPS listener that the [`Samba AD`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Samba AD`]: https://wiki.samba.org/index.php/Setting_up_Samba_as_an_Active_Directory_Domain_Controller
+pub const SAMBA_LDAPS_PORT: ContainerPort = ContainerPort::Tcp(636);
+
+/// Port of the Kerberos KDC that the [`Samba AD`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Samba AD`]: https://wiki.samba.org/index.php/Setting_up_Samba_as_an_Active_Directory_Domain_Controller
+pub const SAMBA_KERBEROS_PORT: ContainerPort = ContainerPort::Tcp(88);
+
+/// Port of the Kerberos KDC (UDP) that the [`Samba AD`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Samba AD`]: https://wiki.samba.org/index.php/Setting_up_Samba_as_an_Active_Directory_Domain_Controller
+pub const SAMBA_KERBEROS_UDP_PORT: ContainerPort = ContainerPort::Udp(88);
+
+/// Port of the Kerberos password change service that the [`Samba AD`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Samba AD`]: https://wiki.samba.org/index.php/Setting_up_Samba_as_an_Active_Directory_Domain_Controller
+pub const SAMBA_KPASSWD_PORT: ContainerPort = ContainerPort::Tcp(464);
+
+const DEFAULT_REALM: &str = "EXAMPLE.ORG";
+const DEFAULT_DOMAIN: &str = "EXAMPLE";
+const DEFAULT_ADMIN_PASSWORD: &str = "Testcontainers1!";
+
+/// Module to work with a [`Samba AD`] domain controller inside of tests.
+///
+/// Starts a Samba Active Directory domain controller based on the [`smblds docker image`],
+/// which provisions the domain on startup.
+///
+/// The realm defaults to `EXAMPLE.ORG` and the NetBIOS domain to `EXAMPLE`, see [`SambaAd::with_realm`]
+/// and [`SambaAd::with_domain`]. The domain is administrated by `Administrator` (see [`SambaAd::admin_dn`])
+/// with the password `Testcontainers1!` (see [`SambaAd::with_admin_password`]).
+/// Simple binds without TLS are allowed, so LDAP clients can connect on port 389 ([`SAMBA_LDAP_PORT`]).
+/// Kerberos clients connect to the KDC on port 88 ([`SAMBA_KERBEROS_PORT`], [`SAMBA_KERBEROS_UDP_PORT`]).
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{
+///     samba_ad::{SambaAd, SAMBA_LDAP_PORT},
+///     testcontainers::runners::SyncRunner,
+/// };
+///
+/// let samba_ad = SambaAd::default().with_realm("CORP.EXAMPLE.ORG");
+/// let bind_dn = samba_ad.admin_dn();
+/// let samba_instance = samba_ad.start().unwrap();
+/// let url = format!(
+///     "ldap://{}:{}",
+///     samba_instance.get_host().unwrap(),
+///     samba_instance.get_host_port_ipv4(SAMBA_LDAP_PORT).unwrap()
+/// );
+/// ```
+///
+/// [`Samba AD`]: https://wiki.samba.org/index.php/Setting_up_Samba_as_an_Active_Directory_Domain_Controller
+/// [`smblds docker image`]: https://hub.docker.com/r/smblds/smblds
+#[derive(Debug, Clone)]
+pub struct SambaAd {
+    env_vars: BTreeMap<String, String>,
+}
+
+impl Default for SambaAd {
+    fn default() -> Self {
+        let mut env_vars = BTreeMap::new();
+        env_vars.insert("REALM".to_owned(), DEFAULT_REALM.to_owned());
+        env_vars.insert("DOMAIN".to_owned(), DEFAULT_DOMAIN.to_owned());
+        env_vars.insert("ADMINPASS".to_owned(), DEFAULT_ADMIN_PASSWORD.to_owned());
+        env_vars.insert("INSECURELDAP".to_owned(), "true".to_owned());
+        Self { env_vars }
+    }
+}
+
+impl SambaAd {
+    /// Sets the Kerberos realm and DNS domain (`REALM`), defaults to `EXAMPLE.ORG`.
+    /// The base DN is derived from it, e.g. `DC=example,DC=org`.
+    pub fn with_realm(mut self, realm: &str) -> Self {
+        self.env_vars
+            .insert("REALM".to_owned(), realm.to_uppercase());
+        self
+    }
+
+    /// Sets the NetBIOS name of the domain (`DOMAIN`), defaults to `EXAMPLE`.
+    pub fn with_domain(mut self, domain: &str) -> Self {
+        self.env_vars
+            .insert("DOMAIN".to_owned(), domain.to_uppercase());
+        self
+    }
+
+    /// Sets the password of the `Administrator` (`ADMINPASS`), defaults to `Testcontainers1!`.
+    /// The password has to satisfy the default password complexity rules of Active Directory.
+    pub fn with_admin_password(mut self, password: &str) -> Self {
+        self.env_vars
+            .insert("ADMINPASS".to_owned(), password.to_owned());
+        self
+    }
+
+    /// Returns the Kerberos realm, e.g. `EXAMPLE.ORG`.
+    pub fn realm(&self) -> &str {
+        &self.env_vars["REALM"]
+    }
+
+    /// Returns the base DN of the directory, derived from the realm, e.g. `DC=example,DC=org`.
+    pub fn base_dn(&self) -> String {
+        self.realm()
+            .to_lowercase()
+            .split('.')
+            .map(|component| format!("DC={component}"))
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    /// Returns the DN of the `Administrator`, e.g. `CN=Administrator,CN=Users,DC=example,DC=org`.
+    pub fn admin_dn(&self) -> String {
+        format!("CN=Administrator,CN=Users,{}", self.base_dn())
+    }
+}
+
+impl Image for SambaAd {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn tag(&self) -> &str {
+        TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        // logged once all services (including LDAP and the KDC) have been started
+        vec![WaitFor::message_on_stdout(
+            "finished starting up and ready to serve connections",
+        )]
+    }
+
+    fn env_vars(
+        &self,
+    ) -> impl IntoIterator<Item = (impl Into<Cow<'_, str>>, impl Into<Cow<'_, str>>)> {
+        &self.env_vars
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[
+            SAMBA_LDAP_PORT,
+            SAMBA_LDAPS_PORT,
+            SAMBA_KERBEROS_PORT,
+            SAMBA_KERBEROS_UDP_PORT,
+            SAMBA_KPASSWD_PORT,
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ldap3::{LdapConnAsync, Scope, SearchEntry};
+    use testcontainers::runners::AsyncRunner;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn samba_ad_bind_as_admin() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let samba_ad = SambaAd::default()
+            .with_realm("corp.example.org")
+            .with_domain("corp")
+            .with_admin_password("Sup3rSecret!");
+        let base_dn = samba_ad.base_dn();
+        let admin_dn = samba_ad.admin_dn();
+        assert_eq!(base_dn, "DC=corp,DC=example,DC=org");
+        let node = samba_ad.start().await?;
+
+        let url = format!(
+            "ldap://{}:{}",
+            node.get_host().await?,
+            node.get_host_port_ipv4(SAMBA_LDAP_PORT).await?
+        );
+        let (conn, mut ldap) = LdapConnAsync::new(&url).await?;
+        ldap3::drive!(conn);
+        ldap.simple_bind(&admin_dn, "Sup3rSecret!")
+            .await?
+            .success()?;
+
+        let (entries, _) = ldap
+            .search(
+                &base_dn,
+                Scope::Subtree,
+                "(sAMAccountName=Administrator)",
+                vec!["sAMAccountName"],
+            )
+            .await?
+            .success()?;
+        let entries: Vec<_> = entries.into_iter().map(SearchEntry::construct).collect();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].dn.to_lowercase(), admin_dn.to_lowercase());
+        ldap.unbind().await?;
+        Ok(())
+    }
+}
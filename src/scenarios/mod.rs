@@ -0,0 +1,242 @@
+use std::{
+    any::Any,
+    future::Future,
+    pin::Pin,
+    sync::atomic::{AtomicUsize, Ordering},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use testcontainers::{
+    core::ContainerPort, runners::AsyncRunner, ContainerAsync, ContainerRequest, Image, ImageExt,
+    TestcontainersError,
+};
+
+type StartedContainer = Box<dyn Any + Send + Sync>;
+type StartFuture =
+    Pin<Box<dyn Future<Output = Result<StartedContainer, TestcontainersError>> + Send>>;
+type Starter = Box<dyn FnOnce(String, String) -> StartFuture + Send>;
+
+/// Composition of several modules, started on one docker network.
+///
+/// Every member is registered under an alias, which determines the host name of its container within
+/// the network (see [`Scenario::host_name`]). The host names are known before the start, thus they can be used
+/// to configure members depending on each other, e.g. the bootstrap servers of a schema registry or the
+/// database of a CDC connector. The members are started in registration order, so dependencies have to be
+/// registered first.
+///
+/// The network is named uniquely for every scenario, so the same scenario can run in parallel tests,
+/// see [`Scenario::with_name`] for a fixed name.
+///
+/// # Example
+/// ```rust,ignore
+/// use testcontainers_modules::{postgres::Postgres, redis::Redis, scenarios::Scenario};
+///
+/// let scenario = Scenario::new();
+/// let db_host = scenario.host_name("db");
+/// let containers = scenario
+///     .with("db", Postgres::default())
+///     .with("cache", Redis::default())
+///     .start()
+///     .await
+///     .unwrap();
+/// let postgres = containers.get::<Postgres>("db").unwrap();
+/// let intra_network_address = containers.address("db", 5432);
+/// ```
+pub struct Scenario {
+    name: String,
+    members: Vec<(String, Starter)>,
+}
+
+impl Default for Scenario {
+    fn default() -> Self {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.subsec_nanos())
+            .unwrap_or_default();
+        Self {
+            name: format!(
+                "testcontainers-scenario-{}-{}-{nanos}",
+                std::process::id(),
+                COUNTER.fetch_add(1, Ordering::Relaxed)
+            ),
+            members: Vec::new(),
+        }
+    }
+}
+
+impl std::fmt::Debug for Scenario {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Scenario")
+            .field("name", &self.name)
+            .field(
+                "members",
+                &self
+                    .members
+                    .iter()
+                    .map(|(alias, _)| alias)
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+impl Scenario {
+    /// Creates an empty scenario with a unique network name.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the name of the network, which is also the prefix of the host names of all members.
+    /// Has to be unique among the scenarios running at the same time.
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = name.to_owned();
+        self
+    }
+
+    /// Registers a module (or an already customized [`ContainerRequest`]) under the given alias.
+    /// The network and the container name of the module are set by the scenario.
+    pub fn with<I: Image + 'static>(
+        mut self,
+        alias: &str,
+        image: impl Into<ContainerRequest<I>>,
+    ) -> Self {
+        let request = image.into();
+        let starter: Starter = Box::new(move |network, container_name| {
+            Box::pin(async move {
+                let container = request
+                    .with_network(network)
+                    .with_container_name(container_name)
+                    .start()
+                    .await?;
+                Ok(Box::new(container) as StartedContainer)
+            })
+        });
+        self.members.push((alias.to_owned(), starter));
+        self
+    }
+
+    /// Returns the name of the network.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the host name of the member with the given alias, as reachable by the other members.
+    pub fn host_name(&self, alias: &str) -> String {
+        host_name(&self.name, alias)
+    }
+
+    /// Starts all members in registration order on a shared network.
+    /// Fails on the first member failing to start, the members started so far are dropped.
+    pub async fn start(self) -> Result<ScenarioContainers, TestcontainersError> {
+        let mut containers = Vec::with_capacity(self.members.len());
+        for (alias, starter) in self.members {
+            let container = starter(self.name.clone(), host_name(&self.name, &alias)).await?;
+            containers.push((alias, container));
+        }
+        Ok(ScenarioContainers {
+            name: self.name,
+            containers,
+        })
+    }
+}
+
+/// Containers of a started [`Scenario`], which are stopped and removed when dropped.
+pub struct ScenarioContainers {
+    name: String,
+    containers: Vec<(String, StartedContainer)>,
+}
+
+impl std::fmt::Debug for ScenarioContainers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ScenarioContainers")
+            .field("name", &self.name)
+            .field(
+                "containers",
+                &self
+                    .containers
+                    .iter()
+                    .map(|(alias, _)| alias)
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+impl ScenarioContainers {
+    /// Returns the container of the member with the given alias,
+    /// or `None` if there is no such member or it is not a container of `I`.
+    pub fn get<I: Image + 'static>(&self, alias: &str) -> Option<&ContainerAsync<I>> {
+        self.containers
+            .iter()
+            .find(|(member, _)| member == alias)
+            .and_then(|(_, container)| container.downcast_ref())
+    }
+
+    /// Returns the name of the network.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the host name of the member with the given alias, as reachable by the other members.
+    pub fn host_name(&self, alias: &str) -> String {
+        host_name(&self.name, alias)
+    }
+
+    /// Returns the address (`host:port`) of the given port of the member with the given alias,
+    /// as reachable by the other members.
+    pub fn address(&self, alias: &str, port: impl Into<ContainerPort>) -> String {
+        format!("{}:{}", self.host_name(alias), port.into().as_u16())
+    }
+}
+
+fn host_name(scenario: &str, alias: &str) -> String {
+    format!("{scenario}-{alias}")
+}
+
+#[cfg(test)]
+mod tests {
+    use testcontainers::{
+        core::{CmdWaitFor, ExecCommand, WaitFor},
+        GenericImage,
+    };
+
+    use super::*;
+
+    fn redis() -> GenericImage {
+        GenericImage::new("redis", "7.2.4")
+            .with_wait_for(WaitFor::message_on_stdout("Ready to accept connections"))
+    }
+
+    #[tokio::test]
+    async fn scenario_members_reach_each_other() -> Result<(), Box<dyn std::error::Error + 'static>>
+    {
+        let _ = pretty_env_logger::try_init();
+        let containers = Scenario::new()
+            .with("cache", redis())
+            .with("client", redis())
+            .start()
+            .await?;
+        assert!(containers.get::<GenericImage>("unknown").is_none());
+
+        let client = containers.get::<GenericImage>("client").unwrap();
+        let mut result = client
+            .exec(
+                ExecCommand::new([
+                    "redis-cli".to_owned(),
+                    "-h".to_owned(),
+                    containers.host_name("cache"),
+                    "ping".to_owned(),
+                ])
+                .with_cmd_ready_condition(CmdWaitFor::exit_code(0)),
+            )
+            .await?;
+        let stdout = String::from_utf8(result.stdout_to_vec().await?)?;
+        assert_eq!(stdout.trim(), "PONG");
+        assert_eq!(
+            containers.address("cache", 6379),
+            format!("{}-cache:6379", containers.name())
+        );
+        Ok(())
+    }
+}
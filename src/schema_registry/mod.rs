@@ -0,0 +1,193 @@
+use std::{borrow::Cow, collections::BTreeMap};
+
+use testcontainers::{
+    core::{wait::HttpWaitStrategy, ContainerPort, WaitFor},
+    Image,
+};
+
+const NAME: &str = "confluentinc/cp-schema-registry";
+const TAG: &str = "7.7.1";
+
+/// Port of the REST API that the [`Schema Registry`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Schema Registry`]: https://docs.confluent.io/platform/current/schema-registry/index.html
+pub const SCHEMA_REGISTRY_PORT: ContainerPort = ContainerPort::Tcp(8081);
+
+/// Module to work with the Confluent [`Schema Registry`] inside of tests.
+///
+/// Starts an instance of Schema Registry based on the official [`Schema Registry docker image`],
+/// serving the REST API on port 8081 ([`SCHEMA_REGISTRY_PORT`]).
+///
+/// The schemas are stored in a Kafka topic, thus the registry has to reach a Kafka broker,
+/// usually another container in the same network (see [`testcontainers::core::ImageExt::with_network`]),
+/// whose address is set with [`SchemaRegistry::with_bootstrap_servers`]. It defaults to `kafka:9093`,
+/// the internal listener of `kafka::apache::Kafka` started with the network alias `kafka`.
+///
+/// # Example
+/// ```rust,ignore
+/// use testcontainers_modules::{
+///     kafka::apache,
+///     schema_registry::{SchemaRegistry, SCHEMA_REGISTRY_PORT},
+///     testcontainers::{runners::SyncRunner, ImageExt},
+/// };
+///
+/// let _kafka_node = apache::Kafka::default()
+///     .with_internal_network_alias("kafka")
+///     .with_network("schema-registry-example")
+///     .with_container_name("kafka")
+///     .start()
+///     .unwrap();
+/// let schema_registry_node = SchemaRegistry::default()
+///     .with_bootstrap_servers("kafka:9093")
+///     .with_network("schema-registry-example")
+///     .start()
+///     .unwrap();
+/// let url = format!(
+///     "http://{}:{}",
+///     schema_registry_node.get_host().unwrap(),
+///     schema_registry_node
+///         .get_host_port_ipv4(SCHEMA_REGISTRY_PORT)
+///         .unwrap()
+/// );
+/// ```
+///
+/// [`Schema Registry`]: https://docs.confluent.io/platform/current/schema-registry/index.html
+/// [`Schema Registry docker image`]: https://hub.docker.com/r/confluentinc/cp-schema-registry
+#[derive(Debug, Clone)]
+pub struct SchemaRegistry {
+    env_vars: BTreeMap<String, String>,
+}
+
+impl Default for SchemaRegistry {
+    fn default() -> Self {
+        let mut env_vars = BTreeMap::new();
+        env_vars.insert(
+            "SCHEMA_REGISTRY_HOST_NAME".to_owned(),
+            "schema-registry".to_owned(),
+        );
+        env_vars.insert(
+            "SCHEMA_REGISTRY_LISTENERS".to_owned(),
+            format!("http://0.0.0.0:{}", SCHEMA_REGISTRY_PORT.as_u16()),
+        );
+        env_vars.insert(
+            "SCHEMA_REGISTRY_KAFKASTORE_BOOTSTRAP_SERVERS".to_owned(),
+            "PLAINTEXT://kafka:9093".to_owned(),
+        );
+        Self { env_vars }
+    }
+}
+
+impl SchemaRegistry {
+    /// Sets the Kafka brokers storing the schemas (`SCHEMA_REGISTRY_KAFKASTORE_BOOTSTRAP_SERVERS`),
+    /// a comma separated list of `host:port`, as reachable from inside of the container.
+    pub fn with_bootstrap_servers(mut self, bootstrap_servers: &str) -> Self {
+        let bootstrap_servers = bootstrap_servers
+            .split(',')
+            .map(|server| {
+                if server.contains("://") {
+                    server.to_owned()
+                } else {
+                    format!("PLAINTEXT://{server}")
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+        self.env_vars.insert(
+            "SCHEMA_REGISTRY_KAFKASTORE_BOOTSTRAP_SERVERS".to_owned(),
+            bootstrap_servers,
+        );
+        self
+    }
+
+    /// Sets the name of the Kafka topic storing the schemas (`SCHEMA_REGISTRY_KAFKASTORE_TOPIC`),
+    /// defaults to `_schemas`. Useful to share a broker between several registries.
+    pub fn with_schemas_topic(mut self, topic: &str) -> Self {
+        self.env_vars.insert(
+            "SCHEMA_REGISTRY_KAFKASTORE_TOPIC".to_owned(),
+            topic.to_owned(),
+        );
+        self
+    }
+}
+
+impl Image for SchemaRegistry {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn tag(&self) -> &str {
+        TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![WaitFor::http(
+            HttpWaitStrategy::new("/subjects")
+                .with_port(SCHEMA_REGISTRY_PORT)
+                .with_expected_status_code(200_u16),
+        )]
+    }
+
+    fn env_vars(
+        &self,
+    ) -> impl IntoIterator<Item = (impl Into<Cow<'_, str>>, impl Into<Cow<'_, str>>)> {
+        &self.env_vars
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[SCHEMA_REGISTRY_PORT]
+    }
+}
+
+#[cfg(all(test, feature = "kafka"))]
+mod tests {
+    use super::*;
+    use crate::{
+        kafka::apache,
+        testcontainers::{runners::SyncRunner, ImageExt},
+    };
+
+    #[test]
+    fn schema_registry_register_schema() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let network = "testcontainers-schema-registry";
+        let kafka_alias = "testcontainers-schema-registry-kafka";
+        let _kafka_node = apache::Kafka::default()
+            .with_internal_network_alias(kafka_alias)
+            .with_network(network)
+            .with_container_name(kafka_alias)
+            .start()?;
+        let node = SchemaRegistry::default()
+            .with_bootstrap_servers(&format!("{kafka_alias}:9093"))
+            .with_network(network)
+            .start()?;
+        let url = format!(
+            "http://{}:{}",
+            node.get_host()?,
+            node.get_host_port_ipv4(SCHEMA_REGISTRY_PORT)?
+        );
+        let client = reqwest::blocking::Client::new();
+
+        let schema = serde_json::json!({
+            "type": "record",
+            "name": "User",
+            "fields": [{ "name": "name", "type": "string" }]
+        });
+        let registered: serde_json::Value = client
+            .post(format!("{url}/subjects/users-value/versions"))
+            .header("Content-Type", "application/vnd.schemaregistry.v1+json")
+            .json(&serde_json::json!({ "schema": schema.to_string() }))
+            .send()?
+            .error_for_status()?
+            .json()?;
+        assert!(registered["id"].is_number());
+
+        let subjects: serde_json::Value = client
+            .get(format!("{url}/subjects"))
+            .send()?
+            .error_for_status()?
+            .json()?;
+        assert_eq!(subjects, serde_json::json!(["users-value"]));
+        Ok(())
+    }
+}
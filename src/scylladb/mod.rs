@@ -0,0 +1,135 @@
+use std::borrow::Cow;
+
+use testcontainers::{
+    core::{ContainerPort, WaitFor},
+    Image,
+};
+
+const NAME: &str = "scylladb/scylla";
+const TAG: &str = "6.2";
+
+/// Port of the CQL native transport that the [`ScyllaDB`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`ScyllaDB`]: https://www.scylladb.com/
+pub const SCYLLA_PORT: ContainerPort = ContainerPort::Tcp(9042);
+
+/// Module to work with [`ScyllaDB`] inside of tests.
+///
+/// Starts a single node instance of ScyllaDB based on the official [`ScyllaDB docker image`].
+///
+/// For a fast startup the node is started with a single shard (`--smp 1`) in developer mode
+/// (`--developer-mode 1`), see [`Scylla::with_smp`] and [`Scylla::with_memory`] to tune the resources.
+/// CQL clients can connect on port 9042 ([`SCYLLA_PORT`]).
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{
+///     scylladb::{Scylla, SCYLLA_PORT},
+///     testcontainers::runners::SyncRunner,
+/// };
+///
+/// let scylla_instance = Scylla::default().start().unwrap();
+/// let contact_point = format!(
+///     "{}:{}",
+///     scylla_instance.get_host().unwrap(),
+///     scylla_instance.get_host_port_ipv4(SCYLLA_PORT).unwrap()
+/// );
+/// ```
+///
+/// [`ScyllaDB`]: https://www.scylladb.com/
+/// [`ScyllaDB docker image`]: https://hub.docker.com/r/scylladb/scylla
+#[derive(Debug, Clone)]
+pub struct Scylla {
+    smp: u16,
+    memory: Option<String>,
+    developer_mode: bool,
+}
+
+impl Default for Scylla {
+    fn default() -> Self {
+        Self {
+            smp: 1,
+            memory: None,
+            developer_mode: true,
+        }
+    }
+}
+
+impl Scylla {
+    /// Sets the number of shards (CPU cores) used by the node (`--smp`), defaults to 1.
+    pub fn with_smp(mut self, smp: u16) -> Self {
+        self.smp = smp;
+        self
+    }
+
+    /// Sets the amount of memory used by the node (`--memory`), e.g. `1G` or `750M`.
+    /// By default the node uses all memory available to the container.
+    pub fn with_memory(mut self, memory: &str) -> Self {
+        self.memory = Some(memory.to_owned());
+        self
+    }
+
+    /// Enables or disables the developer mode (`--developer-mode`), which skips the checks
+    /// of the host configuration. Enabled by default.
+    pub fn with_developer_mode(mut self, enabled: bool) -> Self {
+        self.developer_mode = enabled;
+        self
+    }
+}
+
+impl Image for Scylla {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn tag(&self) -> &str {
+        TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![WaitFor::message_on_stdout(
+            "Starting listening for CQL clients",
+        )]
+    }
+
+    fn cmd(&self) -> impl IntoIterator<Item = impl Into<Cow<'_, str>>> {
+        let mut cmd = vec![
+            "--smp".to_owned(),
+            self.smp.to_string(),
+            "--developer-mode".to_owned(),
+            u8::from(self.developer_mode).to_string(),
+        ];
+        if let Some(memory) = &self.memory {
+            cmd.push("--memory".to_owned());
+            cmd.push(memory.clone());
+        }
+        cmd
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[SCYLLA_PORT]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use testcontainers::core::{CmdWaitFor, ExecCommand};
+
+    use super::*;
+    use crate::testcontainers::runners::SyncRunner;
+
+    #[test]
+    fn scylla_select_release_version() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = Scylla::default().with_memory("750M").start()?;
+
+        let mut result = node.exec(
+            ExecCommand::new(["cqlsh", "-e", "SELECT release_version FROM system.local;"])
+                .with_cmd_ready_condition(CmdWaitFor::exit_code(0)),
+        )?;
+        let stdout = String::from_utf8(result.stdout_to_vec()?)?;
+        assert!(stdout.contains("(1 rows)"));
+        Ok(())
+    }
+}
@@ -0,0 +1,370 @@
+use std::{borrow::Cow, collections::BTreeMap};
+
+use testcontainers::{
+    core::{CmdWaitFor, ContainerPort, ContainerState, ExecCommand, WaitFor},
+    ContainerRequest, Image, ImageExt, TestcontainersError,
+};
+
+const SFTP_NAME: &str = "atmoz/sftp";
+const SFTP_TAG: &str = "alpine-3.7";
+const FTP_NAME: &str = "delfer/alpine-ftp-server";
+const FTP_TAG: &str = "3.20";
+const DEFAULT_PASSIVE_ADDRESS: &str = "127.0.0.1";
+/// Maximum time to wait for the FTP server to accept connections, before the start is considered failed.
+const READY_TIMEOUT_SECS: u64 = 60;
+
+/// Port of the SSH server that the [`SftpServer`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+pub const SFTP_PORT: ContainerPort = ContainerPort::Tcp(22);
+
+/// Port of the FTP control connection that the [`FtpServer`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+pub const FTP_PORT: ContainerPort = ContainerPort::Tcp(21);
+
+/// Ports of the FTP passive mode data connections that the [`FtpServer`] container has internally
+pub const FTP_PASSIVE_PORTS: [ContainerPort; 11] = [
+    ContainerPort::Tcp(21000),
+    ContainerPort::Tcp(21001),
+    ContainerPort::Tcp(21002),
+    ContainerPort::Tcp(21003),
+    ContainerPort::Tcp(21004),
+    ContainerPort::Tcp(21005),
+    ContainerPort::Tcp(21006),
+    ContainerPort::Tcp(21007),
+    ContainerPort::Tcp(21008),
+    ContainerPort::Tcp(21009),
+    ContainerPort::Tcp(21010),
+];
+
+#[derive(Debug, Clone)]
+struct User {
+    name: String,
+    password: String,
+    uid: u32,
+    dir: String,
+}
+
+/// Module to work with an [`SFTP`] server inside of tests.
+///
+/// Starts an OpenSSH server restricted to SFTP, based on the [`atmoz/sftp docker image`].
+/// Clients connect on port 22 ([`SFTP_PORT`]) with the users registered with [`SftpServer::with_user`],
+/// which are chrooted to their home directory `/home/<name>`.
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{
+///     sftp::{SftpServer, SFTP_PORT},
+///     testcontainers::runners::SyncRunner,
+/// };
+///
+/// let sftp_instance = SftpServer::default()
+///     .with_user("foo", "pass", 1001, "upload")
+///     .start()
+///     .unwrap();
+/// let address = format!(
+///     "{}:{}",
+///     sftp_instance.get_host().unwrap(),
+///     sftp_instance.get_host_port_ipv4(SFTP_PORT).unwrap()
+/// );
+/// ```
+///
+/// [`SFTP`]: https://www.openssh.com/
+/// [`atmoz/sftp docker image`]: https://hub.docker.com/r/atmoz/sftp
+#[derive(Debug, Clone, Default)]
+pub struct SftpServer {
+    users: Vec<User>,
+}
+
+impl SftpServer {
+    /// Registers a user with the given password and uid, whose writable directory `dir`
+    /// is created in the home directory (`/home/<name>/<dir>`).
+    /// Can be called multiple times to add (not override) users.
+    pub fn with_user(mut self, name: &str, password: &str, uid: u32, dir: &str) -> Self {
+        self.users.push(User {
+            name: name.to_owned(),
+            password: password.to_owned(),
+            uid,
+            dir: dir.to_owned(),
+        });
+        self
+    }
+}
+
+impl Image for SftpServer {
+    fn name(&self) -> &str {
+        SFTP_NAME
+    }
+
+    fn tag(&self) -> &str {
+        SFTP_TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![WaitFor::message_on_stderr(
+            "Server listening on 0.0.0.0 port 22",
+        )]
+    }
+
+    fn cmd(&self) -> impl IntoIterator<Item = impl Into<Cow<'_, str>>> {
+        // `user:pass:uid:gid:dir`, the gid defaults to the uid
+        self.users
+            .iter()
+            .map(|user| format!("{}:{}:{}::{}", user.name, user.password, user.uid, user.dir))
+            .collect::<Vec<_>>()
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[SFTP_PORT]
+    }
+}
+
+/// Module to work with a plain [`FTP`] server inside of tests.
+///
+/// Starts a vsftpd server based on the [`alpine-ftp-server docker image`].
+/// Clients connect on port 21 ([`FTP_PORT`]) with the users registered with [`FtpServer::with_user`].
+///
+/// In passive mode the server tells the clients to connect to one of the ports 21000 to 21010
+/// ([`FTP_PASSIVE_PORTS`]) of the address set with [`FtpServer::with_passive_address`]. As these ports
+/// are used as is, they have to be mapped to the same ports on the host to transfer files from the host,
+/// see [`FtpServer::with_passive_mode`].
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{
+///     sftp::{FtpServer, FTP_PORT},
+///     testcontainers::runners::SyncRunner,
+/// };
+///
+/// let ftp_instance = FtpServer::default()
+///     .with_user("foo", "pass", 1001, "/ftp/foo")
+///     .start()
+///     .unwrap();
+/// let address = format!(
+///     "{}:{}",
+///     ftp_instance.get_host().unwrap(),
+///     ftp_instance.get_host_port_ipv4(FTP_PORT).unwrap()
+/// );
+/// ```
+///
+/// [`FTP`]: https://security.appspot.com/vsftpd.html
+/// [`alpine-ftp-server docker image`]: https://hub.docker.com/r/delfer/alpine-ftp-server
+#[derive(Debug, Clone, Default)]
+pub struct FtpServer {
+    users: Vec<User>,
+    env_vars: BTreeMap<String, String>,
+}
+
+impl FtpServer {
+    /// Registers a user with the given password and uid, whose directory is `dir`
+    /// (an absolute path inside of the container).
+    /// Can be called multiple times to add (not override) users.
+    pub fn with_user(mut self, name: &str, password: &str, uid: u32, dir: &str) -> Self {
+        self.users.push(User {
+            name: name.to_owned(),
+            password: password.to_owned(),
+            uid,
+            dir: dir.to_owned(),
+        });
+        // `name|password|dir|uid`, separated by spaces
+        let users = self
+            .users
+            .iter()
+            .map(|user| format!("{}|{}|{}|{}", user.name, user.password, user.dir, user.uid))
+            .collect::<Vec<_>>()
+            .join(" ");
+        self.env_vars.insert("USERS".to_owned(), users);
+        self
+    }
+
+    /// Sets the address announced to clients for passive mode connections (`ADDRESS`,
+    /// used as `pasv_address` of vsftpd), e.g. the ip of the host running the container.
+    /// Defaults to `127.0.0.1` when enabling [`FtpServer::with_passive_mode`].
+    pub fn with_passive_address(mut self, address: &str) -> Self {
+        self.env_vars
+            .insert("ADDRESS".to_owned(), address.to_owned());
+        self
+    }
+
+    /// Enables file transfers in passive mode from the host, by exposing the passive mode ports
+    /// ([`FTP_PASSIVE_PORTS`]) on the same ports of the host, as announced by the server.
+    ///
+    /// Returns a [`ContainerRequest`], thus it has to be called after the other builder methods.
+    /// Note that only one container using passive mode can run on a host at the same time.
+    ///
+    /// # Example
+    /// ```
+    /// use testcontainers_modules::{sftp::FtpServer, testcontainers::runners::SyncRunner};
+    ///
+    /// let ftp_instance = FtpServer::default()
+    ///     .with_user("foo", "pass", 1001, "/ftp/foo")
+    ///     .with_passive_mode()
+    ///     .start()
+    ///     .unwrap();
+    /// ```
+    pub fn with_passive_mode(mut self) -> ContainerRequest<Self> {
+        self.env_vars
+            .entry("ADDRESS".to_owned())
+            .or_insert_with(|| DEFAULT_PASSIVE_ADDRESS.to_owned());
+        FTP_PASSIVE_PORTS
+            .iter()
+            .fold(ContainerRequest::from(self), |request, port| {
+                request.with_mapped_port(port.as_u16(), *port)
+            })
+    }
+}
+
+impl Image for FtpServer {
+    fn name(&self) -> &str {
+        FTP_NAME
+    }
+
+    fn tag(&self) -> &str {
+        FTP_TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        // vsftpd does not log when it accepts connections, see `exec_after_start`
+        vec![]
+    }
+
+    fn env_vars(
+        &self,
+    ) -> impl IntoIterator<Item = (impl Into<Cow<'_, str>>, impl Into<Cow<'_, str>>)> {
+        &self.env_vars
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[FTP_PORT]
+    }
+
+    fn exec_after_start(
+        &self,
+        _cs: ContainerState,
+    ) -> Result<Vec<ExecCommand>, TestcontainersError> {
+        Ok(vec![ExecCommand::new([
+            "timeout".to_string(),
+            READY_TIMEOUT_SECS.to_string(),
+            "sh".to_string(),
+            "-c".to_string(),
+            format!(
+                "until nc -z 127.0.0.1 {}; do sleep 0.1; done",
+                FTP_PORT.as_u16()
+            ),
+        ])
+        .with_cmd_ready_condition(CmdWaitFor::exit_code(0))])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpStream,
+    };
+
+    use super::*;
+    use crate::testcontainers::runners::SyncRunner;
+
+    #[test]
+    fn sftp_server_upload_and_list() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = SftpServer::default()
+            .with_user("foo", "pass", 1001, "upload")
+            .start()?;
+
+        let mut stream = TcpStream::connect((
+            node.get_host()?.to_string(),
+            node.get_host_port_ipv4(SFTP_PORT)?,
+        ))?;
+        let mut banner = [0; 8];
+        stream.read_exact(&mut banner)?;
+        assert_eq!(&banner, b"SSH-2.0-");
+
+        // authorize a key of the user, as the sftp client cannot pass a password in batch mode
+        node.exec(
+            ExecCommand::new([
+                "sh",
+                "-c",
+                "ssh-keygen -q -t ed25519 -N '' -f /tmp/id_foo \
+                 && mkdir -p /home/foo/.ssh \
+                 && cp /tmp/id_foo.pub /home/foo/.ssh/authorized_keys \
+                 && chown -R foo /home/foo/.ssh \
+                 && chmod 700 /home/foo/.ssh \
+                 && chmod 600 /home/foo/.ssh/authorized_keys \
+                 && echo testcontainers > /tmp/hello.txt",
+            ])
+            .with_cmd_ready_condition(CmdWaitFor::exit_code(0)),
+        )?;
+        let mut result = node.exec(
+            ExecCommand::new([
+                "sh",
+                "-c",
+                "printf 'put /tmp/hello.txt upload/hello.txt\\nls upload\\n' \
+                 | sftp -b - -i /tmp/id_foo -o StrictHostKeyChecking=no foo@127.0.0.1",
+            ])
+            .with_cmd_ready_condition(CmdWaitFor::exit_code(0)),
+        )?;
+        let listing = String::from_utf8(result.stdout_to_vec()?)?;
+        assert!(listing.contains("upload/hello.txt"), "{listing}");
+
+        let mut result = node.exec(
+            ExecCommand::new(["stat", "-c", "%u %s", "/home/foo/upload/hello.txt"])
+                .with_cmd_ready_condition(CmdWaitFor::exit_code(0)),
+        )?;
+        let stat = String::from_utf8(result.stdout_to_vec()?)?;
+        assert_eq!(stat.trim(), "1001 15");
+        Ok(())
+    }
+
+    #[test]
+    fn ftp_server_upload_and_list() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = FtpServer::default()
+            .with_user("foo", "pass", 1001, "/ftp/foo")
+            .with_passive_mode()
+            .start()?;
+        let host = node.get_host()?.to_string();
+
+        let stream = TcpStream::connect((host.as_str(), node.get_host_port_ipv4(FTP_PORT)?))?;
+        let mut reader = BufReader::new(stream.try_clone()?);
+        let mut writer = stream;
+        let mut command = |command: &str, code: &str| -> Result<String, std::io::Error> {
+            if !command.is_empty() {
+                writer.write_all(format!("{command}\r\n").as_bytes())?;
+            }
+            let mut reply = String::new();
+            reader.read_line(&mut reply)?;
+            assert!(reply.starts_with(code), "{command}: {reply}");
+            Ok(reply)
+        };
+        // `227 Entering Passive Mode (h1,h2,h3,h4,p1,p2).`
+        let passive_port = |reply: &str| -> u16 {
+            let numbers: Vec<u16> = reply[reply.find('(').unwrap() + 1..reply.find(')').unwrap()]
+                .split(',')
+                .map(|number| number.parse().unwrap())
+                .collect();
+            numbers[4] * 256 + numbers[5]
+        };
+
+        command("", "220")?;
+        command("USER foo", "331")?;
+        command("PASS pass", "230")?;
+        command("TYPE I", "200")?;
+
+        let reply = command("PASV", "227")?;
+        let mut data = TcpStream::connect((host.as_str(), passive_port(&reply)))?;
+        command("STOR hello.txt", "150")?;
+        data.write_all(b"testcontainers\n")?;
+        drop(data);
+        command("", "226")?;
+
+        let reply = command("PASV", "227")?;
+        let mut data = TcpStream::connect((host.as_str(), passive_port(&reply)))?;
+        command("NLST", "150")?;
+        let mut listing = String::new();
+        data.read_to_string(&mut listing)?;
+        command("", "226")?;
+        assert_eq!(listing.trim(), "hello.txt");
+        Ok(())
+    }
+}
@@ -0,0 +1,170 @@
+use std::{borrow::Cow, collections::BTreeMap};
+
+use testcontainers::{
+    core::{ContainerPort, WaitFor},
+    Image,
+};
+
+const NAME: &str = "solace/solace-pubsub-standard";
+const TAG: &str = "10.10";
+
+/// Port of the Solace Message Format (SMF) protocol that the [`Solace PubSub+`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Solace PubSub+`]: https://solace.com/products/event-broker/software/
+pub const SOLACE_SMF_PORT: ContainerPort = ContainerPort::Tcp(55555);
+
+/// Port of the AMQP 1.0 protocol that the [`Solace PubSub+`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Solace PubSub+`]: https://solace.com/products/event-broker/software/
+pub const SOLACE_AMQP_PORT: ContainerPort = ContainerPort::Tcp(5672);
+
+/// Port of the MQTT protocol that the [`Solace PubSub+`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Solace PubSub+`]: https://solace.com/products/event-broker/software/
+pub const SOLACE_MQTT_PORT: ContainerPort = ContainerPort::Tcp(1883);
+
+/// Port of the management API (SEMP) and the broker manager that the [`Solace PubSub+`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Solace PubSub+`]: https://solace.com/products/event-broker/software/
+pub const SOLACE_SEMP_PORT: ContainerPort = ContainerPort::Tcp(8080);
+
+/// Module to work with [`Solace PubSub+`] inside of tests.
+///
+/// Starts a software event broker based on the official [`Solace PubSub+ Standard docker image`].
+/// Clients connect with SMF on port 55555 ([`SOLACE_SMF_PORT`]), AMQP 1.0 on port 5672 ([`SOLACE_AMQP_PORT`])
+/// or MQTT on port 1883 ([`SOLACE_MQTT_PORT`]) to the `default` message VPN. The management API (SEMP) is served
+/// on port 8080 ([`SOLACE_SEMP_PORT`]) for the `admin` user with the password `admin`, see [`SolacePubSub::with_admin_password`].
+///
+/// The broker requires a shared memory of at least 1GB (see [`testcontainers::core::ImageExt::with_shm_size`]).
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{
+///     solace::{SolacePubSub, SOLACE_SMF_PORT},
+///     testcontainers::{runners::SyncRunner, ImageExt},
+/// };
+///
+/// let solace_instance = SolacePubSub::default()
+///     .with_shm_size(1_000_000_000)
+///     .start()
+///     .unwrap();
+/// let smf_url = format!(
+///     "tcp://{}:{}",
+///     solace_instance.get_host().unwrap(),
+///     solace_instance.get_host_port_ipv4(SOLACE_SMF_PORT).unwrap()
+/// );
+/// ```
+///
+/// [`Solace PubSub+`]: https://solace.com/products/event-broker/software/
+/// [`Solace PubSub+ Standard docker image`]: https://hub.docker.com/r/solace/solace-pubsub-standard
+#[derive(Debug, Clone)]
+pub struct SolacePubSub {
+    env_vars: BTreeMap<String, String>,
+}
+
+impl Default for SolacePubSub {
+    fn default() -> Self {
+        let mut env_vars = BTreeMap::new();
+        env_vars.insert(
+            "username_admin_globalaccesslevel".to_owned(),
+            "admin".to_owned(),
+        );
+        env_vars.insert("username_admin_password".to_owned(), "admin".to_owned());
+        env_vars.insert(
+            "system_scaling_maxconnectioncount".to_owned(),
+            "100".to_owned(),
+        );
+        Self { env_vars }
+    }
+}
+
+impl SolacePubSub {
+    /// Sets the password of the `admin` user (`username_admin_password`), defaults to `admin`.
+    pub fn with_admin_password(mut self, password: &str) -> Self {
+        self.env_vars
+            .insert("username_admin_password".to_owned(), password.to_owned());
+        self
+    }
+
+    /// Sets the maximum number of client connections the broker is scaled for
+    /// (`system_scaling_maxconnectioncount`), one of 100 (default), 1000, 10000, 100000 or 200000.
+    /// Higher tiers require more memory and shared memory.
+    pub fn with_max_connections(mut self, max_connections: u32) -> Self {
+        self.env_vars.insert(
+            "system_scaling_maxconnectioncount".to_owned(),
+            max_connections.to_string(),
+        );
+        self
+    }
+}
+
+impl Image for SolacePubSub {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn tag(&self) -> &str {
+        TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![WaitFor::message_on_stdout(
+            "Primary Virtual Router is now active",
+        )]
+    }
+
+    fn env_vars(
+        &self,
+    ) -> impl IntoIterator<Item = (impl Into<Cow<'_, str>>, impl Into<Cow<'_, str>>)> {
+        &self.env_vars
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[
+            SOLACE_SMF_PORT,
+            SOLACE_AMQP_PORT,
+            SOLACE_MQTT_PORT,
+            SOLACE_SEMP_PORT,
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testcontainers::{runners::SyncRunner, ImageExt};
+
+    #[test]
+    fn solace_semp_message_vpn() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = SolacePubSub::default()
+            .with_admin_password("secret")
+            .with_shm_size(1_000_000_000)
+            .start()?;
+        let url = format!(
+            "http://{}:{}",
+            node.get_host()?,
+            node.get_host_port_ipv4(SOLACE_SEMP_PORT)?
+        );
+
+        let client = reqwest::blocking::Client::new();
+        let response = client
+            .get(format!("{url}/SEMP/v2/config/msgVpns/default"))
+            .basic_auth("admin", Some("wrong"))
+            .send()?;
+        assert_eq!(response.status(), 401);
+
+        let vpn: serde_json::Value = client
+            .get(format!("{url}/SEMP/v2/config/msgVpns/default"))
+            .basic_auth("admin", Some("secret"))
+            .send()?
+            .error_for_status()?
+            .json()?;
+        assert_eq!(vpn["data"]["msgVpnName"], "default");
+        Ok(())
+    }
+}
@@ -0,0 +1,205 @@
+use std::{borrow::Cow, collections::BTreeMap};
+
+use testcontainers::{
+    core::{ContainerPort, WaitFor},
+    Image,
+};
+
+const NAME: &str = "bitnami/spark";
+const TAG: &str = "3.5.3";
+
+/// Port of the master that the [`Spark`] container has internally, used by workers and drivers (`spark://`)
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Spark`]: https://spark.apache.org/
+pub const SPARK_MASTER_PORT: ContainerPort = ContainerPort::Tcp(7077);
+
+/// Port of the web UI that the [`Spark`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Spark`]: https://spark.apache.org/
+pub const SPARK_WEB_UI_PORT: ContainerPort = ContainerPort::Tcp(8080);
+
+/// Module to work with the master of an [`Apache Spark`] standalone cluster inside of tests.
+///
+/// Starts a master based on the [`Bitnami Spark docker image`], accepting workers and drivers on port 7077
+/// ([`SPARK_MASTER_PORT`]) and serving the web UI on port 8080 ([`SPARK_WEB_UI_PORT`]).
+///
+/// A master on its own cannot run any application, thus at least one [`SparkWorker`] has to be started
+/// in the same network (see [`testcontainers::core::ImageExt::with_network`]), whose master url
+/// points to the container name or network alias of the master.
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{
+///     spark::{Spark, SparkWorker, SPARK_WEB_UI_PORT},
+///     testcontainers::{runners::SyncRunner, ImageExt},
+/// };
+///
+/// let master_instance = Spark::default()
+///     .with_network("spark-example")
+///     .with_container_name("spark")
+///     .start()
+///     .unwrap();
+/// let _worker_instance = SparkWorker::default()
+///     .with_master_url("spark://spark:7077")
+///     .with_network("spark-example")
+///     .start()
+///     .unwrap();
+/// let web_ui = format!(
+///     "http://{}:{}",
+///     master_instance.get_host().unwrap(),
+///     master_instance.get_host_port_ipv4(SPARK_WEB_UI_PORT).unwrap()
+/// );
+/// ```
+///
+/// [`Apache Spark`]: https://spark.apache.org/
+/// [`Bitnami Spark docker image`]: https://hub.docker.com/r/bitnami/spark
+#[derive(Debug, Clone)]
+pub struct Spark {
+    env_vars: BTreeMap<String, String>,
+}
+
+impl Default for Spark {
+    fn default() -> Self {
+        let mut env_vars = BTreeMap::new();
+        env_vars.insert("SPARK_MODE".to_owned(), "master".to_owned());
+        Self { env_vars }
+    }
+}
+
+impl Image for Spark {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn tag(&self) -> &str {
+        TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![WaitFor::message_on_stderr(
+            "I have been elected leader! New state: ALIVE",
+        )]
+    }
+
+    fn env_vars(
+        &self,
+    ) -> impl IntoIterator<Item = (impl Into<Cow<'_, str>>, impl Into<Cow<'_, str>>)> {
+        &self.env_vars
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[SPARK_MASTER_PORT, SPARK_WEB_UI_PORT]
+    }
+}
+
+/// Module to work with a worker of an [`Apache Spark`] standalone cluster inside of tests.
+///
+/// Starts a worker based on the [`Bitnami Spark docker image`], which registers at the master
+/// set with [`SparkWorker::with_master_url`] (`spark://spark:7077` by default).
+/// The resources offered by the worker are set with [`SparkWorker::with_memory`] and [`SparkWorker::with_cores`],
+/// by default it offers 1GB of memory and all cores of the container.
+///
+/// See [`Spark`] for an example.
+///
+/// [`Apache Spark`]: https://spark.apache.org/
+/// [`Bitnami Spark docker image`]: https://hub.docker.com/r/bitnami/spark
+#[derive(Debug, Clone)]
+pub struct SparkWorker {
+    env_vars: BTreeMap<String, String>,
+}
+
+impl Default for SparkWorker {
+    fn default() -> Self {
+        let mut env_vars = BTreeMap::new();
+        env_vars.insert("SPARK_MODE".to_owned(), "worker".to_owned());
+        env_vars.insert(
+            "SPARK_MASTER_URL".to_owned(),
+            "spark://spark:7077".to_owned(),
+        );
+        env_vars.insert("SPARK_WORKER_MEMORY".to_owned(), "1G".to_owned());
+        Self { env_vars }
+    }
+}
+
+impl SparkWorker {
+    /// Sets the url of the master the worker registers at (`SPARK_MASTER_URL`),
+    /// as reachable from inside of the container, defaults to `spark://spark:7077`.
+    pub fn with_master_url(mut self, master_url: &str) -> Self {
+        self.env_vars
+            .insert("SPARK_MASTER_URL".to_owned(), master_url.to_owned());
+        self
+    }
+
+    /// Sets the amount of memory offered to applications (`SPARK_WORKER_MEMORY`), e.g. `512m` or `2g`.
+    pub fn with_memory(mut self, memory: &str) -> Self {
+        self.env_vars
+            .insert("SPARK_WORKER_MEMORY".to_owned(), memory.to_owned());
+        self
+    }
+
+    /// Sets the number of cores offered to applications (`SPARK_WORKER_CORES`).
+    pub fn with_cores(mut self, cores: u16) -> Self {
+        self.env_vars
+            .insert("SPARK_WORKER_CORES".to_owned(), cores.to_string());
+        self
+    }
+}
+
+impl Image for SparkWorker {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn tag(&self) -> &str {
+        TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![WaitFor::message_on_stderr(
+            "Successfully registered with master",
+        )]
+    }
+
+    fn env_vars(
+        &self,
+    ) -> impl IntoIterator<Item = (impl Into<Cow<'_, str>>, impl Into<Cow<'_, str>>)> {
+        &self.env_vars
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testcontainers::{runners::SyncRunner, ImageExt};
+
+    #[test]
+    fn spark_master_with_worker() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let network = "testcontainers-spark";
+        let master_name = "testcontainers-spark-master";
+        let master = Spark::default()
+            .with_network(network)
+            .with_container_name(master_name)
+            .start()?;
+        let _worker = SparkWorker::default()
+            .with_master_url(&format!("spark://{master_name}:7077"))
+            .with_memory("512m")
+            .with_cores(2)
+            .with_network(network)
+            .start()?;
+        let url = format!(
+            "http://{}:{}",
+            master.get_host()?,
+            master.get_host_port_ipv4(SPARK_WEB_UI_PORT)?
+        );
+
+        let status: serde_json::Value = reqwest::blocking::get(format!("{url}/json/"))?.json()?;
+        assert_eq!(status["status"], "ALIVE");
+        assert_eq!(status["aliveworkers"], 1);
+        assert_eq!(status["cores"], 2);
+        assert_eq!(status["memory"], 512);
+        Ok(())
+    }
+}
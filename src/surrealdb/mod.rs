@@ -2,7 +2,7 @@ use std::{borrow::Cow, collections::HashMap};
 
 use testcontainers::{
     core::{ContainerPort, WaitFor},
-    Image,
+    ContainerAsync, Image, TestcontainersError,
 };
 
 const NAME: &str = "surrealdb/surrealdb";
@@ -18,6 +18,7 @@ pub const SURREALDB_PORT: ContainerPort = ContainerPort::Tcp(8000);
 /// Starts an instance of SurrealDB.
 /// This module is based on the official [`SurrealDB docker image`].
 /// Default user and password is `root`, and exposed port is `8000` ([`SURREALDB_PORT`]).
+/// The data is kept in memory by default, see [`SurrealDb::with_storage`].
 /// # Example
 /// ```
 /// # use ::surrealdb::{
@@ -48,6 +49,30 @@ pub const SURREALDB_PORT: ContainerPort = ContainerPort::Tcp(8000);
 #[derive(Debug, Clone)]
 pub struct SurrealDb {
     env_vars: HashMap<String, String>,
+    storage: SurrealDbStorage,
+}
+
+/// Storage engine of [`SurrealDb`], passed as path to the start command.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum SurrealDbStorage {
+    /// Keeps the data in memory (`memory`).
+    #[default]
+    Memory,
+    /// Stores the data with RocksDB in the container (`rocksdb:/tmp/surrealdb`).
+    RocksDb,
+    /// Stores the data in the TiKV cluster of the given placement driver address (`tikv://<address>`),
+    /// e.g. `pd:2379` for a container in the same network.
+    TiKv(String),
+}
+
+impl SurrealDbStorage {
+    fn path(&self) -> String {
+        match self {
+            Self::Memory => "memory".to_owned(),
+            Self::RocksDb => "rocksdb:/tmp/surrealdb".to_owned(),
+            Self::TiKv(address) => format!("tikv://{address}"),
+        }
+    }
 }
 
 impl SurrealDb {
@@ -85,6 +110,44 @@ impl SurrealDb {
             .insert("SURREAL_CAPS_ALLOW_ALL".to_owned(), allow_all.to_string());
         self
     }
+
+    /// Sets the storage engine for the SurrealDB instance, defaults to [`SurrealDbStorage::Memory`].
+    pub fn with_storage(mut self, storage: SurrealDbStorage) -> Self {
+        self.storage = storage;
+        self
+    }
+
+    /// Returns the WebSocket url of the SurrealDB instance running in the given container,
+    /// e.g. to be passed to `surrealdb::engine::any::connect`.
+    ///
+    /// # Example
+    /// ```
+    /// use testcontainers_modules::{surrealdb::SurrealDb, testcontainers::runners::AsyncRunner};
+    ///
+    /// # async fn example() -> Result<(), testcontainers_modules::testcontainers::TestcontainersError> {
+    /// let surrealdb_instance = SurrealDb::default().start().await?;
+    /// let ws_url = SurrealDb::ws_url(&surrealdb_instance).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn ws_url(container: &ContainerAsync<Self>) -> Result<String, TestcontainersError> {
+        Self::url("ws", container).await
+    }
+
+    /// Returns the HTTP url of the SurrealDB instance running in the given container,
+    /// see [`SurrealDb::ws_url`].
+    pub async fn http_url(container: &ContainerAsync<Self>) -> Result<String, TestcontainersError> {
+        Self::url("http", container).await
+    }
+
+    async fn url(
+        scheme: &str,
+        container: &ContainerAsync<Self>,
+    ) -> Result<String, TestcontainersError> {
+        let host = container.get_host().await?;
+        let port = container.get_host_port_ipv4(SURREALDB_PORT).await?;
+        Ok(format!("{scheme}://{host}:{port}"))
+    }
 }
 
 impl Default for SurrealDb {
@@ -94,9 +157,11 @@ impl Default for SurrealDb {
         env_vars.insert("SURREAL_PASS".to_owned(), "root".to_owned());
         env_vars.insert("SURREAL_AUTH".to_owned(), "true".to_owned());
         env_vars.insert("SURREAL_CAPS_ALLOW_ALL".to_owned(), "true".to_owned());
-        env_vars.insert("SURREAL_PATH".to_owned(), "memory".to_owned());
 
-        Self { env_vars }
+        Self {
+            env_vars,
+            storage: SurrealDbStorage::default(),
+        }
     }
 }
 
@@ -120,7 +185,7 @@ impl Image for SurrealDb {
     }
 
     fn cmd(&self) -> impl IntoIterator<Item = impl Into<Cow<'_, str>>> {
-        ["start"]
+        ["start".to_owned(), self.storage.path()]
     }
 
     fn expose_ports(&self) -> &[ContainerPort] {
@@ -238,4 +303,46 @@ mod tests {
         assert!(result.marketing);
         Ok(())
     }
+
+    #[tokio::test]
+    async fn surrealdb_rocksdb_strict() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = SurrealDb::default()
+            .with_storage(SurrealDbStorage::RocksDb)
+            .with_strict(true)
+            .start()
+            .await?;
+        let url = SurrealDb::ws_url(&node).await?;
+        let health_url = format!("{}/health", SurrealDb::http_url(&node).await?);
+        assert!(reqwest::get(health_url).await?.status().is_success());
+
+        let db = surrealdb::engine::any::connect(url).await?;
+        db.signin(Root {
+            username: "root",
+            password: "root",
+        })
+        .await?;
+        db.use_ns("test").use_db("test").await?;
+
+        // in strict mode namespaces and databases have to be defined before they are used
+        let result = db
+            .query("CREATE person:tobie SET marketing = true")
+            .await?
+            .check();
+        assert!(result.is_err());
+
+        db.query("DEFINE NAMESPACE test; USE NS test; DEFINE DATABASE test;")
+            .await?
+            .check()?;
+        db.query("DEFINE TABLE person SCHEMALESS").await?.check()?;
+        db.query("CREATE person:tobie SET marketing = true")
+            .await?
+            .check()?;
+        let marketing: Option<bool> = db
+            .query("SELECT VALUE marketing FROM ONLY person:tobie")
+            .await?
+            .take(0)?;
+        assert_eq!(marketing, Some(true));
+        Ok(())
+    }
 }
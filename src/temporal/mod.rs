@@ -0,0 +1,135 @@
+use std::borrow::Cow;
+
+use testcontainers::{
+    core::{wait::HttpWaitStrategy, ContainerPort, WaitFor},
+    Image,
+};
+
+const NAME: &str = "temporalio/temporal";
+const TAG: &str = "1.1.2";
+
+/// Port of the frontend gRPC API that the [`Temporal`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Temporal`]: https://temporal.io/
+pub const TEMPORAL_GRPC_PORT: ContainerPort = ContainerPort::Tcp(7233);
+
+/// Port of the web UI that the [`Temporal`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Temporal`]: https://temporal.io/
+pub const TEMPORAL_UI_PORT: ContainerPort = ContainerPort::Tcp(8233);
+
+/// Module to work with [`Temporal`] inside of tests.
+///
+/// Starts a Temporal development server (`temporal server start-dev`) based on the official
+/// [`Temporal CLI docker image`], which runs all services with an in-memory database.
+///
+/// Workers and clients connect to the gRPC API on port 7233 ([`TEMPORAL_GRPC_PORT`]),
+/// the web UI is served on port 8233 ([`TEMPORAL_UI_PORT`]).
+/// Besides the `default` namespace, further namespaces are created on startup with [`Temporal::with_namespace`].
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{
+///     temporal::{Temporal, TEMPORAL_GRPC_PORT},
+///     testcontainers::runners::SyncRunner,
+/// };
+///
+/// let temporal_instance = Temporal::default()
+///     .with_namespace("test")
+///     .start()
+///     .unwrap();
+/// let target_url = format!(
+///     "http://{}:{}",
+///     temporal_instance.get_host().unwrap(),
+///     temporal_instance.get_host_port_ipv4(TEMPORAL_GRPC_PORT).unwrap()
+/// );
+/// ```
+///
+/// [`Temporal`]: https://temporal.io/
+/// [`Temporal CLI docker image`]: https://hub.docker.com/r/temporalio/temporal
+#[derive(Debug, Clone, Default)]
+pub struct Temporal {
+    namespaces: Vec<String>,
+}
+
+impl Temporal {
+    /// Registers a namespace, which is created on startup (`--namespace`).
+    /// Can be called multiple times to add (not override) namespaces.
+    pub fn with_namespace(mut self, namespace: &str) -> Self {
+        self.namespaces.push(namespace.to_owned());
+        self
+    }
+}
+
+impl Image for Temporal {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn tag(&self) -> &str {
+        TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        // the UI server proxies the API, thus it succeeds once the frontend accepts requests
+        vec![WaitFor::http(
+            HttpWaitStrategy::new("/api/v1/namespaces")
+                .with_port(TEMPORAL_UI_PORT)
+                .with_expected_status_code(200_u16),
+        )]
+    }
+
+    fn cmd(&self) -> impl IntoIterator<Item = impl Into<Cow<'_, str>>> {
+        let mut cmd = vec![
+            "server".to_owned(),
+            "start-dev".to_owned(),
+            "--ip".to_owned(),
+            "0.0.0.0".to_owned(),
+        ];
+        for namespace in &self.namespaces {
+            cmd.push("--namespace".to_owned());
+            cmd.push(namespace.clone());
+        }
+        cmd
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[TEMPORAL_GRPC_PORT, TEMPORAL_UI_PORT]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testcontainers::runners::SyncRunner;
+
+    #[test]
+    fn temporal_with_namespace() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = Temporal::default()
+            .with_namespace("testcontainers")
+            .start()?;
+        let url = format!(
+            "http://{}:{}",
+            node.get_host()?,
+            node.get_host_port_ipv4(TEMPORAL_UI_PORT)?
+        );
+
+        let namespaces: serde_json::Value =
+            reqwest::blocking::get(format!("{url}/api/v1/namespaces"))?.json()?;
+        let names = namespaces["namespaces"]
+            .as_array()
+            .map(|namespaces| {
+                namespaces
+                    .iter()
+                    .map(|namespace| namespace["namespaceInfo"]["name"].clone())
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        assert!(names.contains(&serde_json::json!("default")));
+        assert!(names.contains(&serde_json::json!("testcontainers")));
+        Ok(())
+    }
+}
@@ -0,0 +1,102 @@
+use testcontainers::{
+    core::{wait::HttpWaitStrategy, ContainerPort, WaitFor},
+    Image,
+};
+
+const NAME: &str = "pingcap/tidb";
+const TAG: &str = "v8.4.0";
+
+/// Port of the MySQL protocol that the [`TiDB`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`TiDB`]: https://www.pingcap.com/tidb/
+pub const TIDB_PORT: ContainerPort = ContainerPort::Tcp(4000);
+
+/// Port of the status API that the [`TiDB`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`TiDB`]: https://www.pingcap.com/tidb/
+pub const TIDB_STATUS_PORT: ContainerPort = ContainerPort::Tcp(10080);
+
+/// Module to work with [`TiDB`] inside of tests.
+///
+/// Starts a standalone TiDB server based on the official [`TiDB docker image`], storing the data in the embedded
+/// `unistore` engine instead of a TiKV cluster. MySQL clients connect on port 4000 ([`TIDB_PORT`])
+/// as `root` without a password, the status API is served on port 10080 ([`TIDB_STATUS_PORT`]).
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{
+///     testcontainers::runners::SyncRunner,
+///     tidb::{TiDb, TIDB_PORT},
+/// };
+///
+/// let tidb_instance = TiDb::default().start().unwrap();
+/// let mysql_url = format!(
+///     "mysql://root@{}:{}/test",
+///     tidb_instance.get_host().unwrap(),
+///     tidb_instance.get_host_port_ipv4(TIDB_PORT).unwrap()
+/// );
+/// ```
+///
+/// [`TiDB`]: https://www.pingcap.com/tidb/
+/// [`TiDB docker image`]: https://hub.docker.com/r/pingcap/tidb
+#[derive(Debug, Default, Clone)]
+pub struct TiDb {
+    /// (remove if there is another variable)
+    /// Field is included to prevent this struct to be a unit struct.
+    /// This allows extending functionality (and thus further variables) without breaking changes
+    _priv: (),
+}
+
+impl Image for TiDb {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn tag(&self) -> &str {
+        TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![WaitFor::http(
+            HttpWaitStrategy::new("/status")
+                .with_port(TIDB_STATUS_PORT)
+                .with_expected_status_code(200_u16),
+        )]
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[TIDB_PORT, TIDB_STATUS_PORT]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mysql::prelude::Queryable;
+
+    use super::*;
+    use crate::testcontainers::runners::SyncRunner;
+
+    #[test]
+    fn tidb_version() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = TiDb::default().start()?;
+
+        let mysql_url = format!(
+            "mysql://root@{}:{}/test",
+            node.get_host()?,
+            node.get_host_port_ipv4(TIDB_PORT)?
+        );
+        let mut conn = mysql::Conn::new(mysql::Opts::from_url(&mysql_url)?)?;
+
+        let version: Option<String> = conn.query_first("SELECT tidb_version()")?;
+        assert!(version.unwrap().contains("Store: unistore"));
+
+        conn.query_drop("CREATE TABLE foo (id int PRIMARY KEY, bar varchar(255))")?;
+        conn.query_drop("INSERT INTO foo VALUES (1, 'blub')")?;
+        let bar: Option<String> = conn.query_first("SELECT bar FROM foo WHERE id = 1")?;
+        assert_eq!(bar.as_deref(), Some("blub"));
+        Ok(())
+    }
+}
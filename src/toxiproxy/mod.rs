@@ -0,0 +1,170 @@
+use std::ops::RangeInclusive;
+
+use testcontainers::{
+    core::{wait::HttpWaitStrategy, CmdWaitFor, ContainerPort, ExecCommand, WaitFor},
+    Image,
+};
+
+const NAME: &str = "ghcr.io/shopify/toxiproxy";
+const TAG: &str = "2.11.0";
+const DEFAULT_PROXY_PORTS: RangeInclusive<u16> = 8666..=8675;
+
+/// Port of the admin API that the [`Toxiproxy`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Toxiproxy`]: https://github.com/Shopify/toxiproxy
+pub const TOXIPROXY_ADMIN_PORT: ContainerPort = ContainerPort::Tcp(8474);
+
+/// Module to work with [`Toxiproxy`] inside of tests.
+///
+/// Starts an instance of Toxiproxy based on the official [`Toxiproxy docker image`].
+///
+/// The admin API is served on port 8474 ([`TOXIPROXY_ADMIN_PORT`]), proxies can listen on the exposed
+/// proxy ports, 8666 to 8675 by default (see [`Toxiproxy::with_proxy_ports`]).
+/// Proxies are created at runtime with [`Toxiproxy::create_proxy`], toxics via the
+/// [admin API](https://github.com/Shopify/toxiproxy#http-api).
+/// To proxy other containers, start them in the same network (see [`testcontainers::core::ImageExt::with_network`])
+/// and use their network alias in the upstream address.
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{
+///     testcontainers::runners::SyncRunner,
+///     toxiproxy::{Toxiproxy, TOXIPROXY_ADMIN_PORT},
+/// };
+///
+/// let toxiproxy_instance = Toxiproxy::default().start().unwrap();
+/// toxiproxy_instance
+///     .exec(Toxiproxy::create_proxy(
+///         "redis",
+///         "0.0.0.0:8666",
+///         "redis:6379",
+///     ))
+///     .unwrap();
+/// let proxy_port = toxiproxy_instance.get_host_port_ipv4(8666).unwrap();
+///
+/// // add toxics with `POST {admin_url}/proxies/redis/toxics`
+/// let admin_url = format!(
+///     "http://{}:{}",
+///     toxiproxy_instance.get_host().unwrap(),
+///     toxiproxy_instance
+///         .get_host_port_ipv4(TOXIPROXY_ADMIN_PORT)
+///         .unwrap()
+/// );
+/// ```
+///
+/// [`Toxiproxy`]: https://github.com/Shopify/toxiproxy
+/// [`Toxiproxy docker image`]: https://github.com/Shopify/toxiproxy/pkgs/container/toxiproxy
+#[derive(Debug, Clone)]
+pub struct Toxiproxy {
+    exposed_ports: Vec<ContainerPort>,
+}
+
+impl Default for Toxiproxy {
+    fn default() -> Self {
+        Self {
+            exposed_ports: Vec::new(),
+        }
+        .with_proxy_ports(DEFAULT_PROXY_PORTS)
+    }
+}
+
+impl Toxiproxy {
+    /// Sets the ports exposed for proxies, replacing the default range `8666..=8675`.
+    pub fn with_proxy_ports(mut self, proxy_ports: impl IntoIterator<Item = u16>) -> Self {
+        self.exposed_ports = std::iter::once(TOXIPROXY_ADMIN_PORT)
+            .chain(proxy_ports.into_iter().map(ContainerPort::Tcp))
+            .collect();
+        self
+    }
+
+    /// Returns a command to create a proxy with the given name, listening on the given address
+    /// (e.g. `0.0.0.0:8666`, using one of the exposed proxy ports) and forwarding to the upstream address.
+    /// The command succeeds once the proxy is created.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let toxiproxy_node = Toxiproxy::default().start().unwrap();
+    /// toxiproxy_node
+    ///     .exec(Toxiproxy::create_proxy("redis", "0.0.0.0:8666", "redis:6379"))
+    ///     .unwrap();
+    /// ```
+    pub fn create_proxy(name: &str, listen: &str, upstream: &str) -> ExecCommand {
+        ExecCommand::new([
+            "/toxiproxy-cli",
+            "create",
+            "-l",
+            listen,
+            "-u",
+            upstream,
+            name,
+        ])
+        .with_cmd_ready_condition(CmdWaitFor::exit_code(0))
+    }
+}
+
+impl Image for Toxiproxy {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn tag(&self) -> &str {
+        TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![WaitFor::http(
+            HttpWaitStrategy::new("/version")
+                .with_port(TOXIPROXY_ADMIN_PORT)
+                .with_expected_status_code(200_u16),
+        )]
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &self.exposed_ports
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::*;
+    use crate::testcontainers::runners::SyncRunner;
+
+    #[test]
+    fn toxiproxy_proxy_with_latency() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = Toxiproxy::default().with_proxy_ports([8666]).start()?;
+        let host = node.get_host()?;
+        let admin_url = format!(
+            "http://{host}:{}",
+            node.get_host_port_ipv4(TOXIPROXY_ADMIN_PORT)?
+        );
+        let client = reqwest::blocking::Client::new();
+
+        // proxy the admin API itself, to not depend on another container
+        node.exec(Toxiproxy::create_proxy(
+            "admin",
+            "0.0.0.0:8666",
+            "localhost:8474",
+        ))?;
+        let proxy_url = format!("http://{host}:{}/version", node.get_host_port_ipv4(8666)?);
+        assert!(reqwest::blocking::get(&proxy_url)?.status().is_success());
+
+        client
+            .post(format!("{admin_url}/proxies/admin/toxics"))
+            .json(&serde_json::json!({
+                "type": "latency",
+                "stream": "downstream",
+                "toxicity": 1.0,
+                "attributes": { "latency": 1000 },
+            }))
+            .send()?
+            .error_for_status()?;
+        let start = Instant::now();
+        assert!(reqwest::blocking::get(&proxy_url)?.status().is_success());
+        assert!(start.elapsed() >= Duration::from_millis(1000));
+        Ok(())
+    }
+}
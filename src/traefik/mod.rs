@@ -0,0 +1,211 @@
+use std::borrow::Cow;
+
+use testcontainers::{
+    core::{wait::HttpWaitStrategy, ContainerPort, WaitFor},
+    CopyDataSource, CopyToContainer, Image,
+};
+
+const NAME: &str = "traefik";
+const TAG: &str = "v3.2";
+const STATIC_CONFIG_PATH: &str = "/etc/traefik/traefik.yml";
+const DYNAMIC_CONFIG_DIR: &str = "/etc/traefik/dynamic";
+
+/// Port of the `web` entrypoint (HTTP) that the [`Traefik`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Traefik`]: https://traefik.io/traefik/
+pub const TRAEFIK_HTTP_PORT: ContainerPort = ContainerPort::Tcp(80);
+
+/// Port of the `websecure` entrypoint (HTTPS) that the [`Traefik`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Traefik`]: https://traefik.io/traefik/
+pub const TRAEFIK_HTTPS_PORT: ContainerPort = ContainerPort::Tcp(443);
+
+/// Port of the `traefik` entrypoint, serving `/ping` and the API and dashboard (if enabled),
+/// that the [`Traefik`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Traefik`]: https://traefik.io/traefik/
+pub const TRAEFIK_API_PORT: ContainerPort = ContainerPort::Tcp(8080);
+
+/// Module to work with [`Traefik`] inside of tests.
+///
+/// Starts an instance of Traefik based on the official [`Traefik docker image`].
+///
+/// By default the static configuration is passed as flags, defining the entrypoints `web` on port 80 ([`TRAEFIK_HTTP_PORT`])
+/// and `websecure` on port 443 ([`TRAEFIK_HTTPS_PORT`]), enabling `/ping` on port 8080 ([`TRAEFIK_API_PORT`])
+/// and the file provider for the dynamic configuration registered with [`Traefik::with_dynamic_config_yaml`].
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{
+///     testcontainers::runners::SyncRunner,
+///     traefik::{Traefik, TRAEFIK_HTTP_PORT},
+/// };
+///
+/// let traefik_instance = Traefik::default().start().unwrap();
+/// let url = format!(
+///     "http://{}:{}",
+///     traefik_instance.get_host().unwrap(),
+///     traefik_instance.get_host_port_ipv4(TRAEFIK_HTTP_PORT).unwrap()
+/// );
+/// ```
+///
+/// [`Traefik`]: https://traefik.io/traefik/
+/// [`Traefik docker image`]: https://hub.docker.com/_/traefik
+#[derive(Debug, Clone, Default)]
+pub struct Traefik {
+    static_config: Option<CopyToContainer>,
+    dynamic_configs: Vec<CopyToContainer>,
+    api_dashboard: bool,
+}
+
+impl Traefik {
+    /// Registers the static configuration, copied to `/etc/traefik/traefik.yml`, instead of the default flags.
+    ///
+    /// The configuration has to enable `ping` on the `traefik` entrypoint (port 8080), as readiness is checked with it.
+    /// To use the dynamic configuration registered with [`Traefik::with_dynamic_config_yaml`],
+    /// it has to enable the file provider for the directory `/etc/traefik/dynamic`.
+    pub fn with_static_config_yaml(mut self, config: impl Into<CopyDataSource>) -> Self {
+        self.static_config = Some(CopyToContainer::new(config.into(), STATIC_CONFIG_PATH));
+        self
+    }
+
+    /// Registers a dynamic configuration file (routers, services, middlewares),
+    /// which is loaded by the file provider from `/etc/traefik/dynamic`.
+    /// Can be called multiple times to add (not override) files.
+    ///
+    /// # Example
+    /// ```
+    /// use testcontainers_modules::traefik::Traefik;
+    ///
+    /// let traefik = Traefik::default().with_dynamic_config_yaml(
+    ///     "http:\n  routers:\n    app:\n      rule: PathPrefix(`/`)\n      service: app\n\
+    ///      \x20 services:\n    app:\n      loadBalancer:\n        servers:\n          - url: http://app:8080\n"
+    ///         .to_string()
+    ///         .into_bytes(),
+    /// );
+    /// ```
+    pub fn with_dynamic_config_yaml(mut self, config: impl Into<CopyDataSource>) -> Self {
+        let target = format!(
+            "{DYNAMIC_CONFIG_DIR}/dynamic_{}.yml",
+            self.dynamic_configs.len()
+        );
+        self.dynamic_configs
+            .push(CopyToContainer::new(config.into(), target));
+        self
+    }
+
+    /// Enables or disables the API and dashboard on port 8080 (`--api.insecure`, `--api.dashboard`),
+    /// disabled by default. Only applies if no static configuration is registered.
+    pub fn with_api_dashboard(mut self, enabled: bool) -> Self {
+        self.api_dashboard = enabled;
+        self
+    }
+}
+
+impl Image for Traefik {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn tag(&self) -> &str {
+        TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![WaitFor::http(
+            HttpWaitStrategy::new("/ping")
+                .with_port(TRAEFIK_API_PORT)
+                .with_expected_status_code(200_u16),
+        )]
+    }
+
+    fn cmd(&self) -> impl IntoIterator<Item = impl Into<Cow<'_, str>>> {
+        if self.static_config.is_some() {
+            return Vec::new();
+        }
+
+        let mut cmd = vec![
+            format!("--entrypoints.web.address=:{}", TRAEFIK_HTTP_PORT.as_u16()),
+            format!(
+                "--entrypoints.websecure.address=:{}",
+                TRAEFIK_HTTPS_PORT.as_u16()
+            ),
+            format!(
+                "--entrypoints.traefik.address=:{}",
+                TRAEFIK_API_PORT.as_u16()
+            ),
+            "--ping=true".to_owned(),
+            format!("--providers.file.directory={DYNAMIC_CONFIG_DIR}"),
+        ];
+        if self.api_dashboard {
+            cmd.push("--api.insecure=true".to_owned());
+            cmd.push("--api.dashboard=true".to_owned());
+        }
+        cmd
+    }
+
+    fn copy_to_sources(&self) -> impl IntoIterator<Item = &CopyToContainer> {
+        self.static_config.iter().chain(&self.dynamic_configs)
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[TRAEFIK_HTTP_PORT, TRAEFIK_HTTPS_PORT, TRAEFIK_API_PORT]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use retry::{delay::Fixed, retry};
+
+    use super::*;
+    use crate::testcontainers::runners::SyncRunner;
+
+    #[test]
+    fn traefik_route_from_dynamic_config() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        // routes `/ping` of the `web` entrypoint to the ping endpoint of traefik itself
+        let node = Traefik::default()
+            .with_api_dashboard(true)
+            .with_dynamic_config_yaml(
+                "http:\n\
+                 \x20 routers:\n\
+                 \x20   ping:\n\
+                 \x20     entryPoints: [web]\n\
+                 \x20     rule: Path(`/ping`)\n\
+                 \x20     service: ping\n\
+                 \x20 services:\n\
+                 \x20   ping:\n\
+                 \x20     loadBalancer:\n\
+                 \x20       servers:\n\
+                 \x20         - url: http://127.0.0.1:8080\n"
+                    .to_string()
+                    .into_bytes(),
+            )
+            .start()?;
+        let host = node.get_host()?;
+        let url = format!(
+            "http://{host}:{}/ping",
+            node.get_host_port_ipv4(TRAEFIK_HTTP_PORT)?
+        );
+
+        let body = retry(Fixed::from(Duration::from_millis(200)).take(20), || {
+            reqwest::blocking::get(&url)
+                .and_then(|response| response.error_for_status())
+                .and_then(|response| response.text())
+        })
+        .map_err(|err| err.to_string())?;
+        assert_eq!(body, "OK");
+
+        let dashboard = reqwest::blocking::get(format!(
+            "http://{host}:{}/dashboard/",
+            node.get_host_port_ipv4(TRAEFIK_API_PORT)?
+        ))?;
+        assert!(dashboard.status().is_success());
+        Ok(())
+    }
+}
@@ -0,0 +1,156 @@
+use testcontainers::{
+    core::{wait::HttpWaitStrategy, ContainerPort, WaitFor},
+    CopyToContainer, Image,
+};
+
+const NAME: &str = "trinodb/trino";
+const TAG: &str = "465";
+
+/// Port of the HTTP API (and web UI) that the [`Trino`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Trino`]: https://trino.io/
+pub const TRINO_PORT: ContainerPort = ContainerPort::Tcp(8080);
+
+/// Module to work with [`Trino`] inside of tests.
+///
+/// Starts a single node instance of Trino (acting as coordinator and worker) based on the official [`Trino docker image`].
+/// Clients connect on port 8080 ([`TRINO_PORT`]), authentication is disabled and any user name is accepted.
+///
+/// The image ships the `jmx`, `memory`, `tpcds` and `tpch` catalogs, additional catalogs
+/// (e.g. connecting other containers in the same network) are registered with [`Trino::with_catalog`].
+/// The container is ready once `/v1/info` reports that the node finished starting.
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{
+///     testcontainers::runners::SyncRunner,
+///     trino::{Trino, TRINO_PORT},
+/// };
+///
+/// let trino_instance = Trino::default()
+///     .with_catalog("test", &[("connector.name", "memory")])
+///     .start()
+///     .unwrap();
+/// let url = format!(
+///     "http://{}:{}",
+///     trino_instance.get_host().unwrap(),
+///     trino_instance.get_host_port_ipv4(TRINO_PORT).unwrap()
+/// );
+/// ```
+///
+/// [`Trino`]: https://trino.io/
+/// [`Trino docker image`]: https://hub.docker.com/r/trinodb/trino
+#[derive(Debug, Clone, Default)]
+pub struct Trino {
+    catalogs: Vec<CopyToContainer>,
+}
+
+impl Trino {
+    /// Registers a catalog with the given properties, written to `/etc/trino/catalog/<name>.properties`.
+    /// Can be called multiple times to add (not override) catalogs.
+    ///
+    /// # Example
+    /// ```
+    /// use testcontainers_modules::trino::Trino;
+    ///
+    /// let trino = Trino::default().with_catalog(
+    ///     "postgres",
+    ///     &[
+    ///         ("connector.name", "postgresql"),
+    ///         ("connection-url", "jdbc:postgresql://postgres:5432/postgres"),
+    ///         ("connection-user", "postgres"),
+    ///         ("connection-password", "postgres"),
+    ///     ],
+    /// );
+    /// ```
+    pub fn with_catalog(mut self, name: &str, properties: &[(&str, &str)]) -> Self {
+        let content: String = properties
+            .iter()
+            .map(|(key, value)| format!("{key}={value}\n"))
+            .collect();
+        self.catalogs.push(CopyToContainer::new(
+            content.into_bytes(),
+            format!("/etc/trino/catalog/{name}.properties"),
+        ));
+        self
+    }
+}
+
+impl Image for Trino {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn tag(&self) -> &str {
+        TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![WaitFor::http(
+            HttpWaitStrategy::new("/v1/info")
+                .with_port(TRINO_PORT)
+                .with_response_matcher_async(|response| async move {
+                    match response.text().await {
+                        Ok(body) => body.replace(' ', "").contains(r#""starting":false"#),
+                        Err(_) => false,
+                    }
+                }),
+        )]
+    }
+
+    fn copy_to_sources(&self) -> impl IntoIterator<Item = &CopyToContainer> {
+        &self.catalogs
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[TRINO_PORT]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testcontainers::runners::SyncRunner;
+
+    #[test]
+    fn trino_show_catalogs() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = Trino::default()
+            .with_catalog("test_memory", &[("connector.name", "memory")])
+            .start()?;
+        let url = format!(
+            "http://{}:{}",
+            node.get_host()?,
+            node.get_host_port_ipv4(TRINO_PORT)?
+        );
+
+        let client = reqwest::blocking::Client::new();
+        let mut result: serde_json::Value = client
+            .post(format!("{url}/v1/statement"))
+            .header("X-Trino-User", "test")
+            .body("SHOW CATALOGS")
+            .send()?
+            .error_for_status()?
+            .json()?;
+        let mut catalogs = Vec::new();
+        loop {
+            if let Some(data) = result["data"].as_array() {
+                catalogs.extend(data.iter().map(|row| row[0].clone()));
+            }
+            let Some(next_uri) = result["nextUri"].as_str() else {
+                break;
+            };
+            result = client
+                .get(next_uri)
+                .header("X-Trino-User", "test")
+                .send()?
+                .error_for_status()?
+                .json()?;
+        }
+        assert!(result["error"].is_null(), "{}", result["error"]);
+        assert!(catalogs.contains(&serde_json::json!("test_memory")));
+        assert!(catalogs.contains(&serde_json::json!("tpch")));
+        Ok(())
+    }
+}
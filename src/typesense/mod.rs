@@ -0,0 +1,137 @@
+use std::{borrow::Cow, collections::BTreeMap};
+
+use testcontainers::{
+    core::{wait::HttpWaitStrategy, ContainerPort, WaitFor},
+    Image,
+};
+
+const NAME: &str = "typesense/typesense";
+const TAG: &str = "27.1";
+const DEFAULT_API_KEY: &str = "testcontainers";
+
+/// Port of the API that the [`Typesense`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Typesense`]: https://typesense.org/
+pub const TYPESENSE_PORT: ContainerPort = ContainerPort::Tcp(8108);
+
+/// Module to work with [`Typesense`] inside of tests.
+///
+/// Starts an instance of Typesense based on the official [`Typesense docker image`],
+/// serving the API on port 8108 ([`TYPESENSE_PORT`]) and storing its data in `/tmp`.
+///
+/// Typesense requires an admin API key, which defaults to `testcontainers` and can be set with
+/// [`Typesense::with_api_key`]. It has to be sent in the `X-TYPESENSE-API-KEY` header.
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{
+///     testcontainers::runners::SyncRunner,
+///     typesense::{Typesense, TYPESENSE_PORT},
+/// };
+///
+/// let typesense_instance = Typesense::default()
+///     .with_api_key("secret")
+///     .start()
+///     .unwrap();
+/// let url = format!(
+///     "http://{}:{}",
+///     typesense_instance.get_host().unwrap(),
+///     typesense_instance.get_host_port_ipv4(TYPESENSE_PORT).unwrap()
+/// );
+/// ```
+///
+/// [`Typesense`]: https://typesense.org/
+/// [`Typesense docker image`]: https://hub.docker.com/r/typesense/typesense
+#[derive(Debug, Clone)]
+pub struct Typesense {
+    env_vars: BTreeMap<String, String>,
+}
+
+impl Default for Typesense {
+    fn default() -> Self {
+        let mut env_vars = BTreeMap::new();
+        env_vars.insert("TYPESENSE_API_KEY".to_owned(), DEFAULT_API_KEY.to_owned());
+        env_vars.insert("TYPESENSE_DATA_DIR".to_owned(), "/tmp".to_owned());
+        Self { env_vars }
+    }
+}
+
+impl Typesense {
+    /// Sets the admin API key (`TYPESENSE_API_KEY`), defaults to `testcontainers`.
+    pub fn with_api_key(mut self, api_key: &str) -> Self {
+        self.env_vars
+            .insert("TYPESENSE_API_KEY".to_owned(), api_key.to_owned());
+        self
+    }
+
+    /// Sets the directory the data is stored in (`TYPESENSE_DATA_DIR`), defaults to `/tmp`.
+    pub fn with_data_dir(mut self, data_dir: &str) -> Self {
+        self.env_vars
+            .insert("TYPESENSE_DATA_DIR".to_owned(), data_dir.to_owned());
+        self
+    }
+}
+
+impl Image for Typesense {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn tag(&self) -> &str {
+        TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![WaitFor::http(
+            HttpWaitStrategy::new("/health")
+                .with_port(TYPESENSE_PORT)
+                .with_response_matcher_async(|response| async move {
+                    match response.text().await {
+                        Ok(body) => body.replace(' ', "").contains(r#""ok":true"#),
+                        Err(_) => false,
+                    }
+                }),
+        )]
+    }
+
+    fn env_vars(
+        &self,
+    ) -> impl IntoIterator<Item = (impl Into<Cow<'_, str>>, impl Into<Cow<'_, str>>)> {
+        &self.env_vars
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[TYPESENSE_PORT]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testcontainers::runners::SyncRunner;
+
+    #[test]
+    fn typesense_with_api_key() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = Typesense::default().with_api_key("secret").start()?;
+        let url = format!(
+            "http://{}:{}/collections",
+            node.get_host()?,
+            node.get_host_port_ipv4(TYPESENSE_PORT)?
+        );
+
+        let client = reqwest::blocking::Client::new();
+        let response = client.get(&url).send()?;
+        assert_eq!(response.status(), 401);
+
+        let collections: serde_json::Value = client
+            .get(&url)
+            .header("X-TYPESENSE-API-KEY", "secret")
+            .send()?
+            .error_for_status()?
+            .json()?;
+        assert_eq!(collections, serde_json::json!([]));
+        Ok(())
+    }
+}
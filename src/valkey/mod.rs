@@ -1,10 +1,13 @@
+use std::borrow::Cow;
+
 use testcontainers::{
     core::{ContainerPort, WaitFor},
-    Image,
+    CopyDataSource, CopyToContainer, Image,
 };
 
 const NAME: &str = "valkey/valkey";
 const TAG: &str = "8.0.1-alpine";
+const CONFIG_FILE_PATH: &str = "/usr/local/etc/valkey/valkey.conf";
 
 /// Default port (6379) on which Valkey is exposed
 pub const VALKEY_PORT: ContainerPort = ContainerPort::Tcp(6379);
@@ -38,14 +41,45 @@ pub const VALKEY_PORT: ContainerPort = ContainerPort::Tcp(6379);
 /// ```
 ///
 /// [`Valkey`]: https://valkey.io/
-/// [`Valeky docker image`]: https://hub.docker.com/r/valkey/valkey
+/// [`Valkey docker image`]: https://hub.docker.com/r/valkey/valkey
 /// [`VALKEY_PORT`]: super::VALKEY_PORT
 #[derive(Debug, Default, Clone)]
 pub struct Valkey {
-    /// (remove if there is another variable)
-    /// Field is included to prevent this struct to be a unit struct.
-    /// This allows extending functionality (and thus further variables) without breaking changes
-    _priv: (),
+    password: Option<String>,
+    config_file: Option<CopyToContainer>,
+}
+
+impl Valkey {
+    /// Enables authentication by passing `--requirepass <password>` to the valkey server.
+    /// Clients have to authenticate with the given password (for the `default` user).
+    ///
+    /// # Example
+    /// ```
+    /// use testcontainers_modules::valkey::Valkey;
+    ///
+    /// let valkey = Valkey::default().with_password("secret");
+    /// ```
+    pub fn with_password(mut self, password: &str) -> Self {
+        self.password = Some(password.to_owned());
+        self
+    }
+
+    /// Registers a custom `valkey.conf` to be used by the valkey server.
+    ///
+    /// The file is copied into the container and passed as first argument to `valkey-server`.
+    /// Options given via other builders (e.g. [`Valkey::with_password`]) take precedence over the file.
+    ///
+    /// # Example
+    /// ```
+    /// use testcontainers_modules::valkey::Valkey;
+    ///
+    /// let valkey = Valkey::default()
+    ///     .with_config_file(b"maxmemory 2mb\nmaxmemory-policy allkeys-lru\n".to_vec());
+    /// ```
+    pub fn with_config_file(mut self, config_file: impl Into<CopyDataSource>) -> Self {
+        self.config_file = Some(CopyToContainer::new(config_file.into(), CONFIG_FILE_PATH));
+        self
+    }
 }
 
 impl Image for Valkey {
@@ -60,13 +94,36 @@ impl Image for Valkey {
     fn ready_conditions(&self) -> Vec<WaitFor> {
         vec![WaitFor::message_on_stdout("Ready to accept connections")]
     }
+
+    fn copy_to_sources(&self) -> impl IntoIterator<Item = &CopyToContainer> {
+        &self.config_file
+    }
+
+    fn cmd(&self) -> impl IntoIterator<Item = impl Into<Cow<'_, str>>> {
+        if self.config_file.is_none() && self.password.is_none() {
+            return Vec::new();
+        }
+
+        let mut cmd = vec!["valkey-server"];
+        if self.config_file.is_some() {
+            cmd.push(CONFIG_FILE_PATH);
+        }
+        if let Some(password) = &self.password {
+            cmd.push("--requirepass");
+            cmd.push(password);
+        }
+        cmd
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use redis::Commands;
 
-    use crate::{testcontainers::runners::SyncRunner, valkey::Valkey};
+    use crate::{
+        testcontainers::runners::SyncRunner,
+        valkey::{Valkey, VALKEY_PORT},
+    };
 
     #[test]
     fn valkey_fetch_an_integer() -> Result<(), Box<dyn std::error::Error + 'static>> {
@@ -84,4 +141,45 @@ mod tests {
         assert_eq!(42, result);
         Ok(())
     }
+
+    #[test]
+    fn valkey_with_password() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = Valkey::default().with_password("secret").start()?;
+        let host_ip = node.get_host()?;
+        let host_port = node.get_host_port_ipv4(VALKEY_PORT)?;
+
+        let client = redis::Client::open(format!("redis://{host_ip}:{host_port}")).unwrap();
+        let mut con = client.get_connection().unwrap();
+        assert!(con.set::<_, _, ()>("my_key", 42).is_err());
+
+        let client = redis::Client::open(format!("redis://:secret@{host_ip}:{host_port}")).unwrap();
+        let mut con = client.get_connection().unwrap();
+        con.set::<_, _, ()>("my_key", 42).unwrap();
+        let result: i64 = con.get("my_key").unwrap();
+        assert_eq!(42, result);
+        Ok(())
+    }
+
+    #[test]
+    fn valkey_with_config_file() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = Valkey::default()
+            .with_config_file(b"maxmemory-policy allkeys-lru\n".to_vec())
+            .start()?;
+        let host_ip = node.get_host()?;
+        let host_port = node.get_host_port_ipv4(VALKEY_PORT)?;
+        let url = format!("redis://{host_ip}:{host_port}");
+
+        let client = redis::Client::open(url.as_ref()).unwrap();
+        let mut con = client.get_connection().unwrap();
+
+        let policy: Vec<String> = redis::cmd("CONFIG")
+            .arg("GET")
+            .arg("maxmemory-policy")
+            .query(&mut con)
+            .unwrap();
+        assert_eq!(vec!["maxmemory-policy", "allkeys-lru"], policy);
+        Ok(())
+    }
 }
@@ -0,0 +1,141 @@
+use testcontainers::{
+    core::{wait::HttpWaitStrategy, ContainerPort, WaitFor},
+    CopyDataSource, CopyToContainer, Image,
+};
+
+const NAME: &str = "verdaccio/verdaccio";
+const TAG: &str = "6.0";
+const CONFIG_FILE_PATH: &str = "/verdaccio/conf/config.yaml";
+
+/// Port of the npm registry that the [`Verdaccio`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Verdaccio`]: https://verdaccio.org/
+pub const VERDACCIO_PORT: ContainerPort = ContainerPort::Tcp(4873);
+
+/// Module to work with [`Verdaccio`] inside of tests.
+///
+/// Starts an instance of Verdaccio, a private npm registry, based on the official [`Verdaccio docker image`],
+/// serving the registry and web UI on port 4873 ([`VERDACCIO_PORT`]).
+///
+/// The default configuration of the image allows any user to register (`npm adduser`) and publish packages,
+/// and proxies unknown packages to `registry.npmjs.org`. For hermetic tests use [`Verdaccio::with_config_yaml`],
+/// e.g. to remove the uplink.
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{
+///     testcontainers::runners::SyncRunner,
+///     verdaccio::{Verdaccio, VERDACCIO_PORT},
+/// };
+///
+/// let verdaccio_instance = Verdaccio::default().start().unwrap();
+/// let registry_url = format!(
+///     "http://{}:{}/",
+///     verdaccio_instance.get_host().unwrap(),
+///     verdaccio_instance.get_host_port_ipv4(VERDACCIO_PORT).unwrap()
+/// );
+/// ```
+///
+/// [`Verdaccio`]: https://verdaccio.org/
+/// [`Verdaccio docker image`]: https://hub.docker.com/r/verdaccio/verdaccio
+#[derive(Debug, Clone, Default)]
+pub struct Verdaccio {
+    config_file: Option<CopyToContainer>,
+}
+
+impl Verdaccio {
+    /// Registers a custom `config.yaml`, replacing `/verdaccio/conf/config.yaml`.
+    ///
+    /// # Example
+    /// ```
+    /// use testcontainers_modules::verdaccio::Verdaccio;
+    ///
+    /// let verdaccio = Verdaccio::default().with_config_yaml(
+    ///     "storage: /verdaccio/storage/data\n\
+    ///      auth:\n  htpasswd:\n    file: /verdaccio/storage/htpasswd\n\
+    ///      packages:\n  '**':\n    access: $all\n    publish: $authenticated\n"
+    ///         .to_string()
+    ///         .into_bytes(),
+    /// );
+    /// ```
+    pub fn with_config_yaml(mut self, config: impl Into<CopyDataSource>) -> Self {
+        self.config_file = Some(CopyToContainer::new(config.into(), CONFIG_FILE_PATH));
+        self
+    }
+}
+
+impl Image for Verdaccio {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn tag(&self) -> &str {
+        TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![WaitFor::http(
+            HttpWaitStrategy::new("/-/ping")
+                .with_port(VERDACCIO_PORT)
+                .with_expected_status_code(200_u16),
+        )]
+    }
+
+    fn copy_to_sources(&self) -> impl IntoIterator<Item = &CopyToContainer> {
+        &self.config_file
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[VERDACCIO_PORT]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testcontainers::runners::SyncRunner;
+
+    #[test]
+    fn verdaccio_add_user() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = Verdaccio::default()
+            .with_config_yaml(
+                "storage: /verdaccio/storage/data\n\
+                 auth:\n  htpasswd:\n    file: /verdaccio/storage/htpasswd\n\
+                 packages:\n  '**':\n    access: $all\n    publish: $authenticated\n\
+                 log: { type: stdout, format: pretty, level: http }\n"
+                    .to_string()
+                    .into_bytes(),
+            )
+            .start()?;
+        let url = format!(
+            "http://{}:{}",
+            node.get_host()?,
+            node.get_host_port_ipv4(VERDACCIO_PORT)?
+        );
+        let client = reqwest::blocking::Client::new();
+
+        // `npm adduser` registers the user and returns a token
+        let user: serde_json::Value = client
+            .put(format!("{url}/-/user/org.couchdb.user:test"))
+            .json(&serde_json::json!({ "name": "test", "password": "testcontainers" }))
+            .send()?
+            .error_for_status()?
+            .json()?;
+        let token = user["token"].as_str().unwrap();
+
+        let whoami: serde_json::Value = client
+            .get(format!("{url}/-/whoami"))
+            .bearer_auth(token)
+            .send()?
+            .error_for_status()?
+            .json()?;
+        assert_eq!(whoami["username"], "test");
+
+        // no uplink is configured, so unknown packages are not found
+        let response = client.get(format!("{url}/left-pad")).send()?;
+        assert_eq!(response.status(), 404);
+        Ok(())
+    }
+}
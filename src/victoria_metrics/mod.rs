@@ -1,8 +1,20 @@
-use testcontainers::{core::WaitFor, Image};
+use std::borrow::Cow;
+
+use testcontainers::{
+    core::{wait::HttpWaitStrategy, ContainerPort, WaitFor},
+    Image,
+};
 
 const NAME: &str = "victoriametrics/victoria-metrics";
 const TAG: &str = "v1.96.0";
 
+/// Port of the HTTP API that the [`VictoriaMetrics`] container has internally,
+/// serving e.g. the Prometheus remote write and query APIs.
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`VictoriaMetrics`]: https://docs.victoriametrics.com/
+pub const VICTORIA_METRICS_PORT: ContainerPort = ContainerPort::Tcp(8428);
+
 /// Module to work with [`VictoriaMetrics`] inside of tests.
 ///
 /// Starts an instance of single-node VictoriaMetrics.
@@ -34,10 +46,23 @@ const TAG: &str = "v1.96.0";
 /// [`VictoriaMetrics Docker image`]: https://hub.docker.com/r/victoriametrics/victoria-metrics
 #[derive(Debug, Default, Clone)]
 pub struct VictoriaMetrics {
-    /// (remove if there is another variable)
-    /// Field is included to prevent this struct to be a unit struct.
-    /// This allows extending functionality (and thus further variables) without breaking changes
-    _priv: (),
+    flags: Vec<String>,
+}
+
+impl VictoriaMetrics {
+    /// Sets how long the data is kept (`-retentionPeriod`), e.g. `1d` or `2w`.
+    /// Without suffix the value is treated as months.
+    pub fn with_retention_period(self, retention_period: &str) -> Self {
+        self.with_extra_flag(&format!("-retentionPeriod={retention_period}"))
+    }
+
+    /// Passes an additional [command-line flag](https://docs.victoriametrics.com/#list-of-command-line-flags)
+    /// to VictoriaMetrics, e.g. `-search.latencyOffset=0s`.
+    /// Can be called multiple times to add (not override) flags.
+    pub fn with_extra_flag(mut self, flag: &str) -> Self {
+        self.flags.push(flag.to_owned());
+        self
+    }
 }
 
 impl Image for VictoriaMetrics {
@@ -50,12 +75,19 @@ impl Image for VictoriaMetrics {
     }
 
     fn ready_conditions(&self) -> Vec<WaitFor> {
-        vec![
-            WaitFor::message_on_stderr("started VictoriaMetrics"),
-            WaitFor::message_on_stderr(
-                "pprof handlers are exposed at http://127.0.0.1:8428/debug/pprof/",
-            ),
-        ]
+        vec![WaitFor::http(
+            HttpWaitStrategy::new("/health")
+                .with_port(VICTORIA_METRICS_PORT)
+                .with_expected_status_code(200_u16),
+        )]
+    }
+
+    fn cmd(&self) -> impl IntoIterator<Item = impl Into<Cow<'_, str>>> {
+        &self.flags
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[VICTORIA_METRICS_PORT]
     }
 }
 
@@ -63,7 +95,7 @@ impl Image for VictoriaMetrics {
 mod tests {
     use crate::{
         testcontainers::runners::SyncRunner,
-        victoria_metrics::VictoriaMetrics as VictoriaMetricsImage,
+        victoria_metrics::{VictoriaMetrics as VictoriaMetricsImage, VICTORIA_METRICS_PORT},
     };
 
     #[test]
@@ -82,4 +114,20 @@ mod tests {
         assert_eq!(version, "2.24.0");
         Ok(())
     }
+
+    #[test]
+    fn with_retention_period_and_extra_flag() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let node = VictoriaMetricsImage::default()
+            .with_retention_period("1d")
+            .with_extra_flag("-search.latencyOffset=0s")
+            .start()?;
+        let host_ip = node.get_host()?;
+        let host_port = node.get_host_port_ipv4(VICTORIA_METRICS_PORT)?;
+        let url = format!("http://{host_ip}:{host_port}/flags");
+
+        let flags = reqwest::blocking::get(url)?.text()?;
+        assert!(flags.contains(r#"-retentionPeriod="1d""#));
+        assert!(flags.contains(r#"-search.latencyOffset="0s""#));
+        Ok(())
+    }
 }
@@ -0,0 +1,189 @@
+use std::{borrow::Cow, collections::BTreeMap};
+
+use testcontainers::{
+    core::{wait::HttpWaitStrategy, ContainerPort, WaitFor},
+    Image,
+};
+
+const NAME: &str = "cr.weaviate.io/semitechnologies/weaviate";
+const TAG: &str = "1.27.2";
+
+/// Port of the REST API that the [`Weaviate`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Weaviate`]: https://weaviate.io/
+pub const WEAVIATE_HTTP_PORT: ContainerPort = ContainerPort::Tcp(8080);
+/// Port of the gRPC API that the [`Weaviate`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Weaviate`]: https://weaviate.io/
+pub const WEAVIATE_GRPC_PORT: ContainerPort = ContainerPort::Tcp(50051);
+
+/// Module to work with [`Weaviate`] inside of tests.
+///
+/// Starts a single node instance of Weaviate based on the official [`Weaviate docker image`],
+/// serving the REST API on port 8080 ([`WEAVIATE_HTTP_PORT`]) and the gRPC API on port 50051 ([`WEAVIATE_GRPC_PORT`]).
+///
+/// By default anonymous access is enabled and no vectorizer module is configured,
+/// see [`Weaviate::with_anonymous_access`] and [`Weaviate::with_modules`].
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{
+///     testcontainers::runners::SyncRunner,
+///     weaviate::{Weaviate, WEAVIATE_HTTP_PORT},
+/// };
+///
+/// let weaviate_instance = Weaviate::default().start().unwrap();
+/// let url = format!(
+///     "http://{}:{}",
+///     weaviate_instance.get_host().unwrap(),
+///     weaviate_instance
+///         .get_host_port_ipv4(WEAVIATE_HTTP_PORT)
+///         .unwrap()
+/// );
+/// ```
+///
+/// [`Weaviate`]: https://weaviate.io/
+/// [`Weaviate docker image`]: https://weaviate.io/developers/weaviate/installation/docker-compose
+#[derive(Debug, Clone)]
+pub struct Weaviate {
+    env_vars: BTreeMap<String, String>,
+}
+
+impl Default for Weaviate {
+    fn default() -> Self {
+        let mut env_vars = BTreeMap::new();
+        env_vars.insert(
+            "AUTHENTICATION_ANONYMOUS_ACCESS_ENABLED".to_owned(),
+            "true".to_owned(),
+        );
+        env_vars.insert(
+            "PERSISTENCE_DATA_PATH".to_owned(),
+            "/var/lib/weaviate".to_owned(),
+        );
+        env_vars.insert("DEFAULT_VECTORIZER_MODULE".to_owned(), "none".to_owned());
+        env_vars.insert("CLUSTER_HOSTNAME".to_owned(), "node1".to_owned());
+        Self { env_vars }
+    }
+}
+
+impl Weaviate {
+    /// Allows or denies unauthenticated requests (`AUTHENTICATION_ANONYMOUS_ACCESS_ENABLED`), allowed by default.
+    ///
+    /// Usually combined with [`Weaviate::with_api_key`] to authenticate clients.
+    pub fn with_anonymous_access(mut self, enabled: bool) -> Self {
+        self.env_vars.insert(
+            "AUTHENTICATION_ANONYMOUS_ACCESS_ENABLED".to_owned(),
+            enabled.to_string(),
+        );
+        self
+    }
+
+    /// Enables authentication with the given API key, which is associated with the given user.
+    /// Clients have to send it as bearer token in the `Authorization` header.
+    pub fn with_api_key(mut self, api_key: &str, user: &str) -> Self {
+        self.env_vars.insert(
+            "AUTHENTICATION_APIKEY_ENABLED".to_owned(),
+            "true".to_owned(),
+        );
+        self.env_vars.insert(
+            "AUTHENTICATION_APIKEY_ALLOWED_KEYS".to_owned(),
+            api_key.to_owned(),
+        );
+        self.env_vars
+            .insert("AUTHENTICATION_APIKEY_USERS".to_owned(), user.to_owned());
+        self
+    }
+
+    /// Sets the comma separated list of modules to enable (`ENABLE_MODULES`),
+    /// e.g. `text2vec-openai,generative-openai`.
+    pub fn with_modules(mut self, modules: &str) -> Self {
+        self.env_vars
+            .insert("ENABLE_MODULES".to_owned(), modules.to_owned());
+        self
+    }
+
+    /// Sets the vectorizer module used for classes without explicit vectorizer (`DEFAULT_VECTORIZER_MODULE`),
+    /// defaults to `none`.
+    pub fn with_default_vectorizer_module(mut self, module: &str) -> Self {
+        self.env_vars
+            .insert("DEFAULT_VECTORIZER_MODULE".to_owned(), module.to_owned());
+        self
+    }
+}
+
+impl Image for Weaviate {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn tag(&self) -> &str {
+        TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![WaitFor::http(
+            HttpWaitStrategy::new("/v1/.well-known/ready")
+                .with_port(WEAVIATE_HTTP_PORT)
+                .with_expected_status_code(200_u16),
+        )]
+    }
+
+    fn env_vars(
+        &self,
+    ) -> impl IntoIterator<Item = (impl Into<Cow<'_, str>>, impl Into<Cow<'_, str>>)> {
+        &self.env_vars
+    }
+
+    fn cmd(&self) -> impl IntoIterator<Item = impl Into<Cow<'_, str>>> {
+        ["--host", "0.0.0.0", "--port", "8080", "--scheme", "http"]
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[WEAVIATE_HTTP_PORT, WEAVIATE_GRPC_PORT]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testcontainers::runners::SyncRunner;
+
+    #[test]
+    fn weaviate_meta() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = Weaviate::default().start()?;
+        let url = format!(
+            "http://{}:{}/v1/meta",
+            node.get_host()?,
+            node.get_host_port_ipv4(WEAVIATE_HTTP_PORT)?
+        );
+
+        let meta: serde_json::Value = reqwest::blocking::get(url)?.error_for_status()?.json()?;
+        assert_eq!(meta["version"], TAG);
+        Ok(())
+    }
+
+    #[test]
+    fn weaviate_with_api_key() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = Weaviate::default()
+            .with_anonymous_access(false)
+            .with_api_key("secret", "test@example.com")
+            .start()?;
+        let url = format!(
+            "http://{}:{}/v1/schema",
+            node.get_host()?,
+            node.get_host_port_ipv4(WEAVIATE_HTTP_PORT)?
+        );
+
+        let client = reqwest::blocking::Client::new();
+        let response = client.get(&url).send()?;
+        assert_eq!(response.status(), 401);
+
+        let response = client.get(&url).bearer_auth("secret").send()?;
+        assert_eq!(response.status(), 200);
+        Ok(())
+    }
+}
@@ -0,0 +1,144 @@
+use std::{borrow::Cow, collections::BTreeMap};
+
+use testcontainers::{
+    core::{ContainerPort, WaitFor},
+    CopyDataSource, CopyToContainer, Image,
+};
+
+const NAME: &str = "bytemark/webdav";
+const TAG: &str = "2.4";
+const DATA_DIR: &str = "/var/lib/dav/data";
+
+/// Port of the WebDAV server that the [`WebDav`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+pub const WEBDAV_PORT: ContainerPort = ContainerPort::Tcp(80);
+
+/// Module to work with a [`WebDAV`] server inside of tests.
+///
+/// Starts an Apache httpd with `mod_dav` based on the [`bytemark/webdav docker image`],
+/// serving the root directory `/var/lib/dav/data` on port 80 ([`WEBDAV_PORT`]).
+///
+/// Without credentials (see [`WebDav::with_basic_auth`]) anonymous access is allowed.
+/// The root directory can be seeded with files, see [`WebDav::with_file`].
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{
+///     testcontainers::runners::SyncRunner,
+///     webdav::{WebDav, WEBDAV_PORT},
+/// };
+///
+/// let webdav_instance = WebDav::default()
+///     .with_basic_auth("user", "password")
+///     .with_file("hello.txt", "world".to_string().into_bytes())
+///     .start()
+///     .unwrap();
+/// let url = format!(
+///     "http://{}:{}/hello.txt",
+///     webdav_instance.get_host().unwrap(),
+///     webdav_instance.get_host_port_ipv4(WEBDAV_PORT).unwrap()
+/// );
+/// ```
+///
+/// [`WebDAV`]: https://httpd.apache.org/docs/2.4/mod/mod_dav.html
+/// [`bytemark/webdav docker image`]: https://hub.docker.com/r/bytemark/webdav
+#[derive(Debug, Clone, Default)]
+pub struct WebDav {
+    env_vars: BTreeMap<String, String>,
+    files: Vec<CopyToContainer>,
+}
+
+impl WebDav {
+    /// Requires HTTP basic authentication with the given credentials (`USERNAME`, `PASSWORD`).
+    pub fn with_basic_auth(mut self, username: &str, password: &str) -> Self {
+        self.env_vars
+            .insert("AUTH_TYPE".to_owned(), "Basic".to_owned());
+        self.env_vars
+            .insert("USERNAME".to_owned(), username.to_owned());
+        self.env_vars
+            .insert("PASSWORD".to_owned(), password.to_owned());
+        self
+    }
+
+    /// Seeds the root directory with a file (or directory) at the given path,
+    /// relative to `/var/lib/dav/data`. Can be called multiple times to add (not override) files.
+    pub fn with_file(mut self, path: &str, source: impl Into<CopyDataSource>) -> Self {
+        let target = format!("{DATA_DIR}/{}", path.trim_start_matches('/'));
+        self.files.push(CopyToContainer::new(source.into(), target));
+        self
+    }
+}
+
+impl Image for WebDav {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn tag(&self) -> &str {
+        TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![WaitFor::message_on_stderr(
+            "Command line: 'httpd -D FOREGROUND'",
+        )]
+    }
+
+    fn env_vars(
+        &self,
+    ) -> impl IntoIterator<Item = (impl Into<Cow<'_, str>>, impl Into<Cow<'_, str>>)> {
+        &self.env_vars
+    }
+
+    fn copy_to_sources(&self) -> impl IntoIterator<Item = &CopyToContainer> {
+        &self.files
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[WEBDAV_PORT]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testcontainers::runners::SyncRunner;
+
+    #[test]
+    fn webdav_with_basic_auth() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = WebDav::default()
+            .with_basic_auth("user", "password")
+            .with_file("docs/hello.txt", "world".to_string().into_bytes())
+            .start()?;
+        let url = format!(
+            "http://{}:{}",
+            node.get_host()?,
+            node.get_host_port_ipv4(WEBDAV_PORT)?
+        );
+        let client = reqwest::blocking::Client::new();
+
+        let response = client.get(format!("{url}/docs/hello.txt")).send()?;
+        assert_eq!(response.status(), 401);
+
+        let body = client
+            .get(format!("{url}/docs/hello.txt"))
+            .basic_auth("user", Some("password"))
+            .send()?
+            .error_for_status()?
+            .text()?;
+        assert_eq!(body, "world");
+
+        let listing = client
+            .request(
+                reqwest::Method::from_bytes(b"PROPFIND")?,
+                format!("{url}/docs/"),
+            )
+            .basic_auth("user", Some("password"))
+            .header("Depth", "1")
+            .send()?;
+        assert_eq!(listing.status(), 207);
+        assert!(listing.text()?.contains("hello.txt"));
+        Ok(())
+    }
+}
@@ -0,0 +1,165 @@
+use std::borrow::Cow;
+
+use testcontainers::{
+    core::{wait::HttpWaitStrategy, ContainerPort, WaitFor},
+    CopyDataSource, CopyToContainer, Image,
+};
+
+const NAME: &str = "wiremock/wiremock";
+const TAG: &str = "3.9.2";
+const MAPPINGS_DIR: &str = "/home/wiremock/mappings";
+
+/// Port of the stub server and the admin API (`/__admin`) that the [`WireMock`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`WireMock`]: https://wiremock.org/
+pub const WIREMOCK_PORT: ContainerPort = ContainerPort::Tcp(8080);
+
+/// Module to work with a standalone [`WireMock`] server inside of tests.
+///
+/// Starts an instance of WireMock based on the official [`WireMock docker image`],
+/// serving the stubs and the admin API on port 8080 ([`WIREMOCK_PORT`]).
+///
+/// Stub mappings can be preloaded with [`WireMockStandalone::with_mappings_dir`] and
+/// [`WireMockStandalone::with_mapping`], or registered at runtime via the admin API.
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{
+///     testcontainers::runners::SyncRunner,
+///     wiremock::{WireMockStandalone, WIREMOCK_PORT},
+/// };
+///
+/// let wiremock_instance = WireMockStandalone::default().start().unwrap();
+/// let url = format!(
+///     "http://{}:{}",
+///     wiremock_instance.get_host().unwrap(),
+///     wiremock_instance.get_host_port_ipv4(WIREMOCK_PORT).unwrap()
+/// );
+/// ```
+///
+/// [`WireMock`]: https://wiremock.org/
+/// [`WireMock docker image`]: https://hub.docker.com/r/wiremock/wiremock
+#[derive(Debug, Clone, Default)]
+pub struct WireMockStandalone {
+    mappings: Vec<CopyToContainer>,
+    verbose: bool,
+    record_target: Option<String>,
+}
+
+impl WireMockStandalone {
+    /// Copies a directory of stub mapping files to `/home/wiremock/mappings`,
+    /// from where they are loaded on startup.
+    pub fn with_mappings_dir(mut self, mappings_dir: impl Into<CopyDataSource>) -> Self {
+        self.mappings
+            .push(CopyToContainer::new(mappings_dir.into(), MAPPINGS_DIR));
+        self
+    }
+
+    /// Registers a single stub mapping file, copied to `/home/wiremock/mappings/<file_name>`.
+    /// Can be called multiple times to add (not override) mappings.
+    ///
+    /// # Example
+    /// ```
+    /// use testcontainers_modules::wiremock::WireMockStandalone;
+    ///
+    /// let wiremock = WireMockStandalone::default().with_mapping(
+    ///     "hello.json",
+    ///     r#"{"request": {"method": "GET", "url": "/hello"}, "response": {"status": 200, "body": "world"}}"#
+    ///         .to_string()
+    ///         .into_bytes(),
+    /// );
+    /// ```
+    pub fn with_mapping(mut self, file_name: &str, mapping: impl Into<CopyDataSource>) -> Self {
+        self.mappings.push(CopyToContainer::new(
+            mapping.into(),
+            format!("{MAPPINGS_DIR}/{file_name}"),
+        ));
+        self
+    }
+
+    /// Enables the verbose logging of the received requests (`--verbose`).
+    pub fn with_verbose(mut self) -> Self {
+        self.verbose = true;
+        self
+    }
+
+    /// Enables the record mode: all requests are proxied to the given target url (`--proxy-all`)
+    /// and the responses are recorded as stub mappings (`--record-mappings`).
+    pub fn with_record_mappings(mut self, target_url: &str) -> Self {
+        self.record_target = Some(target_url.to_owned());
+        self
+    }
+}
+
+impl Image for WireMockStandalone {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn tag(&self) -> &str {
+        TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![WaitFor::http(
+            HttpWaitStrategy::new("/__admin/health")
+                .with_port(WIREMOCK_PORT)
+                .with_expected_status_code(200_u16),
+        )]
+    }
+
+    fn cmd(&self) -> impl IntoIterator<Item = impl Into<Cow<'_, str>>> {
+        let mut cmd = Vec::new();
+        if self.verbose {
+            cmd.push("--verbose".to_owned());
+        }
+        if let Some(target_url) = &self.record_target {
+            cmd.push(format!("--proxy-all={target_url}"));
+            cmd.push("--record-mappings".to_owned());
+        }
+        cmd
+    }
+
+    fn copy_to_sources(&self) -> impl IntoIterator<Item = &CopyToContainer> {
+        &self.mappings
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[WIREMOCK_PORT]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testcontainers::runners::SyncRunner;
+
+    #[test]
+    fn wiremock_with_mapping() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = WireMockStandalone::default()
+            .with_verbose()
+            .with_mapping(
+                "hello.json",
+                r#"{"request": {"method": "GET", "url": "/hello"}, "response": {"status": 200, "body": "world"}}"#
+                    .to_string()
+                    .into_bytes(),
+            )
+            .start()?;
+        let url = format!(
+            "http://{}:{}",
+            node.get_host()?,
+            node.get_host_port_ipv4(WIREMOCK_PORT)?
+        );
+
+        let body = reqwest::blocking::get(format!("{url}/hello"))?
+            .error_for_status()?
+            .text()?;
+        assert_eq!(body, "world");
+
+        let response = reqwest::blocking::get(format!("{url}/unknown"))?;
+        assert_eq!(response.status(), 404);
+        Ok(())
+    }
+}
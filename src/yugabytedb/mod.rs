@@ -0,0 +1,257 @@
+use std::{borrow::Cow, collections::BTreeMap};
+
+use testcontainers::{
+    core::{CmdWaitFor, ContainerPort, ContainerState, ExecCommand, WaitFor},
+    CopyDataSource, CopyToContainer, Image, TestcontainersError,
+};
+
+const NAME: &str = "yugabytedb/yugabyte";
+const TAG: &str = "2.20.1.0-b97";
+const INITIAL_SCRIPTS_DIR: &str = "/home/yugabyte/initial_scripts";
+const INITIAL_SCRIPTS_TIMEOUT_SECS: u32 = 60;
+
+/// Port of the YSQL (Postgres compatible) API that the [`YugabyteDB`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`YugabyteDB`]: https://www.yugabyte.com/
+pub const YSQL_PORT: ContainerPort = ContainerPort::Tcp(5433);
+
+/// Port of the YCQL (Cassandra compatible) API that the [`YugabyteDB`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`YugabyteDB`]: https://www.yugabyte.com/
+pub const YCQL_PORT: ContainerPort = ContainerPort::Tcp(9042);
+
+/// Module to work with [`YugabyteDB`] inside of tests.
+///
+/// Starts a single node instance of YugabyteDB via `yugabyted`.
+/// This module is based on the official [`YugabyteDB docker image`].
+///
+/// Both the YSQL ([`YSQL_PORT`]) and the YCQL ([`YCQL_PORT`]) APIs are exposed.
+/// Default db name and user is `yugabyte`, the default keyspace is `yugabyte` as well.
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{
+///     testcontainers::runners::SyncRunner,
+///     yugabytedb::{YugabyteDb, YSQL_PORT},
+/// };
+///
+/// let yugabyte_instance = YugabyteDb::default().start().unwrap();
+///
+/// let connection_string = format!(
+///     "postgres://yugabyte@{}:{}/yugabyte",
+///     yugabyte_instance.get_host().unwrap(),
+///     yugabyte_instance.get_host_port_ipv4(YSQL_PORT).unwrap()
+/// );
+/// ```
+///
+/// [`YugabyteDB`]: https://www.yugabyte.com/
+/// [`YugabyteDB docker image`]: https://hub.docker.com/r/yugabytedb/yugabyte
+#[derive(Debug, Default, Clone)]
+pub struct YugabyteDb {
+    env_vars: BTreeMap<String, String>,
+    copy_to_sources: Vec<CopyToContainer>,
+    initial_scripts: Vec<String>,
+}
+
+impl YugabyteDb {
+    /// Sets the name of the YSQL database to be created on startup.
+    pub fn with_db_name(mut self, db_name: &str) -> Self {
+        self.env_vars
+            .insert("YSQL_DB".to_owned(), db_name.to_owned());
+        self
+    }
+
+    /// Sets the name of the YSQL user to be created on startup.
+    pub fn with_user(mut self, user: &str) -> Self {
+        self.env_vars
+            .insert("YSQL_USER".to_owned(), user.to_owned());
+        self
+    }
+
+    /// Sets the password of the YSQL user.
+    pub fn with_password(mut self, password: &str) -> Self {
+        self.env_vars
+            .insert("YSQL_PASSWORD".to_owned(), password.to_owned());
+        self
+    }
+
+    /// Sets the name of the YCQL keyspace to be created on startup.
+    pub fn with_keyspace(mut self, keyspace: &str) -> Self {
+        self.env_vars
+            .insert("YCQL_KEYSPACE".to_owned(), keyspace.to_owned());
+        self
+    }
+
+    /// Registers YSQL (`.sql`) to be executed automatically when the node starts,
+    /// as the configured user against the configured database (see [`YugabyteDb::with_db_name`]).
+    /// Can be called multiple times to add (not override) scripts, which are executed in registration order
+    /// before the container is considered ready.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use testcontainers_modules::yugabytedb::YugabyteDb;
+    /// let yugabyte_image = YugabyteDb::default().with_init_sql(
+    ///     "CREATE TABLE foo (bar varchar(255));"
+    ///         .to_string()
+    ///         .into_bytes(),
+    /// );
+    /// ```
+    pub fn with_init_sql(self, init_sql: impl Into<CopyDataSource>) -> Self {
+        self.with_initial_script(init_sql, "sql")
+    }
+
+    /// Registers YCQL (`.cql`) to be executed automatically when the node starts.
+    /// Can be called multiple times to add (not override) scripts, which are executed in registration order
+    /// before the container is considered ready.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use testcontainers_modules::yugabytedb::YugabyteDb;
+    /// let yugabyte_image = YugabyteDb::default().with_init_cql(
+    ///     "CREATE KEYSPACE IF NOT EXISTS app;"
+    ///         .to_string()
+    ///         .into_bytes(),
+    /// );
+    /// ```
+    pub fn with_init_cql(self, init_cql: impl Into<CopyDataSource>) -> Self {
+        self.with_initial_script(init_cql, "cql")
+    }
+
+    fn with_initial_script(mut self, script: impl Into<CopyDataSource>, extension: &str) -> Self {
+        let target = format!(
+            "{INITIAL_SCRIPTS_DIR}/init_{i:03}.{extension}",
+            i = self.copy_to_sources.len()
+        );
+        self.copy_to_sources
+            .push(CopyToContainer::new(script.into(), target.clone()));
+        self.initial_scripts.push(target);
+        self
+    }
+
+    /// Returns the shell command executing the given script with `ysqlsh` or `ycqlsh`.
+    fn initial_script_command(script: &str) -> String {
+        if script.ends_with(".cql") {
+            return format!("bin/ycqlsh \"$(hostname)\" -f {script}");
+        }
+        // the database and the user are created by `yugabyted` shortly after the node is started,
+        // thus wait until they accept connections before executing the script
+        let ysqlsh = r#"PGPASSWORD="${YSQL_PASSWORD:-yugabyte}" bin/ysqlsh -h "$(hostname)" -U "${YSQL_USER:-yugabyte}" -d "${YSQL_DB:-yugabyte}" -v ON_ERROR_STOP=1"#;
+        format!(
+            "for i in $(seq {INITIAL_SCRIPTS_TIMEOUT_SECS}); do {ysqlsh} -c 'SELECT 1' > /dev/null 2>&1 && break; sleep 1; done; {ysqlsh} -f {script}"
+        )
+    }
+}
+
+impl Image for YugabyteDb {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn tag(&self) -> &str {
+        TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![WaitFor::message_on_stdout(
+            "Data placement constraint successfully verified",
+        )]
+    }
+
+    fn env_vars(
+        &self,
+    ) -> impl IntoIterator<Item = (impl Into<Cow<'_, str>>, impl Into<Cow<'_, str>>)> {
+        &self.env_vars
+    }
+
+    fn copy_to_sources(&self) -> impl IntoIterator<Item = &CopyToContainer> {
+        &self.copy_to_sources
+    }
+
+    fn cmd(&self) -> impl IntoIterator<Item = impl Into<Cow<'_, str>>> {
+        ["bin/yugabyted", "start", "--background=false"]
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[YSQL_PORT, YCQL_PORT]
+    }
+
+    fn exec_after_start(&self, _: ContainerState) -> Result<Vec<ExecCommand>, TestcontainersError> {
+        // the scripts are executed here instead of via `--initial_scripts_dir` of `yugabyted`,
+        // which runs them in the background, after the node is reported to be started
+        Ok(self
+            .initial_scripts
+            .iter()
+            .map(|script| {
+                ExecCommand::new(["sh", "-c", &Self::initial_script_command(script)])
+                    .with_cmd_ready_condition(CmdWaitFor::exit_code(0))
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testcontainers::runners::SyncRunner;
+
+    #[test]
+    fn yugabytedb_ysql_one_plus_one() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = YugabyteDb::default().start()?;
+
+        let connection_string = &format!(
+            "postgres://yugabyte@{}:{}/yugabyte",
+            node.get_host()?,
+            node.get_host_port_ipv4(YSQL_PORT)?
+        );
+        let mut conn = postgres::Client::connect(connection_string, postgres::NoTls).unwrap();
+
+        let rows = conn.query("SELECT 1 + 1", &[]).unwrap();
+        assert_eq!(rows.len(), 1);
+
+        let first_row = &rows[0];
+        let first_column: i32 = first_row.get(0);
+        assert_eq!(first_column, 2);
+        Ok(())
+    }
+
+    #[test]
+    fn yugabytedb_with_db_name_and_init_sql() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = YugabyteDb::default()
+            .with_db_name("test_db")
+            .with_user("test_user")
+            .with_password("test_password")
+            .with_init_sql(
+                "CREATE TABLE foo (bar varchar(255));"
+                    .to_string()
+                    .into_bytes(),
+            )
+            .with_init_sql(
+                "INSERT INTO foo (bar) VALUES ('a'), ('b');"
+                    .to_string()
+                    .into_bytes(),
+            )
+            .start()?;
+
+        let connection_string = &format!(
+            "postgres://test_user:test_password@{}:{}/test_db",
+            node.get_host()?,
+            node.get_host_port_ipv4(YSQL_PORT)?
+        );
+        let mut conn = postgres::Client::connect(connection_string, postgres::NoTls).unwrap();
+
+        let rows = conn.query("SELECT current_database()", &[]).unwrap();
+        let first_column: String = rows[0].get(0);
+        assert_eq!(first_column, "test_db");
+
+        let rows = conn.query("SELECT count(*) FROM foo", &[]).unwrap();
+        let count: i64 = rows[0].get(0);
+        assert_eq!(count, 2);
+        Ok(())
+    }
+}
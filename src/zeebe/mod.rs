@@ -0,0 +1,144 @@
+use std::{borrow::Cow, collections::BTreeMap};
+
+use testcontainers::{
+    core::{wait::HttpWaitStrategy, ContainerPort, WaitFor},
+    Image,
+};
+
+const NAME: &str = "camunda/zeebe";
+const TAG: &str = "8.6.5";
+
+/// Port of the gRPC gateway that the [`Zeebe`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Zeebe`]: https://docs.camunda.io/docs/components/zeebe/zeebe-overview/
+pub const ZEEBE_GATEWAY_PORT: ContainerPort = ContainerPort::Tcp(26500);
+
+/// Port of the REST gateway that the [`Zeebe`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Zeebe`]: https://docs.camunda.io/docs/components/zeebe/zeebe-overview/
+pub const ZEEBE_REST_PORT: ContainerPort = ContainerPort::Tcp(8080);
+
+/// Port of the monitoring API (health checks, metrics) that the [`Zeebe`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Zeebe`]: https://docs.camunda.io/docs/components/zeebe/zeebe-overview/
+pub const ZEEBE_MONITORING_PORT: ContainerPort = ContainerPort::Tcp(9600);
+
+/// Module to work with [`Zeebe`], the workflow engine of Camunda 8, inside of tests.
+///
+/// Starts a single broker with an embedded gateway based on the official [`Zeebe docker image`].
+/// Clients connect to the gRPC gateway on port 26500 ([`ZEEBE_GATEWAY_PORT`]) or the REST gateway
+/// on port 8080 ([`ZEEBE_REST_PORT`]), without authentication. The container is ready once the broker
+/// reports itself ready on the monitoring port 9600 ([`ZEEBE_MONITORING_PORT`]).
+///
+/// The broker has a single partition by default, see [`Zeebe::with_partitions_count`].
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{
+///     testcontainers::runners::SyncRunner,
+///     zeebe::{Zeebe, ZEEBE_GATEWAY_PORT},
+/// };
+///
+/// let zeebe_instance = Zeebe::default().start().unwrap();
+/// let gateway_address = format!(
+///     "http://{}:{}",
+///     zeebe_instance.get_host().unwrap(),
+///     zeebe_instance.get_host_port_ipv4(ZEEBE_GATEWAY_PORT).unwrap()
+/// );
+/// ```
+///
+/// [`Zeebe`]: https://docs.camunda.io/docs/components/zeebe/zeebe-overview/
+/// [`Zeebe docker image`]: https://hub.docker.com/r/camunda/zeebe
+#[derive(Debug, Clone)]
+pub struct Zeebe {
+    env_vars: BTreeMap<String, String>,
+}
+
+impl Default for Zeebe {
+    fn default() -> Self {
+        let mut env_vars = BTreeMap::new();
+        env_vars.insert(
+            "ZEEBE_BROKER_CLUSTER_PARTITIONSCOUNT".to_owned(),
+            "1".to_owned(),
+        );
+        env_vars.insert("ZEEBE_BROKER_GATEWAY_ENABLE".to_owned(), "true".to_owned());
+        Self { env_vars }
+    }
+}
+
+impl Zeebe {
+    /// Sets the number of partitions of the broker (`ZEEBE_BROKER_CLUSTER_PARTITIONSCOUNT`), defaults to 1.
+    pub fn with_partitions_count(mut self, partitions_count: u16) -> Self {
+        self.env_vars.insert(
+            "ZEEBE_BROKER_CLUSTER_PARTITIONSCOUNT".to_owned(),
+            partitions_count.to_string(),
+        );
+        self
+    }
+
+    /// Sets an exporter (`ZEEBE_BROKER_EXPORTERS_{NAME}_CLASSNAME`), which has to be available in the container,
+    /// e.g. `with_exporter("elasticsearch", "io.camunda.zeebe.exporter.ElasticsearchExporter")`.
+    /// The exporter is configured with further `ZEEBE_BROKER_EXPORTERS_{NAME}_ARGS_*` environment variables,
+    /// see [`testcontainers::core::ImageExt::with_env_var`].
+    pub fn with_exporter(mut self, name: &str, class_name: &str) -> Self {
+        self.env_vars.insert(
+            format!("ZEEBE_BROKER_EXPORTERS_{}_CLASSNAME", name.to_uppercase()),
+            class_name.to_owned(),
+        );
+        self
+    }
+}
+
+impl Image for Zeebe {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn tag(&self) -> &str {
+        TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![WaitFor::http(
+            HttpWaitStrategy::new("/actuator/health/readiness")
+                .with_port(ZEEBE_MONITORING_PORT)
+                .with_expected_status_code(200_u16),
+        )]
+    }
+
+    fn env_vars(
+        &self,
+    ) -> impl IntoIterator<Item = (impl Into<Cow<'_, str>>, impl Into<Cow<'_, str>>)> {
+        &self.env_vars
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[ZEEBE_GATEWAY_PORT, ZEEBE_REST_PORT, ZEEBE_MONITORING_PORT]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testcontainers::runners::SyncRunner;
+
+    #[test]
+    fn zeebe_topology() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = Zeebe::default().with_partitions_count(2).start()?;
+        let url = format!(
+            "http://{}:{}",
+            node.get_host()?,
+            node.get_host_port_ipv4(ZEEBE_REST_PORT)?
+        );
+
+        let topology: serde_json::Value =
+            reqwest::blocking::get(format!("{url}/v2/topology"))?.json()?;
+        assert_eq!(topology["clusterSize"], 1);
+        assert_eq!(topology["partitionsCount"], 2);
+        Ok(())
+    }
+}
@@ -0,0 +1,116 @@
+use testcontainers::{
+    core::{wait::HttpWaitStrategy, ContainerPort, WaitFor},
+    Image,
+};
+
+const NAME: &str = "openzipkin/zipkin-slim";
+const TAG: &str = "3.4";
+
+/// Port of the HTTP API, span collector and web UI that the [`Zipkin`] container has internally
+/// Can be rebound externally via [`testcontainers::core::ImageExt::with_mapped_port`]
+///
+/// [`Zipkin`]: https://zipkin.io/
+pub const ZIPKIN_PORT: ContainerPort = ContainerPort::Tcp(9411);
+
+/// Module to work with [`Zipkin`] inside of tests.
+///
+/// Starts an instance of Zipkin with in-memory storage based on the official [`Zipkin slim docker image`].
+///
+/// Spans are collected via `POST /api/v2/spans` and queried via the HTTP API,
+/// both served on port 9411 ([`ZIPKIN_PORT`]).
+///
+/// # Example
+/// ```
+/// use testcontainers_modules::{
+///     testcontainers::runners::SyncRunner,
+///     zipkin::{Zipkin, ZIPKIN_PORT},
+/// };
+///
+/// let zipkin_instance = Zipkin::default().start().unwrap();
+/// let url = format!(
+///     "http://{}:{}/api/v2/spans",
+///     zipkin_instance.get_host().unwrap(),
+///     zipkin_instance.get_host_port_ipv4(ZIPKIN_PORT).unwrap()
+/// );
+/// ```
+///
+/// [`Zipkin`]: https://zipkin.io/
+/// [`Zipkin slim docker image`]: https://hub.docker.com/r/openzipkin/zipkin-slim
+#[derive(Debug, Clone, Default)]
+pub struct Zipkin {
+    /// (remove if there is another variable)
+    /// Field is included to prevent this struct to be a unit struct.
+    /// This allows extending functionality (and thus further variables) without breaking changes
+    _priv: (),
+}
+
+impl Image for Zipkin {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn tag(&self) -> &str {
+        TAG
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![WaitFor::http(
+            HttpWaitStrategy::new("/health")
+                .with_port(ZIPKIN_PORT)
+                .with_expected_status_code(200_u16),
+        )]
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[ZIPKIN_PORT]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use retry::{delay::Fixed, retry};
+
+    use super::*;
+    use crate::testcontainers::runners::SyncRunner;
+
+    #[test]
+    fn zipkin_collect_and_query_span() -> Result<(), Box<dyn std::error::Error + 'static>> {
+        let _ = pretty_env_logger::try_init();
+        let node = Zipkin::default().start()?;
+        let url = format!(
+            "http://{}:{}/api/v2",
+            node.get_host()?,
+            node.get_host_port_ipv4(ZIPKIN_PORT)?
+        );
+
+        let spans = serde_json::json!([{
+            "traceId": "5b8efff798038103",
+            "id": "eee19b7ec3c1b174",
+            "name": "test-span",
+            "timestamp": 1700000000000000_u64,
+            "duration": 1000,
+            "localEndpoint": { "serviceName": "testcontainers" }
+        }]);
+        let client = reqwest::blocking::Client::new();
+        client
+            .post(format!("{url}/spans"))
+            .json(&spans)
+            .send()?
+            .error_for_status()?;
+
+        // spans are stored asynchronously after being accepted
+        let trace: serde_json::Value =
+            retry(Fixed::from(Duration::from_millis(200)).take(20), || {
+                client
+                    .get(format!("{url}/trace/5b8efff798038103"))
+                    .send()
+                    .and_then(|response| response.error_for_status())
+                    .and_then(|response| response.json())
+            })
+            .map_err(|err| err.to_string())?;
+        assert_eq!(trace[0]["name"], "test-span");
+        Ok(())
+    }
+}